@@ -1,24 +1,43 @@
 use clap::{App, Arg, SubCommand};
 
 use reso_launcher_lib::{
-    install::{ResoniteInstall, ResoniteInstallManager},
+    install::{InstallConflictError, ResoniteInstall, ResoniteInstallManager},
+    launch_args,
     profile::ProfileManager,
     depotdownloader::DepotDownloader,
+    mod_loader_type::ModLoaderType,
+    mod_manager::{ModManager, ModManagerError},
     utils,
 };
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn main() {
+    // 引数の解析前に発生したエラーもJSONで出力できるよう、フラグは直接確認する
+    let json = std::env::args().skip(1).any(|arg| arg == "--json");
+
+    if let Err(e) = run() {
+        if json {
+            eprintln!("{}", serde_json::json!({ "error": e.to_string() }));
+        } else {
+            eprintln!("Error: {}", e);
+        }
+        std::process::exit(1);
+    }
+}
+
+/// `--json` が指定されたか（サブコマンド側に指定された場合も含む）
+fn json_requested(matches: &clap::ArgMatches<'_>) -> bool {
+    matches.is_present("json") || matches.subcommand().1.is_some_and(json_requested)
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
     // 実行可能ファイルのディレクトリを取得
     let exe_dir = utils::get_executable_directory()?;
 
     // DepotDownloaderの初期化
     let depot_downloader = DepotDownloader::with_default_path(&exe_dir);
 
-    // DepotDownloaderの存在確認
-    depot_downloader.check_exists()?;
-
     // プロファイルマネージャの初期化
-    let profile_manager = ProfileManager::new(&exe_dir);
+    let mut profile_manager = ProfileManager::new(&exe_dir);
 
     // インストールマネージャの初期化
     let install_manager = ResoniteInstallManager::new(&exe_dir);
@@ -27,12 +46,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .version("1.0.1")
         .author("resonite.love community")
         .about("RESO Launcher CLI - Community Resonite management tool")
+        .arg(
+            Arg::with_name("json")
+                .long("json")
+                .global(true)
+                .help("Print results as JSON (progress logs and errors are printed to stderr; errors as {\"error\": \"...\"})"),
+        )
         .subcommand(
             SubCommand::with_name("install")
                 .about("Installs or updates Resonite")
                 .arg(
                     Arg::with_name("branch")
-                        .help("Branch to install: 'release' or 'prerelease' (default: release)")
+                        .help("Branch to install: 'release', 'prerelease' or a Steam beta branch name (default: release)")
                         .takes_value(true)
                         .default_value("release"),
                 )
@@ -55,12 +80,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .help("Steam password")
                         .takes_value(true),
                 )
+                .arg(
+                    Arg::with_name("beta_password")
+                        .long("beta-password")
+                        .help("Password of the Steam beta branch")
+                        .takes_value(true),
+                )
                 .arg(
                     Arg::with_name("auth_code")
                         .short("a")
                         .long("auth-code")
                         .help("Steam Guard authentication code")
                         .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("force")
+                        .short("f")
+                        .long("force")
+                        .help("Overwrite the installation folder without confirmation even if it has other files or another branch"),
                 ),
         )
         .subcommand(
@@ -68,7 +105,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .about("Updates an existing Resonite installation")
                 .arg(
                     Arg::with_name("branch")
-                        .help("Branch to update: 'release' or 'prerelease' (default: release)")
+                        .help("Branch to update: 'release', 'prerelease' or a Steam beta branch name (default: release)")
                         .takes_value(true)
                         .default_value("release"),
                 )
@@ -91,12 +128,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .help("Steam password")
                         .takes_value(true),
                 )
+                .arg(
+                    Arg::with_name("beta_password")
+                        .long("beta-password")
+                        .help("Password of the Steam beta branch")
+                        .takes_value(true),
+                )
                 .arg(
                     Arg::with_name("auth_code")
                         .short("a")
                         .long("auth-code")
                         .help("Steam Guard authentication code")
                         .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("force")
+                        .short("f")
+                        .long("force")
+                        .help("Overwrite the installation folder without confirmation even if it has other files or another branch"),
                 ),
         )
         .subcommand(
@@ -104,7 +153,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .about("Checks if updates are available")
                 .arg(
                     Arg::with_name("branch")
-                        .help("Branch to check: 'release' or 'prerelease' (default: release)")
+                        .help("Branch to check: 'release', 'prerelease' or a Steam beta branch name (default: release)")
                         .takes_value(true)
                         .default_value("release"),
                 )
@@ -127,6 +176,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .help("Steam password")
                         .takes_value(true),
                 )
+                .arg(
+                    Arg::with_name("beta_password")
+                        .long("beta-password")
+                        .help("Password of the Steam beta branch")
+                        .takes_value(true),
+                )
                 .arg(
                     Arg::with_name("auth_code")
                         .short("a")
@@ -156,10 +211,51 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .arg(Arg::with_name("name").help("Profile name").required(true)),
                 )
                 .subcommand(SubCommand::with_name("list").about("List all profiles"))
+                .subcommand(
+                    SubCommand::with_name("delete")
+                        .about("Delete a profile and all its data")
+                        .arg(Arg::with_name("name").help("Profile name").required(true))
+                        .arg(
+                            Arg::with_name("force")
+                                .short("f")
+                                .long("force")
+                                .help("Delete without confirmation"),
+                        ),
+                )
                 .subcommand(
                     SubCommand::with_name("edit")
-                        .about("Edit a profile (not implemented yet)")
-                        .arg(Arg::with_name("name").help("Profile name").required(true)),
+                        .about("Edit a profile")
+                        .arg(Arg::with_name("name").help("Profile name").required(true))
+                        .arg(
+                            Arg::with_name("description")
+                                .long("description")
+                                .help("New description")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("add-arg")
+                                .long("add-arg")
+                                .help("Launch argument to add, with its value if any (e.g. \"-DataPath D:\\Data\")")
+                                .takes_value(true)
+                                .multiple(true)
+                                .number_of_values(1)
+                                .allow_hyphen_values(true),
+                        )
+                        .arg(
+                            Arg::with_name("remove-arg")
+                                .long("remove-arg")
+                                .help("Launch argument to remove (its value is removed too)")
+                                .takes_value(true)
+                                .multiple(true)
+                                .number_of_values(1)
+                                .allow_hyphen_values(true),
+                        )
+                        .arg(
+                            Arg::with_name("rename")
+                                .long("rename")
+                                .help("New display name")
+                                .takes_value(true),
+                        ),
                 ),
         )
         .subcommand(
@@ -178,10 +274,87 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         .help("Profile to use for launch")
                         .takes_value(true)
                         .required(true),
+                )
+                .arg(
+                    Arg::with_name("join")
+                        .long("join")
+                        .help("Session ID or session URL to join on startup")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("data-path")
+                        .long("data-path")
+                        .help("Override -DataPath for this launch only")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("cache-path")
+                        .long("cache-path")
+                        .help("Override -CachePath for this launch only")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("extra-args")
+                        .long("extra-args")
+                        .help("Launch argument to add for this launch only, with its value if any (e.g. \"-Device SteamVR\")")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .allow_hyphen_values(true),
+                )
+                .arg(
+                    Arg::with_name("debug-log")
+                        .long("debug-log")
+                        .help("Launch with verbose logging for troubleshooting (this launch only)"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("mods")
+                .about("Manage MODs of a profile")
+                .subcommand(
+                    SubCommand::with_name("list")
+                        .about("List installed MODs")
+                        .arg(Arg::with_name("profile").help("Profile name").required(true)),
+                )
+                .subcommand(SubCommand::with_name("available").about("List MODs in the MOD manifest"))
+                .subcommand(
+                    SubCommand::with_name("install")
+                        .about("Install a MOD from a GitHub repository")
+                        .arg(Arg::with_name("profile").help("Profile name").required(true))
+                        .arg(Arg::with_name("repo_url").help("GitHub repository URL").required(true))
+                        .arg(
+                            Arg::with_name("version")
+                                .short("v")
+                                .long("version")
+                                .help("Release tag to install (default: latest)")
+                                .takes_value(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("uninstall")
+                        .about("Uninstall a MOD")
+                        .arg(Arg::with_name("profile").help("Profile name").required(true))
+                        .arg(Arg::with_name("name").help("MOD name").required(true))
+                        .arg(
+                            Arg::with_name("force")
+                                .short("f")
+                                .long("force")
+                                .help("Uninstall even if other MODs depend on it"),
+                        ),
                 ),
         )
         .get_matches();
 
+    let json = json_requested(&matches);
+    // 標準出力をJSONだけにするため、ライブラリの進捗ログは標準エラー出力に回す
+    utils::set_log_to_stderr(json);
+
+    // MOD管理はDepotDownloaderを使わないため、DepotDownloaderが無い環境（CIなど）でも実行できるようにする
+    if matches.subcommand_name() != Some("mods") {
+        // DepotDownloaderの存在確認
+        depot_downloader.check_exists()?;
+    }
+
     match matches.subcommand() {
         ("install", Some(sub_m)) => {
             let branch = sub_m.value_of("branch").unwrap_or("release").to_string();
@@ -193,8 +366,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 None,
                 sub_m.value_of("username").map(String::from),
                 sub_m.value_of("password").map(String::from),
-            );
-            install.install(&depot_downloader, &profile_manager)?;
+            )
+            .with_overwrite(sub_m.is_present("force"))
+            .with_auth_code(sub_m.value_of("auth_code").map(String::from))
+            .with_beta_password(sub_m.value_of("beta_password").map(String::from));
+
+            match install.install(&depot_downloader, &profile_manager) {
+                Err(e) if !json && e.is::<InstallConflictError>() => {
+                    // 衝突内容を示して上書きの確認を求める
+                    println!("{}", e);
+                    if !confirm("Overwrite the installation folder?")? {
+                        println!("Cancelled");
+                        return Ok(());
+                    }
+                    install.with_overwrite(true).install(&depot_downloader, &profile_manager)?;
+                }
+                result => result?,
+            }
         }
         ("update", Some(sub_m)) => {
             let branch = sub_m.value_of("branch").unwrap_or("release").to_string();
@@ -206,8 +394,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 None,
                 sub_m.value_of("username").map(String::from),
                 sub_m.value_of("password").map(String::from),
-            );
-            install.update(&depot_downloader, &profile_manager)?;
+            )
+            .with_overwrite(sub_m.is_present("force"))
+            .with_auth_code(sub_m.value_of("auth_code").map(String::from))
+            .with_beta_password(sub_m.value_of("beta_password").map(String::from));
+
+            match install.update(&depot_downloader, &profile_manager) {
+                Err(e) if !json && e.is::<InstallConflictError>() => {
+                    // 衝突内容を示して上書きの確認を求める
+                    println!("{}", e);
+                    if !confirm("Overwrite the installation folder?")? {
+                        println!("Cancelled");
+                        return Ok(());
+                    }
+                    install.with_overwrite(true).update(&depot_downloader, &profile_manager)?;
+                }
+                result => result?,
+            }
         }
         ("check", Some(sub_m)) => {
             let branch = sub_m.value_of("branch").unwrap_or("release").to_string();
@@ -219,9 +422,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 None,
                 sub_m.value_of("username").map(String::from),
                 sub_m.value_of("password").map(String::from),
-            );
+            )
+            .with_beta_password(sub_m.value_of("beta_password").map(String::from));
             let has_updates = install.check_updates(&depot_downloader, &profile_manager)?;
-            if has_updates {
+            if json {
+                println!("{}", serde_json::json!({ "branch": install.branch, "update_available": has_updates }));
+            } else if has_updates {
                 println!("Updates are available for Resonite.");
             } else {
                 println!("Resonite is up to date.");
@@ -248,7 +454,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
             ("list", Some(_)) => match profile_manager.list_profiles() {
                 Ok(profiles) => {
-                    if profiles.is_empty() {
+                    if json {
+                        println!("{}", serde_json::to_string_pretty(&profiles)?);
+                    } else if profiles.is_empty() {
                         println!("No profiles found. Create one with 'profiles new <name>'");
                     } else {
                         println!("Available profiles:");
@@ -258,11 +466,72 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
                 Err(e) => {
-                    eprintln!("Failed to list profiles: {}", e);
+                    return Err(format!("Failed to list profiles: {}", e).into());
                 }
             },
-            ("edit", Some(_)) => {
-                println!("Profile editing is not implemented yet. Please edit the launchconfig.json file manually.");
+            ("delete", Some(delete_m)) => {
+                let profile_name = delete_m.value_of("name").unwrap();
+                if profile_name == "default" {
+                    return Err("Cannot delete the default profile".into());
+                }
+                if let Err(e) = profile_manager.get_profile(profile_name) {
+                    return Err(format!("Profile '{}' not found: {}", profile_name, e).into());
+                }
+
+                if !delete_m.is_present("force") && !confirm(&format!(
+                    "Delete profile '{}' and all its data (including the installed game and MODs)?",
+                    profile_name
+                ))? {
+                    println!("Cancelled");
+                    return Ok(());
+                }
+
+                // 削除前にプロファイルのディレクトリを取得
+                let profile_dir = profile_manager.get_profile_dir(profile_name);
+                if let Err(e) = profile_manager.delete_profile(profile_name) {
+                    return Err(format!("Failed to delete profile: {}", e).into());
+                }
+                if profile_dir.exists() {
+                    std::fs::remove_dir_all(&profile_dir)?;
+                }
+                println!("Profile '{}' deleted successfully", profile_name);
+            }
+            ("edit", Some(edit_m)) => {
+                let profile_name = edit_m.value_of("name").unwrap();
+                let mut profile = match profile_manager.get_profile(profile_name) {
+                    Ok(profile) => profile,
+                    Err(e) => {
+                        return Err(e);
+                    }
+                };
+
+                if let Some(description) = edit_m.value_of("description") {
+                    profile.description = description.to_string();
+                }
+                if let Some(new_name) = edit_m.value_of("rename") {
+                    let new_name = new_name.trim();
+                    if new_name.is_empty() {
+                        return Err("Profile name cannot be empty".into());
+                    }
+                    // フォルダ名（ID）は変えずに表示名のみ変更
+                    profile.display_name = new_name.to_string();
+                }
+                for name in edit_m.values_of("remove-arg").into_iter().flatten() {
+                    if !launch_args::remove_launch_arg(&mut profile.args, name) {
+                        println!("Launch argument '{}' was not set", name);
+                    }
+                }
+                for arg in edit_m.values_of("add-arg").into_iter().flatten() {
+                    if let Err(e) = launch_args::add_launch_arg(&mut profile.args, arg) {
+                        return Err(format!("Invalid launch argument: {}", e).into());
+                    }
+                }
+
+                profile_manager.update_profile(&profile)?;
+
+                let config_path = profile_manager.get_profile_dir(profile.get_folder_name()).join("launchconfig.json");
+                println!("Profile '{}' updated:", profile.get_display_name());
+                println!("{}", std::fs::read_to_string(config_path)?);
             }
             _ => {
                 println!("Unknown profiles command. Use --help for usage information.");
@@ -275,8 +544,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             // ブランチ名の検証
             utils::validate_branch(branch)?;
 
+            // 引数の上書きはこの起動だけに適用し、プロファイルは変更しない
+            let overrides = launch_args::LaunchOverrides {
+                join_session: launch_m.value_of("join").map(String::from),
+                data_path: launch_m.value_of("data-path").map(String::from),
+                cache_path: launch_m.value_of("cache-path").map(String::from),
+                extra_args: launch_m.values_of("extra-args").into_iter().flatten().map(String::from).collect(),
+                debug_logging: launch_m.is_present("debug-log"),
+            };
+
             // Resoniteを起動
-            install_manager.launch_with_profile(profile_name, &profile_manager)?;
+            install_manager.launch_with_overrides(profile_name, &profile_manager, &overrides)?;
+        }
+        ("mods", Some(mods_m)) => {
+            // MOD管理は非同期のため、tokioランタイム上で実行
+            let runtime = tokio::runtime::Runtime::new()?;
+            runtime.block_on(run_mods_command(mods_m, &profile_manager, &exe_dir, json))?;
         }
         _ => {
             println!("No command specified. Use --help for usage information.");
@@ -285,3 +568,119 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// y/N の確認プロンプトを表示し、yが入力された場合のみtrueを返す
+fn confirm(message: &str) -> Result<bool, Box<dyn std::error::Error>> {
+    use std::io::Write;
+
+    print!("{} [y/N]: ", message);
+    std::io::stdout().flush()?;
+
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// プロファイル用のModManagerを作成（キャッシュはGUIと共有）
+fn create_mod_manager(profile_dir: std::path::PathBuf, exe_dir: &std::path::Path) -> ModManager {
+    ModManager::with_shared_cache(profile_dir, exe_dir.join("cache"))
+}
+
+/// `mods` サブコマンドを実行
+async fn run_mods_command(
+    mods_m: &clap::ArgMatches<'_>,
+    profile_manager: &ProfileManager,
+    exe_dir: &std::path::Path,
+    json: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match mods_m.subcommand() {
+        ("list", Some(list_m)) => {
+            let profile_name = list_m.value_of("profile").unwrap();
+            profile_manager.get_profile(profile_name)?;
+            let mod_manager = create_mod_manager(profile_manager.get_profile_dir(profile_name), exe_dir);
+
+            let installed_mods = mod_manager.get_installed_mods().map_err(|e| e.to_string())?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&installed_mods)?);
+            } else if installed_mods.is_empty() {
+                println!("No MODs installed in profile '{}'", profile_name);
+            } else {
+                println!("Installed MODs in profile '{}':", profile_name);
+                for installed_mod in installed_mods {
+                    let disabled = if installed_mod.enabled == Some(false) { " (disabled)" } else { "" };
+                    println!(
+                        "  - {} {}{} : {}",
+                        installed_mod.name, installed_mod.installed_version, disabled, installed_mod.source_location
+                    );
+                }
+            }
+        }
+        ("available", Some(_)) => {
+            let mod_manager = create_mod_manager(std::env::temp_dir(), exe_dir);
+
+            let mods = mod_manager.fetch_mod_manifest().await.map_err(|e| e.to_string())?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&mods)?);
+                return Ok(());
+            }
+            println!("Available MODs ({}):", mods.len());
+            for mod_info in mods {
+                println!(
+                    "  - {} {} : {}",
+                    mod_info.name,
+                    mod_info.latest_version.as_deref().unwrap_or("-"),
+                    mod_info.source_location
+                );
+            }
+        }
+        ("install", Some(install_m)) => {
+            let profile_name = install_m.value_of("profile").unwrap();
+            let repo_url = install_m.value_of("repo_url").unwrap();
+            let profile = profile_manager.get_profile(profile_name)?;
+            let mod_manager = create_mod_manager(profile_manager.get_profile_dir(profile_name), exe_dir);
+
+            let mod_loader_type = profile.mod_loader_type.map(|t| match t {
+                ModLoaderType::ResoniteModLoader => "ResoniteModLoader",
+                ModLoaderType::MonkeyLoader => "MonkeyLoader",
+                ModLoaderType::BepisLoader => "BepisLoader",
+            });
+
+            let installed_mod = mod_manager
+                .install_mod_from_github(repo_url, install_m.value_of("version"), mod_loader_type)
+                .await
+                .map_err(|e| e.to_string())?;
+            println!(
+                "Installed {} {} to profile '{}'",
+                installed_mod.name, installed_mod.installed_version, profile_name
+            );
+        }
+        ("uninstall", Some(uninstall_m)) => {
+            let profile_name = uninstall_m.value_of("profile").unwrap();
+            let mod_name = uninstall_m.value_of("name").unwrap();
+            profile_manager.get_profile(profile_name)?;
+            let mod_manager = create_mod_manager(profile_manager.get_profile_dir(profile_name), exe_dir);
+
+            if !mod_manager.get_installed_mods().map_err(|e| e.to_string())?.iter().any(|m| m.name == mod_name) {
+                return Err(format!("MOD '{}' is not installed in profile '{}'", mod_name, profile_name).into());
+            }
+            match mod_manager.uninstall_mod_checked(mod_name, uninstall_m.is_present("force")).await {
+                Err(e @ ModManagerError::HasDependents { .. }) if !json => {
+                    // 依存しているMODを示して削除の確認を求める
+                    println!("{}", e);
+                    if !confirm("Uninstall anyway?")? {
+                        println!("Cancelled");
+                        return Ok(());
+                    }
+                    mod_manager.uninstall_mod(mod_name).map_err(|e| e.to_string())?;
+                }
+                result => result.map_err(|e| e.to_string())?,
+            }
+            println!("Uninstalled {} from profile '{}'", mod_name, profile_name);
+        }
+        _ => {
+            println!("Unknown mods command. Use --help for usage information.");
+        }
+    }
+
+    Ok(())
+}
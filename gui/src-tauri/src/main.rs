@@ -4,20 +4,36 @@
 mod wsrelay;
 mod logviewer;
 
-use std::sync::Mutex;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, OnceLock};
 use std::path::PathBuf;
 use tauri::{State, Window, AppHandle, Manager};
 use reso_launcher_lib::{
-    depotdownloader::DepotDownloader,
-    install::{ResoniteInstall, ResoniteInstallManager},
-    profile::{Profile, ProfileManager},
+    depotdownloader::{DepotDownloader, RuntimeStatus, SteamGuardRequiredError, DOTNET_DOWNLOAD_URL},
+    install::{InstallConflict, InstallSizeEstimate, InstallStatus, LogFileInfo, ResoniteInstall, ResoniteInstallManager},
+    install_rules::INSTALL_RULES_FILE,
+    launch_stats::{LaunchRecord, LaunchStats},
+    launch_args::{self, ArgSpec, PlaceholderVariable},
+    launch_lock,
+    loader_migration::{LoaderMigrator, MigrationReport},
+    preflight::{self, PreflightReport},
+    version_history::VersionRecord,
+    profile::{sort_profiles_for_display, Profile, ProfileManager},
+    profile_template::{PendingTemplateSetup, ProfileDefaults, ProfileTemplate, ProfileTemplateManager},
     mod_loader::ModLoader,
     mod_loader_type::ModLoaderType,
     monkey_loader::MonkeyLoader,
-    mod_manager::{ModManager, ModInfo, InstalledMod, GitHubRelease, ModRelease, UnmanagedMod, MultiFileInstallRequest, FileInstallChoice, UpgradeableMod},
+    mod_config::{ConfigSchema, ModConfig, ModConfigMap},
+    download_limiter::DownloadLimiter,
+    game_dedup::DedupReport,
+    mod_history::{ModHistoryManager, RecentlyInstalledMod},
+    crash_report::{CrashReport, CrashReportManager},
+    webhook::WebhookConfig,
+    mod_install_queue::{ModInstallQueue, QueueItem, QueueSnapshot},
+    mod_manager::{ModManager, ModManagerConfig, ModInfo, InstalledMod, InstalledModSource, GitHubRelease, RateLimitInfo, ModRelease, UnmanagedMod, MultiFileInstallRequest, FileInstallChoice, UpgradeableMod, UpgradeableModGroup, ModImportResult, BatchProgress, UnmaintainedMod, OutputFormat, CacheRefreshResult, CachedUpgradeableMods, VersionChangelog, PlannedModChange, ModReloadMode, ModLoaderMismatch, suggest_artifact_choices},
     thunderstore::{ThunderstoreClient, ThunderstorePackage},
     bepis_loader::{BepisLoader, BepisLoaderStatus, BepisLoaderInfo, InstalledBepisMod},
-    utils,
+    utils::{self, MissingDependency},
 };
 use std::process::Command;
 
@@ -27,6 +43,7 @@ struct AppState {
     profile_manager: Option<ProfileManager>,
     install_manager: Option<ResoniteInstallManager>,
     exe_dir: Option<PathBuf>,
+    install_queue: InstallQueue,
 }
 
 impl Default for AppState {
@@ -36,10 +53,24 @@ impl Default for AppState {
             profile_manager: None,
             install_manager: None,
             exe_dir: None,
+            install_queue: InstallQueue::default(),
         }
     }
 }
 
+// Number of finished game installs kept in the queue status (oldest are dropped first)
+const MAX_COMPLETED_INSTALLS: usize = 20;
+
+// Game installs run one at a time so DepotDownloader logins don't conflict
+#[derive(Default)]
+struct InstallQueue {
+    next_id: u64,
+    queued: VecDeque<(InstallQueueEntry, GameInstallRequest)>,
+    running: Option<InstallQueueEntry>,
+    completed: Vec<InstallQueueResult>,
+    worker_active: bool,
+}
+
 // WebSocket Relay state (separate from AppState for async access)
 type WsRelayStateHandle = std::sync::Arc<tokio::sync::RwLock<wsrelay::WsRelayState>>;
 
@@ -62,15 +93,69 @@ pub struct ProfileInfo {
     pub version: Option<String>,
     pub has_mod_loader: bool,
     pub mod_loader_type: Option<ModLoaderType>,
+    pub auto_update_game: bool,
+    pub pinned_manifest_id: Option<String>,
+    /// このプロファイルのResoniteが起動中か
+    pub is_running: bool,
+    /// 一覧の上部に固定されているか
+    pub pinned: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct GameInstallRequest {
     pub profile_name: String,
+    /// 空の場合はプロファイルの既定のブランチ（未設定ならrelease）
+    #[serde(default)]
     pub branch: String,
     pub manifest_id: Option<String>,
     pub username: Option<String>,
     pub password: Option<String>,
+    /// ネットワーク切断時に復帰を待って自動で再開する
+    #[serde(default)]
+    pub wait_for_network: bool,
+    /// インストール先に別のファイル・別ブランチがあっても上書きする（ユーザーが確認済み）
+    #[serde(default)]
+    pub overwrite: bool,
+    /// Steam Guardの認証コード（2FA）
+    #[serde(default)]
+    pub auth_code: Option<String>,
+    /// パスワード付きのbetaブランチのパスワード
+    #[serde(default)]
+    pub beta_password: Option<String>,
+}
+
+impl GameInstallRequest {
+    // Use the profile's default branch when the request leaves it empty (first install of a new profile)
+    fn with_profile_branch(mut self, profile_manager: &ProfileManager) -> Self {
+        if self.branch.is_empty() {
+            self.branch = profile_manager.get_profile(&self.profile_name).ok()
+                .and_then(|profile| profile.default_branch)
+                .unwrap_or_else(|| "release".to_string());
+        }
+        self
+    }
+}
+
+#[derive(serde::Serialize, Clone)]
+pub struct InstallQueueEntry {
+    pub id: u64,
+    pub profile_name: String,
+    pub branch: String,
+}
+
+#[derive(serde::Serialize, Clone)]
+pub struct InstallQueueResult {
+    #[serde(flatten)]
+    pub entry: InstallQueueEntry,
+    pub success: bool,
+    pub message: String,
+}
+
+#[derive(Clone, serde::Serialize)]
+pub struct InstallQueueStatus {
+    pub queued: Vec<InstallQueueEntry>,
+    pub running: Option<InstallQueueEntry>,
+    pub completed: Vec<InstallQueueResult>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -78,6 +163,11 @@ pub struct UnifiedModLoaderInfo {
     pub installed: bool,
     pub loader_type: Option<ModLoaderType>,
     pub version: Option<String>,
+    /// 新しいバージョンがあるか（`check_mod_loader_update` で確認した場合のみ）
+    #[serde(default)]
+    pub update_available: bool,
+    #[serde(default)]
+    pub latest_version: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -104,6 +194,12 @@ pub struct AppStatus {
     pub depot_downloader_available: bool,
     pub exe_dir: Option<String>,
     pub is_first_run: bool,
+    /// DepotDownloaderの実行に必要な.NETランタイムがあるか
+    pub dotnet_available: bool,
+    /// ランタイムが無い場合の公式ダウンロードページ
+    pub dotnet_download_url: Option<String>,
+    /// Resoniteの起動に必要で不足しているシステムの依存（VC++ ランタイムなど）
+    pub missing_dependencies: Vec<MissingDependency>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
@@ -112,6 +208,16 @@ pub struct SteamCredentials {
     pub password: String,
 }
 
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ModReloadGuidance {
+    /// 対象プロファイルのResoniteが起動中か
+    pub resonite_running: bool,
+    /// 起動中でもホットリロードで反映されるMOD
+    pub hot_reload: Vec<String>,
+    /// 反映にResoniteの再起動が必要なMOD
+    pub restart_required: Vec<String>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct YtDlpInfo {
     pub installed: bool,
@@ -119,6 +225,44 @@ pub struct YtDlpInfo {
     pub path: Option<String>,
 }
 
+#[derive(serde::Serialize)]
+pub struct AutoUpdateResult {
+    pub updated_profiles: Vec<String>,
+    // "<profile>: <error>" for each profile that failed to update
+    pub failed_profiles: Vec<String>,
+}
+
+#[derive(serde::Serialize)]
+pub struct TemplateSetupResult {
+    pub profile_id: String,
+    // The MOD loader / MODs are still waiting for the game to be installed
+    pub pending: bool,
+    pub mod_loader_message: Option<String>,
+    pub installed_mods: Vec<String>,
+    pub failed_mods: Vec<String>,
+}
+
+// Share the download concurrency limits across every ModManager in this process
+fn shared_download_limiter() -> Arc<DownloadLimiter> {
+    static LIMITER: OnceLock<Arc<DownloadLimiter>> = OnceLock::new();
+    LIMITER
+        .get_or_init(|| {
+            let config = ModManagerConfig::default();
+            Arc::new(DownloadLimiter::new(config.max_parallel_downloads, config.max_connections_per_host))
+        })
+        .clone()
+}
+
+// Create a ModManager that shares the MOD manifest cache across profiles
+fn create_mod_manager(profile_dir: PathBuf) -> ModManager {
+    let manager = match utils::get_executable_directory() {
+        Ok(exe_dir) => ModManager::with_shared_cache(profile_dir, exe_dir.join("cache"))
+            .with_install_rules_file(exe_dir.join(INSTALL_RULES_FILE)),
+        Err(_) => ModManager::new(profile_dir),
+    };
+    manager.with_download_limiter(shared_download_limiter())
+}
+
 // Check if this is the first run
 fn is_first_run(exe_dir: &std::path::Path) -> bool {
     let first_run_marker = exe_dir.join(".first_run_complete");
@@ -147,6 +291,16 @@ async fn initialize_app(state: State<'_, Mutex<AppState>>) -> Result<AppStatus,
             // Initialize DepotDownloader
             let depot_downloader = DepotDownloader::with_default_path(&dir);
             let depot_available = depot_downloader.check_exists().is_ok();
+            // Self-contained builds of DepotDownloader run without the .NET runtime
+            let dotnet_available = !depot_downloader.requires_runtime() || depot_downloader.check_runtime()
+                .map(|status| status.meets_requirement)
+                .unwrap_or(false);
+            
+            // Check system dependencies such as the VC++ runtime
+            let missing_dependencies = utils::check_system_dependencies().unwrap_or_else(|e| {
+                eprintln!("Failed to check system dependencies: {}", e);
+                Vec::new()
+            });
             
             app_state.depot_downloader = Some(depot_downloader);
             app_state.profile_manager = Some(ProfileManager::new(&dir));
@@ -157,17 +311,45 @@ async fn initialize_app(state: State<'_, Mutex<AppState>>) -> Result<AppStatus,
                 depot_downloader_available: depot_available,
                 exe_dir: Some(dir.to_string_lossy().to_string()),
                 is_first_run,
+                dotnet_available,
+                dotnet_download_url: (!dotnet_available).then(|| DOTNET_DOWNLOAD_URL.to_string()),
+                missing_dependencies,
             })
         }
         Err(e) => Err(format!("Failed to initialize: {}", e)),
     }
 }
 
+// Inspect the install folder for other files or another branch before installing
+#[tauri::command]
+async fn inspect_install_target(
+    request: GameInstallRequest,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<InstallConflict>, String> {
+    let app_state = state.lock().unwrap();
+
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+    let request = request.with_profile_branch(profile_manager);
+
+    let install = ResoniteInstall::new(
+        request.profile_name,
+        request.branch,
+        request.manifest_id,
+        None,
+        None,
+    );
+
+    install.inspect_install_target(profile_manager)
+        .map_err(|e| format!("Failed to inspect install folder: {}", e))
+}
+
 // Install Resonite to a profile
 #[tauri::command]
 async fn install_game_to_profile(
     request: GameInstallRequest,
     state: State<'_, Mutex<AppState>>,
+    app: AppHandle,
 ) -> Result<String, String> {
     let app_state = state.lock().unwrap();
     
@@ -176,6 +358,7 @@ async fn install_game_to_profile(
     
     let profile_manager = app_state.profile_manager.as_ref()
         .ok_or("Profile manager not initialized")?;
+    let request = request.with_profile_branch(profile_manager);
     
     let install = ResoniteInstall::new(
         request.profile_name.clone(),
@@ -183,14 +366,26 @@ async fn install_game_to_profile(
         request.manifest_id.clone(),
         request.username,
         request.password,
-    );
+    ).with_wait_for_network(request.wait_for_network).with_overwrite(request.overwrite).with_auth_code(request.auth_code).with_beta_password(request.beta_password);
     
     install.install(depot_downloader, profile_manager)
         .map_err(|e| format!("Installation failed: {}", e))?;
+    apply_template_setup_after_install(&app, &request.profile_name);
     
     Ok(format!("Resonite {} branch installed successfully to profile '{}'", request.branch, request.profile_name))
 }
 
+// Ask the frontend for a Steam Guard code; the install is retried with `auth_code` set
+fn emit_needs_2fa(window: &Window, profile_name: &str, branch: &str, message: &str) {
+    let _ = window.emit("installation-status", serde_json::json!({
+        "profile_name": profile_name,
+        "branch": branch,
+        "message": message,
+        "is_complete": false,
+        "needs_2fa": true
+    }));
+}
+
 // Install Resonite to a profile (Auto-fallback Mode)
 #[tauri::command]
 async fn install_game_to_profile_interactive(
@@ -205,6 +400,7 @@ async fn install_game_to_profile_interactive(
     
     let profile_manager = app_state.profile_manager.as_ref()
         .ok_or("Profile manager not initialized")?;
+    let request = request.with_profile_branch(profile_manager);
     
     let install = ResoniteInstall::new(
         request.profile_name.clone(),
@@ -212,24 +408,28 @@ async fn install_game_to_profile_interactive(
         request.manifest_id.clone(),
         request.username,
         request.password,
-    );
+    ).with_wait_for_network(request.wait_for_network).with_overwrite(request.overwrite).with_auth_code(request.auth_code).with_beta_password(request.beta_password);
 
     let profile_name = request.profile_name.clone();
     let branch = request.branch.clone();
     let window_clone = window.clone();
     
     // 自動フォールバック機能を使用
-    install.install_with_fallback(depot_downloader, profile_manager, move |status_message, is_complete| {
+    install.install_with_fallback(depot_downloader, profile_manager, move |status_message, status| {
         // ステータス更新をGUIに送信
         let _ = window_clone.emit("installation-status", serde_json::json!({
             "profile_name": profile_name,
             "branch": branch,
             "message": status_message,
-            "is_complete": is_complete
+            "is_complete": status.is_complete(),
+            "needs_2fa": false
         }));
 
-        if is_complete {
-            let success = status_message.contains("完了しました") && !status_message.contains("失敗");
+        if status.is_complete() {
+            let success = status == InstallStatus::Succeeded;
+            if success {
+                apply_template_setup_after_install(&window_clone.app_handle(), &profile_name);
+            }
             let final_message = if success {
                 format!("Installation completed for profile '{}' ({})", profile_name, branch)
             } else {
@@ -243,11 +443,205 @@ async fn install_game_to_profile_interactive(
                 "message": final_message
             }));
         }
-    }).map_err(|e| format!("Installation failed: {}", e))?;
+    }).map_err(|e| {
+        if e.is::<SteamGuardRequiredError>() {
+            emit_needs_2fa(&window, &request.profile_name, &request.branch, &e.to_string());
+        }
+        format!("Installation failed: {}", e)
+    })?;
     
     Ok(format!("Resonite {} branch installation started for profile '{}' (auto-fallback enabled)", request.branch, request.profile_name))
 }
 
+fn install_queue_status(queue: &InstallQueue) -> InstallQueueStatus {
+    InstallQueueStatus {
+        queued: queue.queued.iter().map(|(entry, _)| entry.clone()).collect(),
+        running: queue.running.clone(),
+        completed: queue.completed.clone(),
+    }
+}
+
+fn emit_install_queue_status(app: &AppHandle) {
+    let status = {
+        let state = app.state::<Mutex<AppState>>();
+        let app_state = state.lock().unwrap();
+        install_queue_status(&app_state.install_queue)
+    };
+    let _ = app.emit_all("install-queue-status", status);
+}
+
+// Add a game install to the queue; installs run one after another in the background
+#[tauri::command]
+async fn enqueue_install(
+    request: GameInstallRequest,
+    state: State<'_, Mutex<AppState>>,
+    app: AppHandle,
+) -> Result<u64, String> {
+    let (id, start_worker) = {
+        let mut app_state = state.lock().unwrap();
+        let request = match app_state.profile_manager.as_ref() {
+            Some(profile_manager) => request.with_profile_branch(profile_manager),
+            None => request,
+        };
+        let queue = &mut app_state.install_queue;
+
+        queue.next_id += 1;
+        let entry = InstallQueueEntry {
+            id: queue.next_id,
+            profile_name: request.profile_name.clone(),
+            branch: request.branch.clone(),
+        };
+        queue.queued.push_back((entry, request));
+
+        let start_worker = !queue.worker_active;
+        queue.worker_active = true;
+        (queue.next_id, start_worker)
+    }; // MutexGuard is dropped here
+
+    emit_install_queue_status(&app);
+    if start_worker {
+        std::thread::spawn(move || run_install_queue(app));
+    }
+    Ok(id)
+}
+
+// Remove a queued install that has not started yet
+#[tauri::command]
+async fn cancel_queued_install(
+    id: u64,
+    state: State<'_, Mutex<AppState>>,
+    app: AppHandle,
+) -> Result<bool, String> {
+    let cancelled = {
+        let mut app_state = state.lock().unwrap();
+        let queued = &mut app_state.install_queue.queued;
+        let before = queued.len();
+        queued.retain(|(entry, _)| entry.id != id);
+        queued.len() != before
+    };
+
+    if cancelled {
+        emit_install_queue_status(&app);
+    }
+    Ok(cancelled)
+}
+
+// Get the queued, running and completed game installs
+#[tauri::command]
+async fn get_install_queue_status(state: State<'_, Mutex<AppState>>) -> Result<InstallQueueStatus, String> {
+    let app_state = state.lock().unwrap();
+    Ok(install_queue_status(&app_state.install_queue))
+}
+
+// Worker thread: run queued installs one by one until the queue is empty
+fn run_install_queue(app: AppHandle) {
+    loop {
+        let job = {
+            let state = app.state::<Mutex<AppState>>();
+            let mut app_state = state.lock().unwrap();
+
+            let Some((entry, request)) = app_state.install_queue.queued.pop_front() else {
+                app_state.install_queue.worker_active = false;
+                break;
+            };
+
+            match (app_state.depot_downloader.clone(), app_state.profile_manager.clone()) {
+                (Some(depot_downloader), Some(profile_manager)) => {
+                    app_state.install_queue.running = Some(entry.clone());
+                    Ok((entry, request, depot_downloader, profile_manager))
+                }
+                _ => Err(InstallQueueResult {
+                    entry,
+                    success: false,
+                    message: "DepotDownloader or profile manager not initialized".to_string(),
+                }),
+            }
+        }; // MutexGuard is dropped here
+
+        let result = match job {
+            Ok((entry, request, depot_downloader, profile_manager)) => {
+                emit_install_queue_status(&app);
+                run_queued_install(&app, entry, request, &depot_downloader, &profile_manager)
+            }
+            Err(result) => result,
+        };
+
+        {
+            let state = app.state::<Mutex<AppState>>();
+            let mut app_state = state.lock().unwrap();
+            app_state.install_queue.running = None;
+            let completed = &mut app_state.install_queue.completed;
+            completed.push(result);
+            if completed.len() > MAX_COMPLETED_INSTALLS {
+                completed.drain(..completed.len() - MAX_COMPLETED_INSTALLS);
+            }
+        }
+        emit_install_queue_status(&app);
+    }
+
+    emit_install_queue_status(&app);
+}
+
+// Run one queued install, waiting for an interactive fallback to finish before the next one starts
+fn run_queued_install(
+    app: &AppHandle,
+    entry: InstallQueueEntry,
+    request: GameInstallRequest,
+    depot_downloader: &DepotDownloader,
+    profile_manager: &ProfileManager,
+) -> InstallQueueResult {
+    let install = ResoniteInstall::new(
+        request.profile_name.clone(),
+        request.branch.clone(),
+        request.manifest_id.clone(),
+        request.username,
+        request.password,
+    ).with_wait_for_network(request.wait_for_network).with_overwrite(request.overwrite).with_auth_code(request.auth_code).with_beta_password(request.beta_password);
+
+    let (complete_tx, complete_rx) = std::sync::mpsc::channel::<(String, InstallStatus)>();
+    let status_app = app.clone();
+    let profile_name = entry.profile_name.clone();
+    let branch = entry.branch.clone();
+
+    let result = install.install_with_fallback(depot_downloader, profile_manager, move |status_message, status| {
+        let _ = status_app.emit_all("installation-status", serde_json::json!({
+            "profile_name": profile_name,
+            "branch": branch,
+            "message": status_message,
+            "is_complete": status.is_complete(),
+            "needs_2fa": false
+        }));
+        if status.is_complete() {
+            let _ = complete_tx.send((status_message.to_string(), status));
+        }
+    });
+
+    let (success, message) = match result {
+        Err(e) => {
+            if e.is::<SteamGuardRequiredError>() {
+                let _ = app.emit_all("installation-status", serde_json::json!({
+                    "profile_name": entry.profile_name,
+                    "branch": entry.branch,
+                    "message": e.to_string(),
+                    "is_complete": false,
+                    "needs_2fa": true
+                }));
+            }
+            (false, format!("Installation failed: {}", e))
+        }
+        // The callback is dropped without reporting completion if the interactive window goes away
+        Ok(()) => match complete_rx.recv() {
+            Ok((message, status)) => (status == InstallStatus::Succeeded, message),
+            Err(_) => (false, "Installation finished without reporting a result".to_string()),
+        },
+    };
+
+    if success {
+        apply_template_setup_after_install(app, &entry.profile_name);
+    }
+    InstallQueueResult { entry, success, message }
+}
+
 // Update Resonite in a profile
 #[tauri::command]
 async fn update_profile_game(
@@ -268,7 +662,7 @@ async fn update_profile_game(
         request.manifest_id.clone(),
         request.username,
         request.password,
-    );
+    ).with_wait_for_network(request.wait_for_network).with_auth_code(request.auth_code).with_beta_password(request.beta_password);
     
     install.update(depot_downloader, profile_manager)
         .map_err(|e| format!("Update failed: {}", e))?;
@@ -297,24 +691,25 @@ async fn update_profile_game_interactive(
         request.manifest_id.clone(),
         request.username,
         request.password,
-    );
+    ).with_wait_for_network(request.wait_for_network).with_auth_code(request.auth_code).with_beta_password(request.beta_password);
 
     let profile_name = request.profile_name.clone();
     let branch = request.branch.clone();
     let window_clone = window.clone();
     
     // 自動フォールバック機能を使用
-    install.update_with_fallback(depot_downloader, profile_manager, move |status_message, is_complete| {
+    install.update_with_fallback(depot_downloader, profile_manager, move |status_message, status| {
         // ステータス更新をGUIに送信
         let _ = window_clone.emit("installation-status", serde_json::json!({
             "profile_name": profile_name,
             "branch": branch,
             "message": status_message,
-            "is_complete": is_complete
+            "is_complete": status.is_complete(),
+            "needs_2fa": false
         }));
 
-        if is_complete {
-            let success = status_message.contains("完了しました") && !status_message.contains("失敗");
+        if status.is_complete() {
+            let success = status == InstallStatus::Succeeded;
             let final_message = if success {
                 format!("Update completed for profile '{}' ({})", profile_name, branch)
             } else {
@@ -328,11 +723,112 @@ async fn update_profile_game_interactive(
                 "message": final_message
             }));
         }
-    }).map_err(|e| format!("Update failed: {}", e))?;
+    }).map_err(|e| {
+        if e.is::<SteamGuardRequiredError>() {
+            emit_needs_2fa(&window, &request.profile_name, &request.branch, &e.to_string());
+        }
+        format!("Update failed: {}", e)
+    })?;
     
     Ok(format!("Resonite {} branch update started for profile '{}' (auto-fallback enabled)", request.branch, request.profile_name))
 }
 
+// Estimate the download size from the manifest and compare it with the free space of the install drive
+#[tauri::command]
+async fn estimate_install_size(
+    request: GameInstallRequest,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<InstallSizeEstimate, String> {
+    let (depot_downloader, profile_manager) = {
+        let app_state = state.lock().unwrap();
+
+        let depot_downloader = app_state.depot_downloader.as_ref()
+            .ok_or("DepotDownloader not initialized")?
+            .clone();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?
+            .clone();
+
+        (depot_downloader, profile_manager)
+    }; // MutexGuard is dropped here
+
+    // Fetching the manifest runs DepotDownloader, so keep it off the async runtime
+    tokio::task::spawn_blocking(move || {
+        let request = request.with_profile_branch(&profile_manager);
+        let install = ResoniteInstall::new(
+            request.profile_name,
+            request.branch,
+            request.manifest_id,
+            request.username,
+            request.password,
+        ).with_beta_password(request.beta_password);
+
+        install.estimate_install_space(&depot_downloader, &profile_manager)
+            .map_err(|e| format!("Failed to estimate install size: {}", e))
+    })
+    .await
+    .map_err(|e| format!("Install size estimation task failed: {}", e))?
+}
+
+// Verify installed game files against the manifest
+#[tauri::command]
+async fn verify_profile_game(
+    request: GameInstallRequest,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<String>, String> {
+    let app_state = state.lock().unwrap();
+
+    let depot_downloader = app_state.depot_downloader.as_ref()
+        .ok_or("DepotDownloader not initialized")?;
+
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+
+    let install = ResoniteInstall::new(
+        request.profile_name,
+        request.branch,
+        request.manifest_id,
+        request.username,
+        request.password,
+    ).with_beta_password(request.beta_password);
+
+    let corrupted_files = install.verify(depot_downloader, profile_manager)
+        .map_err(|e| format!("Verification failed: {}", e))?;
+
+    Ok(corrupted_files.iter().map(|p| p.to_string_lossy().to_string()).collect())
+}
+
+// Re-download only the corrupted game files found by verify_profile_game
+#[tauri::command]
+async fn repair_profile_game(
+    request: GameInstallRequest,
+    corrupted_files: Vec<String>,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<String, String> {
+    let app_state = state.lock().unwrap();
+
+    let depot_downloader = app_state.depot_downloader.as_ref()
+        .ok_or("DepotDownloader not initialized")?;
+
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+
+    let install = ResoniteInstall::new(
+        request.profile_name.clone(),
+        request.branch,
+        request.manifest_id,
+        request.username,
+        request.password,
+    ).with_wait_for_network(request.wait_for_network).with_beta_password(request.beta_password);
+
+    let file_count = corrupted_files.len();
+    install.repair(depot_downloader, profile_manager, corrupted_files.into_iter().map(PathBuf::from).collect())
+        .map_err(|e| format!("Repair failed: {}", e))?;
+
+    Ok(format!("Repaired {} files in profile '{}'", file_count, request.profile_name))
+}
+
 // Check for updates in a profile
 #[tauri::command]
 async fn check_profile_updates(
@@ -353,12 +849,98 @@ async fn check_profile_updates(
         request.manifest_id.clone(),
         request.username,
         request.password,
-    );
+    ).with_beta_password(request.beta_password);
     
     install.check_updates(depot_downloader, profile_manager)
         .map_err(|e| format!("Update check failed: {}", e))
 }
 
+// Apply game updates to every profile according to its auto-update / pin settings
+#[tauri::command]
+async fn auto_update_profile_games(
+    username: Option<String>,
+    password: Option<String>,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<AutoUpdateResult, String> {
+    let (depot_downloader, profile_manager) = {
+        let app_state = state.lock().unwrap();
+        let depot_downloader = app_state.depot_downloader.as_ref()
+            .ok_or("DepotDownloader not initialized")?
+            .clone();
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?
+            .clone();
+        (depot_downloader, profile_manager)
+    }; // MutexGuard is dropped here
+
+    tokio::task::spawn_blocking(move || {
+        let profiles = profile_manager.list_profiles()
+            .map_err(|e| format!("Failed to list profiles: {}", e))?;
+
+        // A failure in one profile doesn't stop the others from being updated
+        let mut result = AutoUpdateResult {
+            updated_profiles: Vec::new(),
+            failed_profiles: Vec::new(),
+        };
+        for profile in profiles {
+            let Some(game_info) = profile.game_info.as_ref() else {
+                continue;
+            };
+
+            let install = ResoniteInstall::new(
+                profile.get_folder_name().to_string(),
+                game_info.branch.clone(),
+                None,
+                username.clone(),
+                password.clone(),
+            );
+
+            match install.auto_update(&depot_downloader, &profile_manager) {
+                Ok(true) => result.updated_profiles.push(profile.get_folder_name().to_string()),
+                Ok(false) => {}
+                Err(e) => result.failed_profiles.push(format!("{}: {}", profile.get_display_name(), e)),
+            }
+        }
+        Ok(result)
+    })
+    .await
+    .map_err(|e| format!("Auto update task failed: {}", e))?
+}
+
+// Share identical game files between profiles on the same branch and version
+#[tauri::command]
+async fn deduplicate_game_installs(
+    state: State<'_, Mutex<AppState>>,
+) -> Result<DedupReport, String> {
+    let profile_manager = {
+        let app_state = state.lock().unwrap();
+        app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?
+            .clone()
+    }; // MutexGuard is dropped here
+
+    tokio::task::spawn_blocking(move || {
+        profile_manager.deduplicate_game_installs()
+            .map_err(|e| format!("Failed to deduplicate game installs: {}", e))
+    })
+    .await
+    .map_err(|e| format!("Deduplication task failed: {}", e))?
+}
+
+// Get the Resonite versions installed to a profile, newest first
+#[tauri::command]
+async fn get_version_history(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<VersionRecord>, String> {
+    let app_state = state.lock().unwrap();
+    
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+    
+    Ok(profile_manager.get_version_history(&profile_name))
+}
+
 // Get profiles with game info
 #[tauri::command]
 async fn get_profiles(state: State<'_, Mutex<AppState>>) -> Result<Vec<ProfileInfo>, String> {
@@ -367,8 +949,12 @@ async fn get_profiles(state: State<'_, Mutex<AppState>>) -> Result<Vec<ProfileIn
     let profile_manager = app_state.profile_manager.as_ref()
         .ok_or("Profile manager not initialized")?;
     
-    let profiles = profile_manager.list_profiles()
+    let mut profiles = profile_manager.list_profiles()
         .map_err(|e| format!("Failed to get profiles: {}", e))?;
+    // Pinned profiles come first, then by name
+    sort_profiles_for_display(&mut profiles);
+    
+    let running_profiles = utils::identify_running_profiles();
     
     Ok(profiles.into_iter().map(|p| {
         let profile_dir = profile_manager.get_profile_dir(p.get_folder_name());
@@ -416,60 +1002,456 @@ async fn get_profiles(state: State<'_, Mutex<AppState>>) -> Result<Vec<ProfileIn
             version: current_version,
             has_mod_loader,
             mod_loader_type,
+            auto_update_game: p.auto_update_game,
+            pinned_manifest_id: p.pinned_manifest_id.clone(),
+            is_running: running_profiles.iter().any(|id| id == p.get_folder_name()),
+            pinned: p.is_pinned(),
         }
     }).collect())
 }
 
-// Create profile
+// Pin or unpin a profile at the top of the profile list
 #[tauri::command]
-async fn create_profile(
-    name: String,
-    description: String,
+async fn toggle_profile_pin(
+    profile_name: String,
     state: State<'_, Mutex<AppState>>,
-) -> Result<String, String> {
+) -> Result<bool, String> {
     let app_state = state.lock().unwrap();
-    
+
     let profile_manager = app_state.profile_manager.as_ref()
         .ok_or("Profile manager not initialized")?;
-    
-    let mut profile = profile_manager.create_profile(&name)
-        .map_err(|e| format!("Failed to create profile: {}", e))?;
-    
-    profile.description = description;
-    
-    let profile_dir = profile_manager.get_profile_dir(profile.get_folder_name());
-    
-    profile.save(&profile_dir)
-        .map_err(|e| format!("Failed to save profile: {}", e))?;
-    
-    Ok(format!("Profile '{}' created successfully", name))
+
+    profile_manager.toggle_profile_pin(&profile_name)
+        .map_err(|e| format!("Failed to toggle profile pin: {}", e))
 }
 
-// Launch Resonite with profile
+// Turn the profile's debug log mode (verbose logging launch arguments) on or off
 #[tauri::command]
-async fn launch_resonite(
+async fn set_profile_debug_logging(
     profile_name: String,
+    enabled: bool,
     state: State<'_, Mutex<AppState>>,
-) -> Result<String, String> {
+) -> Result<(), String> {
     let app_state = state.lock().unwrap();
-    
-    let install_manager = app_state.install_manager.as_ref()
-        .ok_or("Install manager not initialized")?;
-    
+
     let profile_manager = app_state.profile_manager.as_ref()
         .ok_or("Profile manager not initialized")?;
-    
-    install_manager.launch_with_profile(&profile_name, profile_manager)
-        .map_err(|e| format!("Launch failed: {}", e))?;
-    
-    Ok(format!("Resonite launched with profile '{}'", profile_name))
+
+    let mut profile = profile_manager.get_profile(&profile_name)
+        .map_err(|e| format!("Failed to get profile: {}", e))?;
+    profile.debug_logging = enabled;
+
+    profile_manager.update_profile(&profile)
+        .map_err(|e| format!("Failed to update profile: {}", e))
 }
 
-// Launch Resonite with specific mode override
+// Set (or clear with None) the resolution and screen mode passed to Resonite on launch
 #[tauri::command]
-async fn launch_resonite_with_mode(
+async fn set_profile_display_settings(
     profile_name: String,
-    mode: String, // "screen" or "vr"
+    resolution: Option<(u32, u32)>,
+    screen_mode: Option<String>,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<(), String> {
+    if let Some((width, height)) = resolution {
+        if width == 0 || height == 0 {
+            return Err(format!("Invalid resolution {}x{}", width, height));
+        }
+    }
+    if let Some(screen_mode) = &screen_mode {
+        launch_args::validate_screen_mode(screen_mode)?;
+    }
+
+    let app_state = state.lock().unwrap();
+
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+
+    let mut profile = profile_manager.get_profile(&profile_name)
+        .map_err(|e| format!("Failed to get profile: {}", e))?;
+    profile.resolution = resolution;
+    profile.screen_mode = screen_mode;
+
+    profile_manager.update_profile(&profile)
+        .map_err(|e| format!("Failed to update profile: {}", e))
+}
+
+// Set (or clear with None) the webhook notified of launch and exit by launch_and_monitor
+#[tauri::command]
+async fn set_profile_webhook(
+    profile_name: String,
+    webhook: Option<WebhookConfig>,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<(), String> {
+    let app_state = state.lock().unwrap();
+
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+
+    let mut profile = profile_manager.get_profile(&profile_name)
+        .map_err(|e| format!("Failed to get profile: {}", e))?;
+    profile.webhook = webhook;
+
+    profile_manager.update_profile(&profile)
+        .map_err(|e| format!("Failed to update profile: {}", e))
+}
+
+// Create profile
+#[tauri::command]
+async fn create_profile(
+    app: AppHandle,
+    name: String,
+    description: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<String, String> {
+    let defaults = profile_template_manager(&app)?.get_defaults();
+    let app_state = state.lock().unwrap();
+    
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+    
+    let mut profile = profile_manager.create_profile_with_defaults(&name, &defaults)
+        .map_err(|e| format!("Failed to create profile: {}", e))?;
+    
+    profile.description = description;
+    
+    let profile_dir = profile_manager.get_profile_dir(profile.get_folder_name());
+    
+    profile.save(&profile_dir)
+        .map_err(|e| format!("Failed to save profile: {}", e))?;
+    
+    Ok(format!("Profile '{}' created successfully", name))
+}
+
+// Get the profile template manager stored in app data
+fn profile_template_manager(app: &AppHandle) -> Result<ProfileTemplateManager, String> {
+    let app_data_dir = app.path_resolver()
+        .app_data_dir()
+        .ok_or("Failed to get app data directory")?;
+
+    Ok(ProfileTemplateManager::new(&app_data_dir))
+}
+
+// Get the settings applied to newly created profiles
+#[tauri::command]
+async fn get_profile_defaults(app: AppHandle) -> Result<ProfileDefaults, String> {
+    Ok(profile_template_manager(&app)?.get_defaults())
+}
+
+// Save the settings applied to newly created profiles
+#[tauri::command]
+async fn save_profile_defaults(app: AppHandle, defaults: ProfileDefaults) -> Result<(), String> {
+    profile_template_manager(&app)?
+        .save_defaults(&defaults)
+        .map_err(|e| format!("Failed to save profile defaults: {}", e))
+}
+
+// Get saved profile templates
+#[tauri::command]
+async fn list_templates(app: AppHandle) -> Result<Vec<ProfileTemplate>, String> {
+    Ok(profile_template_manager(&app)?.list())
+}
+
+// Save a profile template (replaces a template with the same name)
+#[tauri::command]
+async fn save_template(template: ProfileTemplate, app: AppHandle) -> Result<(), String> {
+    profile_template_manager(&app)?
+        .save_template(&template)
+        .map_err(|e| format!("Failed to save template: {}", e))
+}
+
+// Delete a profile template
+#[tauri::command]
+async fn delete_template(name: String, app: AppHandle) -> Result<(), String> {
+    profile_template_manager(&app)?
+        .delete_template(&name)
+        .map_err(|e| format!("Failed to delete template: {}", e))
+}
+
+// Create a profile from a template and install its MOD loader / MODs if the game is already there
+#[tauri::command]
+async fn create_profile_from_template(
+    name: String,
+    template_name: String,
+    state: State<'_, Mutex<AppState>>,
+    app: AppHandle,
+) -> Result<TemplateSetupResult, String> {
+    let template = profile_template_manager(&app)?
+        .get(&template_name)
+        .ok_or_else(|| format!("Template '{}' not found", template_name))?;
+
+    let profile_id = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        let profile = profile_manager.create_profile_from_template(&name, &template)
+            .map_err(|e| format!("Failed to create profile: {}", e))?;
+        profile.id
+    }; // MutexGuard is dropped here
+
+    apply_template_setup(&profile_id, state).await
+}
+
+// Install the template's MOD loader / MODs after the game has been installed
+#[tauri::command]
+async fn apply_profile_template_setup(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<TemplateSetupResult, String> {
+    apply_template_setup(&profile_name, state).await
+}
+
+// Apply a profile's pending template setup (kept pending until the game is installed)
+async fn apply_template_setup(
+    profile_name: &str,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<TemplateSetupResult, String> {
+    let (profile_dir, setup) = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        let profile = profile_manager.get_profile(profile_name)
+            .map_err(|e| format!("Failed to get profile: {}", e))?;
+
+        (profile_manager.get_profile_dir(profile_name), profile.pending_template_setup)
+    }; // MutexGuard is dropped here
+
+    let mut result = TemplateSetupResult {
+        profile_id: profile_name.to_string(),
+        pending: false,
+        mod_loader_message: None,
+        installed_mods: Vec::new(),
+        failed_mods: Vec::new(),
+    };
+
+    let Some(setup) = setup else {
+        return Ok(result);
+    };
+    if find_game_path(&profile_dir).is_err() {
+        result.pending = true;
+        return Ok(result);
+    }
+
+    if let Some(loader_type) = setup.mod_loader_type {
+        let message = install_mod_loader(profile_name.to_string(), loader_type, None, state.clone()).await?;
+        result.mod_loader_message = Some(message);
+    }
+
+    let mod_loader_type = setup.mod_loader_type.map(|t| match t {
+        ModLoaderType::ResoniteModLoader => "ResoniteModLoader".to_string(),
+        ModLoaderType::MonkeyLoader => "MonkeyLoader".to_string(),
+        ModLoaderType::BepisLoader => "BepisLoader".to_string(),
+    });
+
+    let mut failed_sources = Vec::new();
+    if !setup.mods.is_empty() {
+        let mod_manager = create_mod_manager(profile_dir);
+        let all_mods = mod_manager.fetch_mod_manifest().await.unwrap_or_else(|e| {
+            eprintln!("Failed to fetch MOD manifest, installing from GitHub directly: {}", e);
+            Vec::new()
+        });
+
+        for source_location in &setup.mods {
+            let installed = match all_mods.iter().find(|m| utils::same_source_location(&m.source_location, source_location)) {
+                Some(mod_info) => mod_manager.install_mod_from_cache(mod_info, None, mod_loader_type.as_deref()).await,
+                None => mod_manager.install_mod_from_github(source_location, None, mod_loader_type.as_deref()).await,
+            };
+            match installed {
+                Ok(installed_mod) => result.installed_mods.push(installed_mod.name),
+                Err(e) => {
+                    result.failed_mods.push(format!("{}: {}", source_location, e));
+                    failed_sources.push(source_location.clone());
+                }
+            }
+        }
+    }
+
+    // Keep only the failed MODs pending so they can be retried
+    {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        let mut profile = profile_manager.get_profile(profile_name)
+            .map_err(|e| format!("Failed to get profile: {}", e))?;
+        profile.pending_template_setup = if failed_sources.is_empty() {
+            None
+        } else {
+            Some(PendingTemplateSetup {
+                template_name: setup.template_name,
+                mod_loader_type: None,
+                mods: failed_sources,
+            })
+        };
+        profile_manager.update_profile(&profile)
+            .map_err(|e| format!("Failed to update profile: {}", e))?;
+    }
+
+    Ok(result)
+}
+
+// Apply a profile's pending template setup in the background once its game has been installed
+fn apply_template_setup_after_install(app: &AppHandle, profile_name: &str) {
+    let app = app.clone();
+    let profile_name = profile_name.to_string();
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<Mutex<AppState>>();
+        match apply_template_setup(&profile_name, state).await {
+            Ok(result) => {
+                if result.mod_loader_message.is_some() || !result.installed_mods.is_empty() || !result.failed_mods.is_empty() {
+                    let _ = app.emit_all("template-setup-completed", &result);
+                }
+            }
+            Err(e) => eprintln!("Failed to apply template setup for {}: {}", profile_name, e),
+        }
+    });
+}
+
+// Launch Resonite with profile
+#[tauri::command]
+async fn launch_resonite(
+    profile_name: String,
+    join_session: Option<String>,
+    debug_logging: Option<bool>,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<String, String> {
+    let app_state = state.lock().unwrap();
+    
+    let install_manager = app_state.install_manager.as_ref()
+        .ok_or("Install manager not initialized")?;
+    
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+    
+    // Verbose logging can be requested for this launch only, on top of the profile's debug log mode
+    let overrides = launch_args::LaunchOverrides {
+        join_session,
+        debug_logging: debug_logging.unwrap_or(false),
+        ..Default::default()
+    };
+    install_manager.launch_with_overrides(&profile_name, profile_manager, &overrides)
+        .map_err(|e| format!("Launch failed: {}", e))?;
+    
+    Ok(format!("Resonite launched with profile '{}'", profile_name))
+}
+
+// Check whether Resonite is already running with this profile (stale locks are cleared)
+#[tauri::command]
+async fn is_profile_locked(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<bool, String> {
+    let app_state = state.lock().unwrap();
+
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+
+    Ok(launch_lock::is_profile_locked(&profile_manager.get_profile_dir(&profile_name)))
+}
+
+// Run all pre-launch checks (game, running state, args, mod loader, DataPath, version) at once
+#[tauri::command]
+async fn preflight_check(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<PreflightReport, String> {
+    let profile_manager = {
+        let app_state = state.lock().unwrap();
+        app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?
+            .clone()
+    }; // MutexGuard is dropped here
+
+    tokio::task::spawn_blocking(move || {
+        preflight::preflight_check(&profile_manager, &profile_name)
+            .map_err(|e| format!("Preflight check failed: {}", e))
+    })
+    .await
+    .map_err(|e| format!("Preflight task failed: {}", e))?
+}
+
+// Test that the profile's DataPath is writable before launching
+#[tauri::command]
+async fn check_data_path_writable(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Option<utils::WriteAccessError>, String> {
+    let (profile, profile_dir) = {
+        let app_state = state.lock().unwrap();
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+        let profile = profile_manager.get_profile(&profile_name)
+            .map_err(|e| format!("Failed to load profile: {}", e))?;
+        (profile, profile_manager.get_profile_dir(&profile_name))
+    }; // MutexGuard is dropped here
+
+    Ok(profile
+        .get_data_path(&profile_dir)
+        .and_then(|data_path| utils::check_directory_writable(&data_path).err()))
+}
+
+// Find enabled MODs that the profile's mod loader won't load (checked before launching)
+#[tauri::command]
+async fn check_mod_loader_mismatches(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<ModLoaderMismatch>, String> {
+    let (profile_dir, profile_loader) = profile_mod_loader(&profile_name, &state)?;
+
+    create_mod_manager(profile_dir)
+        .find_loader_mismatches(profile_loader)
+        .map_err(|e| format!("Failed to check mod loader mismatches: {}", e))
+}
+
+// Disable every MOD that the profile's mod loader won't load
+#[tauri::command]
+async fn disable_mismatched_mods(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<String>, String> {
+    let (profile_dir, profile_loader) = profile_mod_loader(&profile_name, &state)?;
+
+    create_mod_manager(profile_dir)
+        .disable_loader_mismatches(profile_loader)
+        .map_err(|e| format!("Failed to disable mismatched mods: {}", e))
+}
+
+// Resolve the profile directory and its mod loader (auto-detected when not recorded)
+fn profile_mod_loader(
+    profile_name: &str,
+    state: &State<'_, Mutex<AppState>>,
+) -> Result<(PathBuf, Option<ModLoaderType>), String> {
+    let (profile_dir, mod_loader_type) = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        let profile = profile_manager.get_profile(profile_name)
+            .map_err(|e| format!("Failed to get profile: {}", e))?;
+
+        (profile_manager.get_profile_dir(profile_name), profile.mod_loader_type)
+    }; // MutexGuard is dropped here
+
+    let loader = match mod_loader_type {
+        Some(loader) => Some(loader),
+        None => find_game_path(&profile_dir)
+            .ok()
+            .and_then(|game_path| detect_mod_loader_type(&game_path, &profile_dir)),
+    };
+    Ok((profile_dir, loader))
+}
+
+// Launch Resonite with specific mode override
+#[tauri::command]
+async fn launch_resonite_with_mode(
+    profile_name: String,
+    mode: String, // "screen" or "vr"
     state: State<'_, Mutex<AppState>>,
 ) -> Result<String, String> {
     let app_state = state.lock().unwrap();
@@ -486,6 +1468,228 @@ async fn launch_resonite_with_mode(
     Ok(format!("Resonite launched with profile '{}' in {} mode", profile_name, mode))
 }
 
+// Launch Resonite and measure the time until startup completes
+#[tauri::command]
+async fn launch_and_monitor(
+    profile_name: String,
+    window: Window,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<LaunchRecord, String> {
+    let (install_manager, profile_manager) = {
+        let app_state = state.lock().unwrap();
+        let install_manager = app_state.install_manager.as_ref()
+            .ok_or("Install manager not initialized")?
+            .clone();
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?
+            .clone();
+        (install_manager, profile_manager)
+    }; // MutexGuard is dropped here
+
+    tokio::task::spawn_blocking(move || {
+        let record = install_manager
+            .launch_and_monitor(&profile_name, &profile_manager, std::time::Duration::from_secs(600))
+            .map_err(|e| format!("Launch failed: {}", e))?;
+
+        // クラッシュを検知したら最新のログをGUIに表示できるよう通知する
+        if record.crashed {
+            let profile_dir = profile_manager.get_profile_dir(&profile_name);
+            if let Ok(log) = install_manager.get_latest_log(&profile_dir) {
+                let _ = window.emit("resonite-crash-log", serde_json::json!({
+                    "profile_name": profile_name,
+                    "log": log,
+                }));
+            }
+        }
+
+        Ok(record)
+    })
+    .await
+    .map_err(|e| format!("Launch task failed: {}", e))?
+}
+
+// List Resonite log files of a profile, newest first
+#[tauri::command]
+async fn list_logs(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<LogFileInfo>, String> {
+    let app_state = state.lock().unwrap();
+
+    let install_manager = app_state.install_manager.as_ref()
+        .ok_or("Install manager not initialized")?;
+
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+
+    install_manager.list_logs(&profile_manager.get_profile_dir(&profile_name))
+        .map_err(|e| format!("Failed to list logs: {}", e))
+}
+
+// Read a Resonite log file of a profile by file name
+#[tauri::command]
+async fn get_log(
+    profile_name: String,
+    file_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<String, String> {
+    let app_state = state.lock().unwrap();
+
+    let install_manager = app_state.install_manager.as_ref()
+        .ok_or("Install manager not initialized")?;
+
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+
+    install_manager.get_log(&profile_manager.get_profile_dir(&profile_name), &file_name)
+        .map_err(|e| format!("Failed to read log: {}", e))
+}
+
+// Read the latest Resonite log file of a profile
+#[tauri::command]
+async fn get_latest_log(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<String, String> {
+    let app_state = state.lock().unwrap();
+
+    let install_manager = app_state.install_manager.as_ref()
+        .ok_or("Install manager not initialized")?;
+
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+
+    install_manager.get_latest_log(&profile_manager.get_profile_dir(&profile_name))
+        .map_err(|e| format!("Failed to read latest log: {}", e))
+}
+
+// Get launch time statistics for a profile
+#[tauri::command]
+async fn get_launch_stats(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<LaunchStats, String> {
+    let app_state = state.lock().unwrap();
+
+    let install_manager = app_state.install_manager.as_ref()
+        .ok_or("Install manager not initialized")?;
+
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+
+    Ok(install_manager.get_launch_stats(&profile_name, profile_manager))
+}
+
+// Disable all MODs and relaunch Resonite in safe mode
+#[tauri::command]
+async fn launch_safe_mode(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<LaunchRecord, String> {
+    let (install_manager, profile_manager) = {
+        let app_state = state.lock().unwrap();
+        let install_manager = app_state.install_manager.as_ref()
+            .ok_or("Install manager not initialized")?
+            .clone();
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?
+            .clone();
+        (install_manager, profile_manager)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_manager.get_profile_dir(&profile_name));
+    mod_manager.disable_all_mods()
+        .map_err(|e| format!("Failed to disable mods: {}", e))?;
+
+    tokio::task::spawn_blocking(move || {
+        install_manager
+            .launch_and_monitor(&profile_name, &profile_manager, std::time::Duration::from_secs(600))
+            .map_err(|e| format!("Launch failed: {}", e))
+    })
+    .await
+    .map_err(|e| format!("Launch task failed: {}", e))?
+}
+
+// Get MODs disabled by safe mode that have not been re-enabled yet
+#[tauri::command]
+async fn get_safe_mode_pending_mods(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<String>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    Ok(create_mod_manager(profile_dir).get_safe_mode_pending_mods())
+}
+
+// Re-enable the next MOD disabled by safe mode (for narrowing down the cause)
+#[tauri::command]
+async fn enable_next_safe_mode_mod(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Option<String>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    create_mod_manager(profile_dir).enable_next_safe_mode_mod()
+        .map_err(|e| format!("Failed to enable mod: {}", e))
+}
+
+// Re-enable all MODs disabled by safe mode
+#[tauri::command]
+async fn restore_safe_mode_mods(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<String>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    create_mod_manager(profile_dir).restore_safe_mode_mods()
+        .map_err(|e| format!("Failed to restore mods: {}", e))
+}
+
+// Get known Resonite launch arguments for autocomplete
+#[tauri::command]
+fn get_known_launch_args() -> Vec<ArgSpec> {
+    launch_args::get_known_launch_args()
+}
+
+// Get the {placeholder} variables available in a profile's launch arguments
+#[tauri::command]
+async fn get_placeholder_variables(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<PlaceholderVariable>, String> {
+    let app_state = state.lock().unwrap();
+    
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+    
+    let profile = profile_manager.get_profile(&profile_name)
+        .map_err(|e| format!("Failed to get profile: {}", e))?;
+    let profile_dir = profile_manager.get_profile_dir(&profile_name);
+    
+    Ok(profile.get_placeholder_variables(&profile_dir))
+}
+
 // Interactive Steam login
 #[tauri::command]
 async fn steam_login(
@@ -503,26 +1707,42 @@ async fn steam_login(
     Ok("Steam login successful".to_string())
 }
 
+// Steam credentials live in the OS credential store (Windows Credential Manager / macOS Keychain / Secret Service)
+const CREDENTIALS_SERVICE: &str = "reso-launcher";
+const CREDENTIALS_ACCOUNT: &str = "steam_credentials";
+
+fn credentials_entry() -> Result<keyring::Entry, String> {
+    keyring::Entry::new(CREDENTIALS_SERVICE, CREDENTIALS_ACCOUNT)
+        .map_err(|e| format!("Failed to access credential store: {}", e))
+}
+
+// Plain-text file used by older versions; migrated to the credential store on first load
+fn legacy_credentials_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app.path_resolver()
+        .app_data_dir()
+        .ok_or("Failed to get app data directory")?;
+
+    Ok(app_data_dir.join("steam_credentials.json"))
+}
+
 // Save Steam credentials
 #[tauri::command]
 async fn save_steam_credentials(
     credentials: SteamCredentials,
     app: AppHandle,
 ) -> Result<String, String> {
-    let app_data_dir = app.path_resolver()
-        .app_data_dir()
-        .ok_or("Failed to get app data directory")?;
-    
-    // Create app data directory if it doesn't exist
-    std::fs::create_dir_all(&app_data_dir)
-        .map_err(|e| format!("Failed to create app data directory: {}", e))?;
-    
-    let credentials_path = app_data_dir.join("steam_credentials.json");
-    let json = serde_json::to_string_pretty(&credentials)
+    let json = serde_json::to_string(&credentials)
         .map_err(|e| format!("Failed to serialize credentials: {}", e))?;
     
-    std::fs::write(credentials_path, json)
+    credentials_entry()?
+        .set_password(&json)
         .map_err(|e| format!("Failed to save credentials: {}", e))?;
+
+    // Don't leave an old plain-text copy behind
+    let legacy_path = legacy_credentials_path(&app)?;
+    if legacy_path.exists() {
+        let _ = std::fs::remove_file(legacy_path);
+    }
     
     Ok("Steam credentials saved successfully".to_string())
 }
@@ -530,21 +1750,37 @@ async fn save_steam_credentials(
 // Load Steam credentials
 #[tauri::command]
 async fn load_steam_credentials(app: AppHandle) -> Result<Option<SteamCredentials>, String> {
-    let app_data_dir = app.path_resolver()
-        .app_data_dir()
-        .ok_or("Failed to get app data directory")?;
-    
-    let credentials_path = app_data_dir.join("steam_credentials.json");
-    
-    if !credentials_path.exists() {
+    let entry = credentials_entry()?;
+
+    match entry.get_password() {
+        Ok(json) => {
+            let credentials: SteamCredentials = serde_json::from_str(&json)
+                .map_err(|e| format!("Failed to parse credentials: {}", e))?;
+            return Ok(Some(credentials));
+        }
+        Err(keyring::Error::NoEntry) => {}
+        Err(e) => return Err(format!("Failed to read credentials: {}", e)),
+    }
+
+    // Migrate credentials saved as plain text by older versions
+    let legacy_path = legacy_credentials_path(&app)?;
+    if !legacy_path.exists() {
         return Ok(None);
     }
     
-    let json = std::fs::read_to_string(credentials_path)
+    let json = std::fs::read_to_string(&legacy_path)
         .map_err(|e| format!("Failed to read credentials: {}", e))?;
     
     let credentials: SteamCredentials = serde_json::from_str(&json)
         .map_err(|e| format!("Failed to parse credentials: {}", e))?;
+
+    let json = serde_json::to_string(&credentials)
+        .map_err(|e| format!("Failed to serialize credentials: {}", e))?;
+    entry.set_password(&json)
+        .map_err(|e| format!("Failed to migrate credentials: {}", e))?;
+    std::fs::remove_file(&legacy_path)
+        .map_err(|e| format!("Failed to remove plain-text credentials: {}", e))?;
+    println!("Migrated Steam credentials to the OS credential store");
     
     Ok(Some(credentials))
 }
@@ -552,14 +1788,14 @@ async fn load_steam_credentials(app: AppHandle) -> Result<Option<SteamCredential
 // Clear Steam credentials
 #[tauri::command]
 async fn clear_steam_credentials(app: AppHandle) -> Result<String, String> {
-    let app_data_dir = app.path_resolver()
-        .app_data_dir()
-        .ok_or("Failed to get app data directory")?;
-    
-    let credentials_path = app_data_dir.join("steam_credentials.json");
-    
-    if credentials_path.exists() {
-        std::fs::remove_file(credentials_path)
+    match credentials_entry()?.delete_password() {
+        Ok(()) | Err(keyring::Error::NoEntry) => {}
+        Err(e) => return Err(format!("Failed to remove credentials: {}", e)),
+    }
+
+    let legacy_path = legacy_credentials_path(&app)?;
+    if legacy_path.exists() {
+        std::fs::remove_file(legacy_path)
             .map_err(|e| format!("Failed to remove credentials: {}", e))?;
     }
     
@@ -598,20 +1834,54 @@ async fn update_profile_config(
     Ok(format!("Profile '{}' updated successfully", profile.name))
 }
 
+// Game folder names to search, in order (release/prerelease are from older profile layouts)
+const GAME_DIR_NAMES: &[&str] = &["Game", "release", "prerelease"];
+// Executable names to look for (Windows build, then the Linux binary without extension)
+const GAME_EXE_NAMES: &[&str] = &["Resonite.exe", "Resonite"];
+
+// Helper function to find an entry in a directory by name, ignoring case
+fn find_entry_ignore_case(dir: &std::path::Path, name: &str) -> Option<std::path::PathBuf> {
+    let exact = dir.join(name);
+    if exact.exists() {
+        return Some(exact);
+    }
+
+    std::fs::read_dir(dir).ok()?
+        .filter_map(|entry| entry.ok())
+        .find(|entry| entry.file_name().to_string_lossy().eq_ignore_ascii_case(name))
+        .map(|entry| entry.path())
+}
+
 // Helper function to find the game installation path for a profile
 fn find_game_path(profile_dir: &std::path::Path) -> Result<std::path::PathBuf, String> {
     println!("Searching for game in profile dir: {:?}", profile_dir);
-    
-    let release_path = profile_dir.join("Game");
-    let release_exe = release_path.join("Resonite.exe");
-    println!("Checking release path: {:?}, exe exists: {}", release_path, release_exe.exists());
-    
-    if release_path.exists() && release_exe.exists() {
-        println!("Found game in: {:?}", release_path);
-        return Ok(release_path);
+
+    let mut searched = Vec::new();
+    for dir_name in GAME_DIR_NAMES {
+        let game_path = match find_entry_ignore_case(profile_dir, dir_name) {
+            Some(path) if path.is_dir() => path,
+            _ => {
+                searched.push(profile_dir.join(dir_name));
+                continue;
+            }
+        };
+
+        for exe_name in GAME_EXE_NAMES {
+            match find_entry_ignore_case(&game_path, exe_name) {
+                Some(exe) if exe.is_file() => {
+                    println!("Found game in: {:?} ({:?})", game_path, exe);
+                    return Ok(game_path);
+                }
+                _ => searched.push(game_path.join(exe_name)),
+            }
+        }
     }
-    
-    Err(format!("Game not installed in this profile. Searched in: {:?}", profile_dir))
+
+    Err(format!(
+        "Game not installed in this profile. Searched in: {:?} ({})",
+        profile_dir,
+        searched.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", ")
+    ))
 }
 
 // Get mod loader status for a profile
@@ -625,10 +1895,50 @@ async fn get_mod_loader_status(
     let profile_manager = app_state.profile_manager.as_ref()
         .ok_or("Profile manager not initialized")?;
 
-    let profile = profile_manager.get_profile(&profile_name)
+    detect_mod_loader_status(profile_manager, &profile_name)
+}
+
+// Check whether a newer version of the installed mod loader is available
+#[tauri::command]
+async fn check_mod_loader_update(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<UnifiedModLoaderInfo, String> {
+    let (mut info, game_path) = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        let info = detect_mod_loader_status(profile_manager, &profile_name)?;
+        let game_path = find_game_path(&profile_manager.get_profile_dir(&profile_name))?;
+        (info, game_path)
+    }; // MutexGuard is dropped here
+
+    if !info.installed {
+        return Ok(info);
+    }
+
+    let latest_version = match info.loader_type {
+        Some(ModLoaderType::ResoniteModLoader) => ModLoader::new(game_path).check_update().await
+            .map_err(|e| format!("Failed to check ResoniteModLoader update: {}", e))?,
+        Some(ModLoaderType::MonkeyLoader) => MonkeyLoader::new(game_path).check_update().await
+            .map_err(|e| format!("Failed to check MonkeyLoader update: {}", e))?,
+        // BepisLoaderはThunderstore経由で管理するため対象外
+        _ => None,
+    };
+
+    info.update_available = latest_version.is_some();
+    info.latest_version = latest_version;
+    Ok(info)
+}
+
+// Detect which mod loader is installed in a profile
+fn detect_mod_loader_status(profile_manager: &ProfileManager, profile_name: &str) -> Result<UnifiedModLoaderInfo, String> {
+    let profile = profile_manager.get_profile(profile_name)
         .map_err(|e| format!("Failed to get profile: {}", e))?;
 
-    let profile_dir = profile_manager.get_profile_dir(&profile_name);
+    let profile_dir = profile_manager.get_profile_dir(profile_name);
     let game_path = find_game_path(&profile_dir)?;
 
     // Check ResoniteModLoader
@@ -668,16 +1978,79 @@ async fn get_mod_loader_status(
         installed,
         loader_type,
         version,
+        update_available: false,
+        latest_version: None,
     })
 }
 
+// Check whether Harmony (0Harmony.dll) is present for ResoniteModLoader
+#[tauri::command]
+async fn check_harmony(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<bool, String> {
+    let app_state = state.lock().unwrap();
+
+    let profile_manager = app_state.profile_manager.as_ref()
+        .ok_or("Profile manager not initialized")?;
+
+    let game_path = find_game_path(&profile_manager.get_profile_dir(&profile_name))?;
+    Ok(ModLoader::new(game_path).check_harmony())
+}
+
+// Place Harmony (0Harmony.dll) bundled with the ResoniteModLoader release if missing
+#[tauri::command]
+async fn ensure_harmony(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<String, String> {
+    let game_path = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        find_game_path(&profile_manager.get_profile_dir(&profile_name))?
+    }; // MutexGuard is dropped here
+
+    ModLoader::new(game_path).ensure_harmony().await
+        .map_err(|e| format!("Failed to install Harmony: {}", e))?;
+
+    Ok("Harmony is installed".to_string())
+}
+
+// List ResoniteModLoader versions available for installation
+#[tauri::command]
+async fn get_mod_loader_versions(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<String>, String> {
+    let game_path = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        let profile_dir = profile_manager.get_profile_dir(&profile_name);
+        find_game_path(&profile_dir)?
+    }; // MutexGuard is dropped here
+
+    ModLoader::new(game_path).list_available_versions().await
+        .map_err(|e| format!("Failed to get ResoniteModLoader versions: {}", e))
+}
+
 // Install mod loader to a profile
 #[tauri::command]
 async fn install_mod_loader(
     profile_name: String,
     loader_type: ModLoaderType,
+    version: Option<String>,
     state: State<'_, Mutex<AppState>>,
 ) -> Result<String, String> {
+    if version.is_some() && loader_type != ModLoaderType::ResoniteModLoader {
+        return Err("Version selection is only supported for ResoniteModLoader".to_string());
+    }
+
     let (game_path, profile_dir) = {
         let app_state = state.lock().unwrap();
 
@@ -692,8 +2065,18 @@ async fn install_mod_loader(
     let result = match loader_type {
         ModLoaderType::ResoniteModLoader => {
             let mod_loader = ModLoader::new(game_path.clone());
-            mod_loader.install().await
-                .map_err(|e| format!("Failed to install ResoniteModLoader: {}", e))?
+            // バージョン未指定の場合は最新版をインストール
+            let message = match version.as_deref() {
+                Some(tag) => mod_loader.install_version(tag).await,
+                None => mod_loader.install().await,
+            }
+                .map_err(|e| format!("Failed to install ResoniteModLoader: {}", e))?;
+
+            // Harmony依存のMODが動くよう0Harmony.dllの有無を確認し、不足していれば配置
+            match mod_loader.ensure_harmony().await {
+                Ok(()) => format!("{} (Harmony: OK)", message),
+                Err(e) => format!("{} (Harmony: 見つかりません - {})", message, e),
+            }
         },
         ModLoaderType::MonkeyLoader => {
             let monkey_loader = MonkeyLoader::new(game_path.clone());
@@ -749,6 +2132,40 @@ async fn install_mod_loader(
     Ok(result)
 }
 
+// Migrate a profile between ResoniteModLoader and MonkeyLoader, switching MODs to the target loader's releases
+#[tauri::command]
+async fn migrate_mod_loader(
+    profile_name: String,
+    target: ModLoaderType,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<MigrationReport, String> {
+    let profile_manager = {
+        let app_state = state.lock().unwrap();
+        app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?
+            .clone()
+    }; // MutexGuard is dropped here
+
+    LoaderMigrator::new(profile_manager)
+        .with_mod_manager_factory(create_mod_manager)
+        .migrate_loader(&profile_name, target)
+        .await
+        .map_err(|e| format!("Failed to migrate mod loader: {}", e))
+}
+
+// Detect the installed mod loader for profiles that don't record one
+fn detect_mod_loader_type(game_path: &std::path::Path, profile_dir: &std::path::Path) -> Option<ModLoaderType> {
+    if ModLoader::new(game_path.to_path_buf()).get_status().is_ok_and(|s| s.installed) {
+        Some(ModLoaderType::ResoniteModLoader)
+    } else if MonkeyLoader::new(game_path.to_path_buf()).get_status().is_ok_and(|s| s.installed) {
+        Some(ModLoaderType::MonkeyLoader)
+    } else if BepisLoader::new(profile_dir.to_path_buf()).get_status().installed {
+        Some(ModLoaderType::BepisLoader)
+    } else {
+        None
+    }
+}
+
 // Uninstall mod loader from a profile
 #[tauri::command]
 async fn uninstall_mod_loader(
@@ -771,24 +2188,9 @@ async fn uninstall_mod_loader(
     };
 
     // Detect which loader is installed if not specified in profile
-    let loader_type = if let Some(loader_type) = mod_loader_type {
-        loader_type
-    } else {
-        // Auto-detect
-        let rml = ModLoader::new(game_path.clone());
-        let ml = MonkeyLoader::new(game_path.clone());
-        let bepis = BepisLoader::new(profile_dir.clone());
-
-        if rml.get_status().map_or(false, |s| s.installed) {
-            ModLoaderType::ResoniteModLoader
-        } else if ml.get_status().map_or(false, |s| s.installed) {
-            ModLoaderType::MonkeyLoader
-        } else if bepis.get_status().installed {
-            ModLoaderType::BepisLoader
-        } else {
-            return Err("No mod loader installed".to_string());
-        }
-    };
+    let loader_type = mod_loader_type
+        .or_else(|| detect_mod_loader_type(&game_path, &profile_dir))
+        .ok_or("No mod loader installed")?;
 
     // Uninstall the appropriate loader
     let result = match loader_type {
@@ -1049,6 +2451,25 @@ fn delete_profile(
     Ok(format!("Profile '{}' deleted successfully", profile_name))
 }
 
+// Delete only the Game folder of a profile, keeping its settings, MOD info and DataPath
+#[tauri::command]
+async fn remove_game_from_profile(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<String, String> {
+    let profile_manager = {
+        let app_state = state.lock().unwrap();
+        app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?
+            .clone()
+    }; // MutexGuard is dropped here
+
+    profile_manager.remove_game_files(&profile_name)
+        .map_err(|e| format!("Failed to remove game files: {}", e))?;
+
+    Ok(format!("Game files removed from profile '{}'", profile_name))
+}
+
 // Helper function to get DataPath from profile args
 fn get_data_path_from_profile(profile: &reso_launcher_lib::profile::Profile, profile_dir: &std::path::Path) -> std::path::PathBuf {
     // Look for -DataPath in args
@@ -1149,9 +2570,8 @@ async fn check_for_app_update() -> Result<AppUpdateInfo, String> {
     let latest_version = release.tag_name.trim_start_matches('v');
     let current_clean = current_version.trim_start_matches('v');
     
-    // Simple version comparison
-    let update_available = latest_version != current_clean && 
-        version_compare(latest_version, current_clean) > 0;
+    // Compare versions (a prerelease is lower than the release with the same number)
+    let update_available = utils::compare_versions(latest_version, current_clean) == std::cmp::Ordering::Greater;
     
     // Convert assets
     let assets: Vec<UpdateAsset> = release.assets
@@ -1177,29 +2597,11 @@ async fn check_for_app_update() -> Result<AppUpdateInfo, String> {
     })
 }
 
-// Simple version comparison helper
-fn version_compare(a: &str, b: &str) -> i32 {
-    let a_parts: Vec<u32> = a.split('.').filter_map(|s| s.parse().ok()).collect();
-    let b_parts: Vec<u32> = b.split('.').filter_map(|s| s.parse().ok()).collect();
-    
-    for i in 0..std::cmp::max(a_parts.len(), b_parts.len()) {
-        let a_part = a_parts.get(i).unwrap_or(&0);
-        let b_part = b_parts.get(i).unwrap_or(&0);
-        
-        if a_part > b_part {
-            return 1;
-        } else if a_part < b_part {
-            return -1;
-        }
-    }
-    
-    0
-}
-
 // Fetch available MODs from manifest
 #[tauri::command]
 async fn fetch_mod_manifest(
     profile_name: String,
+    force: Option<bool>,
     state: State<'_, Mutex<AppState>>,
 ) -> Result<Vec<ModInfo>, String> {
     let profile_dir = {
@@ -1211,10 +2613,60 @@ async fn fetch_mod_manifest(
         profile_manager.get_profile_dir(&profile_name)
     }; // MutexGuard is dropped here
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
-    mod_manager.fetch_mod_manifest().await
-        .map_err(|e| format!("Failed to fetch mod manifest: {}", e))
+    // force指定時はキャッシュの有効期限に関わらずリモートから取得し直す
+    let manifest = if force.unwrap_or(false) {
+        mod_manager.refresh_mod_manifest().await
+    } else {
+        mod_manager.fetch_mod_manifest().await
+    };
+    manifest.map_err(|e| format!("Failed to fetch mod manifest: {}", e))
+}
+
+// Search MODs in the manifest by keyword, category and tags
+#[tauri::command]
+async fn search_mods(
+    profile_name: String,
+    query: String,
+    category: Option<String>,
+    tags: Option<Vec<String>>,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<ModInfo>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.search_mods(&query, category.as_deref(), &tags.unwrap_or_default()).await
+        .map_err(|e| format!("Failed to search mods: {}", e))
+}
+
+// Refresh both the MOD manifest cache and the hash lookup cache
+#[tauri::command]
+async fn refresh_mod_caches(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<CacheRefreshResult, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.refresh_caches().await
+        .map_err(|e| format!("Failed to refresh caches: {}", e))
 }
 
 // Get installed MODs for a profile
@@ -1252,18 +2704,42 @@ async fn get_installed_mods(
                 mod_loader_type: Some("BepisLoader".to_string()),
                 file_format: Some("dll".to_string()),
                 enabled: Some(true),
+                hot_reload_capable: None,
+                hot_reload_override: None,
+                load_priority: None,
             }
         }).collect();
 
         return Ok(installed_mods);
     }
 
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
 
     mod_manager.get_installed_mods()
         .map_err(|e| format!("Failed to get installed mods: {}", e))
 }
 
+// Classify installed MODs by where they came from (manifest, GitHub, local file or unknown)
+#[tauri::command]
+async fn classify_installed_mods(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<(InstalledMod, InstalledModSource)>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.classify_installed_mods().await
+        .map_err(|e| format!("Failed to classify installed mods: {}", e))
+}
+
 // Install MOD from cache information
 #[tauri::command]
 async fn install_mod_from_cache(
@@ -1271,6 +2747,7 @@ async fn install_mod_from_cache(
     mod_info: ModInfo,
     version: Option<String>,
     state: State<'_, Mutex<AppState>>,
+    app: AppHandle,
 ) -> Result<InstalledMod, String> {
     let (profile_dir, mod_loader_type) = {
         let app_state = state.lock().unwrap();
@@ -1291,10 +2768,92 @@ async fn install_mod_from_cache(
         (profile_dir, mod_loader_type)
     }; // MutexGuard is dropped here
 
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
+
+    let installed_mod = mod_manager.install_mod_from_cache(&mod_info, version.as_deref(), mod_loader_type.as_deref()).await
+        .map_err(|e| format!("Failed to install mod: {}", e))?;
+
+    record_installed_mod(&app, &profile_name, &installed_mod);
+
+    Ok(installed_mod)
+}
+
+// Get the suggested destination for each artifact of a MOD release
+#[tauri::command]
+async fn get_mod_artifact_choices(mod_info: ModInfo, release: ModRelease) -> Result<Vec<FileInstallChoice>, String> {
+    Ok(suggest_artifact_choices(&release, &mod_info.name))
+}
+
+// Install the selected artifacts of a MOD release (all of them with suggested destinations if no choices)
+#[tauri::command]
+async fn install_mod_artifacts(
+    profile_name: String,
+    mod_info: ModInfo,
+    version: Option<String>,
+    choices: Option<Vec<FileInstallChoice>>,
+    state: State<'_, Mutex<AppState>>,
+    app: AppHandle,
+) -> Result<Vec<InstalledMod>, String> {
+    let (profile_dir, mod_loader_type) = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        let profile_dir = profile_manager.get_profile_dir(&profile_name);
+        let profile = profile_manager.get_profile(&profile_name)
+            .map_err(|e| format!("Failed to get profile: {}", e))?;
+
+        let mod_loader_type = profile.mod_loader_type.map(|t| match t {
+            ModLoaderType::ResoniteModLoader => "ResoniteModLoader".to_string(),
+            ModLoaderType::MonkeyLoader => "MonkeyLoader".to_string(),
+            ModLoaderType::BepisLoader => "BepisLoader".to_string(),
+        });
+
+        (profile_dir, mod_loader_type)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    let installed_mods = mod_manager.install_mod_artifacts(&mod_info, version.as_deref(), choices, mod_loader_type.as_deref()).await
+        .map_err(|e| format!("Failed to install mod artifacts: {}", e))?;
+
+    for installed_mod in &installed_mods {
+        record_installed_mod(&app, &profile_name, installed_mod);
+    }
+
+    Ok(installed_mods)
+}
+
+// Install MOD from a local .dll / .nupkg file
+#[tauri::command]
+async fn install_mod_from_local(
+    profile_name: String,
+    file_path: String,
+    state: State<'_, Mutex<AppState>>,
+    app: AppHandle,
+) -> Result<InstalledMod, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile(&profile_name)
+            .map_err(|e| format!("Failed to get profile: {}", e))?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
 
-    mod_manager.install_mod_from_cache(&mod_info, version.as_deref(), mod_loader_type.as_deref()).await
-        .map_err(|e| format!("Failed to install mod: {}", e))
+    // MODローダーはファイルの拡張子から決まる
+    let installed_mod = mod_manager.install_mod_from_local_file(std::path::Path::new(&file_path), None).await
+        .map_err(|e| format!("Failed to install mod: {}", e))?;
+
+    record_installed_mod(&app, &profile_name, &installed_mod);
+
+    Ok(installed_mod)
 }
 
 // Install MOD from GitHub repository (fallback)
@@ -1304,30 +2863,239 @@ async fn install_mod_from_github(
     repo_url: String,
     version: Option<String>,
     state: State<'_, Mutex<AppState>>,
+    app: AppHandle,
 ) -> Result<InstalledMod, String> {
     let (profile_dir, mod_loader_type) = {
         let app_state = state.lock().unwrap();
 
-        let profile_manager = app_state.profile_manager.as_ref()
-            .ok_or("Profile manager not initialized")?;
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        let profile_dir = profile_manager.get_profile_dir(&profile_name);
+        let profile = profile_manager.get_profile(&profile_name)
+            .map_err(|e| format!("Failed to get profile: {}", e))?;
+
+        let mod_loader_type = profile.mod_loader_type.map(|t| match t {
+            ModLoaderType::ResoniteModLoader => "ResoniteModLoader".to_string(),
+            ModLoaderType::MonkeyLoader => "MonkeyLoader".to_string(),
+            ModLoaderType::BepisLoader => "BepisLoader".to_string(),
+        });
+
+        (profile_dir, mod_loader_type)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    let installed_mod = mod_manager.install_mod_from_github(&repo_url, version.as_deref(), mod_loader_type.as_deref()).await
+        .map_err(|e| format!("Failed to install mod: {}", e))?;
+
+    record_installed_mod(&app, &profile_name, &installed_mod);
+
+    Ok(installed_mod)
+}
+
+// Number of queued MOD installs processed at the same time (installs to the same profile run one at a time)
+const MOD_INSTALL_QUEUE_PARALLELISM: usize = 2;
+
+// Get the app-wide MOD install queue, starting its worker on first use
+fn mod_install_queue(app: &AppHandle) -> Arc<ModInstallQueue> {
+    static QUEUE: OnceLock<Arc<ModInstallQueue>> = OnceLock::new();
+    QUEUE
+        .get_or_init(|| {
+            let queue = Arc::new(ModInstallQueue::new(MOD_INSTALL_QUEUE_PARALLELISM));
+
+            let event_app = app.clone();
+            queue.set_listener(move |snapshot| {
+                let _ = event_app.emit_all("mod-install-queue", snapshot);
+            });
+
+            let worker_app = app.clone();
+            tauri::async_runtime::spawn(queue.clone().run(move |item| {
+                let app = worker_app.clone();
+                async move { install_queued_mod(&app, item).await }
+            }));
+
+            queue
+        })
+        .clone()
+}
+
+// Install a single MOD taken from the install queue
+async fn install_queued_mod(app: &AppHandle, item: QueueItem) -> Result<(), String> {
+    let (profile_dir, mod_loader_type) = {
+        let state = app.state::<Mutex<AppState>>();
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        let profile_dir = profile_manager.get_profile_dir(&item.profile_name);
+        let profile = profile_manager.get_profile(&item.profile_name)
+            .map_err(|e| format!("Failed to get profile: {}", e))?;
+
+        let mod_loader_type = profile.mod_loader_type.map(|t| match t {
+            ModLoaderType::ResoniteModLoader => "ResoniteModLoader".to_string(),
+            ModLoaderType::MonkeyLoader => "MonkeyLoader".to_string(),
+            ModLoaderType::BepisLoader => "BepisLoader".to_string(),
+        });
+
+        (profile_dir, mod_loader_type)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    let installed_mod = mod_manager.install_mod_from_cache(&item.mod_info, item.version.as_deref(), mod_loader_type.as_deref()).await
+        .map_err(|e| format!("Failed to install mod: {}", e))?;
+
+    record_installed_mod(app, &item.profile_name, &installed_mod);
+
+    Ok(())
+}
+
+// Add a MOD install to the queue and return its queue item id
+#[tauri::command]
+async fn enqueue_mod_install(
+    profile_name: String,
+    mod_info: ModInfo,
+    version: Option<String>,
+    app: AppHandle,
+) -> Result<u64, String> {
+    Ok(mod_install_queue(&app).enqueue(&profile_name, mod_info, version))
+}
+
+// Get the current state of the MOD install queue
+#[tauri::command]
+async fn get_mod_install_queue(app: AppHandle) -> Result<QueueSnapshot, String> {
+    Ok(mod_install_queue(&app).snapshot())
+}
+
+// Stop starting new queued MOD installs (running ones finish normally)
+#[tauri::command]
+async fn pause_mod_install_queue(app: AppHandle) -> Result<(), String> {
+    mod_install_queue(&app).pause();
+    Ok(())
+}
+
+// Resume processing the MOD install queue
+#[tauri::command]
+async fn resume_mod_install_queue(app: AppHandle) -> Result<(), String> {
+    mod_install_queue(&app).resume();
+    Ok(())
+}
+
+// Cancel a pending MOD install (returns false if it already started or finished)
+#[tauri::command]
+async fn cancel_mod_install(id: u64, app: AppHandle) -> Result<bool, String> {
+    Ok(mod_install_queue(&app).cancel(id))
+}
+
+// Move a pending MOD install to another position in the queue
+#[tauri::command]
+async fn move_mod_install(id: u64, index: usize, app: AppHandle) -> Result<bool, String> {
+    Ok(mod_install_queue(&app).move_item(id, index))
+}
+
+// Remove completed, failed and cancelled items from the MOD install queue
+#[tauri::command]
+async fn clear_finished_mod_installs(app: AppHandle) -> Result<(), String> {
+    mod_install_queue(&app).clear_finished();
+    Ok(())
+}
+
+// Get the MOD search/install history manager stored in app data
+fn mod_history_manager(app: &AppHandle) -> Result<ModHistoryManager, String> {
+    let app_data_dir = app.path_resolver()
+        .app_data_dir()
+        .ok_or("Failed to get app data directory")?;
+
+    Ok(ModHistoryManager::new(&app_data_dir))
+}
+
+// Record an installed MOD in the recently installed list (failures are only logged)
+fn record_installed_mod(app: &AppHandle, profile_name: &str, installed_mod: &InstalledMod) {
+    let result = mod_history_manager(app).and_then(|manager| {
+        manager.add_installed(&installed_mod.name, &installed_mod.source_location, &installed_mod.installed_version, profile_name)
+            .map_err(|e| e.to_string())
+    });
+    if let Err(e) = result {
+        eprintln!("Failed to record installed mod: {}", e);
+    }
+}
+
+// Get recent MOD search queries (newest first)
+#[tauri::command]
+async fn get_recent_mod_searches(app: AppHandle) -> Result<Vec<String>, String> {
+    Ok(mod_history_manager(&app)?.load().searches)
+}
+
+// Add a MOD search query to the history
+#[tauri::command]
+async fn add_recent_mod_search(query: String, app: AppHandle) -> Result<(), String> {
+    mod_history_manager(&app)?
+        .add_search(&query)
+        .map_err(|e| format!("Failed to save search history: {}", e))
+}
+
+// Clear the MOD search history
+#[tauri::command]
+async fn clear_recent_mod_searches(app: AppHandle) -> Result<(), String> {
+    mod_history_manager(&app)?
+        .clear_searches()
+        .map_err(|e| format!("Failed to clear search history: {}", e))
+}
+
+// Get recently installed MODs (newest first)
+#[tauri::command]
+async fn get_recently_installed_mods(app: AppHandle) -> Result<Vec<RecentlyInstalledMod>, String> {
+    Ok(mod_history_manager(&app)?.load().installed)
+}
+
+// Clear the recently installed MOD list
+#[tauri::command]
+async fn clear_recently_installed_mods(app: AppHandle) -> Result<(), String> {
+    mod_history_manager(&app)?
+        .clear_installed()
+        .map_err(|e| format!("Failed to clear recently installed mods: {}", e))
+}
 
-        let profile_dir = profile_manager.get_profile_dir(&profile_name);
-        let profile = profile_manager.get_profile(&profile_name)
-            .map_err(|e| format!("Failed to get profile: {}", e))?;
+// Get the launcher crash report manager stored in app data
+fn crash_report_manager(app: &AppHandle) -> Result<CrashReportManager, String> {
+    let app_data_dir = app.path_resolver()
+        .app_data_dir()
+        .ok_or("Failed to get app data directory")?;
 
-        let mod_loader_type = profile.mod_loader_type.map(|t| match t {
-            ModLoaderType::ResoniteModLoader => "ResoniteModLoader".to_string(),
-            ModLoaderType::MonkeyLoader => "MonkeyLoader".to_string(),
-            ModLoaderType::BepisLoader => "BepisLoader".to_string(),
-        });
+    Ok(CrashReportManager::new(&app_data_dir))
+}
 
-        (profile_dir, mod_loader_type)
-    }; // MutexGuard is dropped here
+// Get crash reports left by previous launcher crashes (newest first)
+#[tauri::command]
+async fn get_crash_reports(app: AppHandle) -> Result<Vec<CrashReport>, String> {
+    Ok(crash_report_manager(&app)?.list())
+}
+
+// Format a crash report as a GitHub issue body
+#[tauri::command]
+async fn get_crash_report_issue(id: String, app: AppHandle) -> Result<String, String> {
+    crash_report_manager(&app)?
+        .get(&id)
+        .map(|report| report.to_issue_markdown())
+        .ok_or_else(|| format!("Crash report not found: {}", id))
+}
 
-    let mod_manager = ModManager::new(profile_dir);
+// Delete a crash report once the user has sent or dismissed it
+#[tauri::command]
+async fn delete_crash_report(id: String, app: AppHandle) -> Result<(), String> {
+    crash_report_manager(&app)?
+        .delete(&id)
+        .map_err(|e| format!("Failed to delete crash report: {}", e))
+}
 
-    mod_manager.install_mod_from_github(&repo_url, version.as_deref(), mod_loader_type.as_deref()).await
-        .map_err(|e| format!("Failed to install mod: {}", e))
+// Delete all crash reports
+#[tauri::command]
+async fn clear_crash_reports(app: AppHandle) -> Result<(), String> {
+    crash_report_manager(&app)?
+        .clear()
+        .map_err(|e| format!("Failed to clear crash reports: {}", e))
 }
 
 // Check if a GitHub repository requires multi-file installation
@@ -1346,7 +3114,7 @@ async fn check_multi_file_install(
         profile_manager.get_profile_dir("default")
     }; // MutexGuard is dropped here
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
     mod_manager.check_multi_file_install(&repo_url, version.as_deref()).await
         .map_err(|e| format!("Failed to check multi-file install: {}", e))
@@ -1370,7 +3138,7 @@ async fn install_multiple_files(
         profile_manager.get_profile_dir(&profile_name)
     }; // MutexGuard is dropped here
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
     mod_manager.install_multiple_files(&repo_url, version.as_deref(), choices).await
         .map_err(|e| format!("Failed to install multiple files: {}", e))
@@ -1382,6 +3150,7 @@ async fn uninstall_mod(
     profile_name: String,
     mod_name: String,
     source_location: Option<String>,
+    force: Option<bool>,
     state: State<'_, Mutex<AppState>>,
 ) -> Result<String, String> {
     let (profile_dir, mod_loader_type) = {
@@ -1411,14 +3180,145 @@ async fn uninstall_mod(
         return Ok(format!("Successfully uninstalled mod: {}", mod_name));
     }
 
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
 
-    mod_manager.uninstall_mod(&mod_name)
+    // Refuse to break mods that depend on this one unless the user confirmed
+    mod_manager.uninstall_mod_checked(&mod_name, force.unwrap_or(false)).await
         .map_err(|e| format!("Failed to uninstall mod: {}", e))?;
 
     Ok(format!("Successfully uninstalled mod: {}", mod_name))
 }
 
+// Get installed MODs that depend on the given MOD
+#[tauri::command]
+async fn get_dependent_mods(
+    profile_name: String,
+    mod_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<String>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.find_dependent_mods(&mod_name).await
+        .map_err(|e| format!("Failed to find dependent mods: {}", e))
+}
+
+// Find dependency libraries in rml_libs that no installed MOD needs anymore
+#[tauri::command]
+async fn find_orphaned_dependencies(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<PathBuf>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.find_orphaned_dependencies().await
+        .map_err(|e| format!("Failed to find orphaned dependencies: {}", e))
+}
+
+// Delete orphaned dependency libraries the user confirmed
+#[tauri::command]
+async fn delete_orphaned_dependencies(
+    profile_name: String,
+    files: Vec<PathBuf>,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<PathBuf>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.delete_orphaned_dependencies(&files).await
+        .map_err(|e| format!("Failed to delete orphaned dependencies: {}", e))
+}
+
+// Manually mark whether a MOD can be hot reloaded (None falls back to the manifest)
+#[tauri::command]
+async fn set_mod_hot_reload(
+    profile_name: String,
+    mod_name: String,
+    hot_reload: Option<bool>,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<InstalledMod, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.set_mod_hot_reload_override(&mod_name, hot_reload)
+        .map_err(|e| format!("Failed to set hot reload: {}", e))
+}
+
+// Tell whether updated MODs take effect via hot reload or need a Resonite restart
+#[tauri::command]
+async fn get_mod_reload_guidance(
+    profile_name: String,
+    mod_names: Vec<String>,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<ModReloadGuidance, String> {
+    let (profile_dir, resonite_running) = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        let profile = profile_manager.get_profile(&profile_name)
+            .map_err(|e| format!("Failed to get profile: {}", e))?;
+        let running_profiles = utils::identify_running_profiles();
+
+        (
+            profile_manager.get_profile_dir(&profile_name),
+            running_profiles.iter().any(|id| id == profile.get_folder_name()),
+        )
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+    let installed_mods = mod_manager.get_installed_mods()
+        .map_err(|e| format!("Failed to get installed mods: {}", e))?;
+
+    let mut guidance = ModReloadGuidance {
+        resonite_running,
+        hot_reload: Vec::new(),
+        restart_required: Vec::new(),
+    };
+    for installed_mod in installed_mods.iter().filter(|m| mod_names.contains(&m.name)) {
+        match installed_mod.reload_mode() {
+            ModReloadMode::HotReload => guidance.hot_reload.push(installed_mod.name.clone()),
+            ModReloadMode::RestartRequired => guidance.restart_required.push(installed_mod.name.clone()),
+        }
+    }
+
+    Ok(guidance)
+}
+
 // Disable a MOD (rename to .disabled)
 #[tauri::command]
 async fn disable_mod(
@@ -1435,7 +3335,7 @@ async fn disable_mod(
         profile_manager.get_profile_dir(&profile_name)
     }; // MutexGuard is dropped here
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
     mod_manager.disable_mod(&mod_name)
         .map_err(|e| format!("Failed to disable mod: {}", e))?;
@@ -1459,7 +3359,7 @@ async fn enable_mod(
         profile_manager.get_profile_dir(&profile_name)
     }; // MutexGuard is dropped here
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
     mod_manager.enable_mod(&mod_name)
         .map_err(|e| format!("Failed to enable mod: {}", e))?;
@@ -1475,20 +3375,216 @@ async fn migrate_installed_mods(
 ) -> Result<String, String> {
     let profile_dir = {
         let app_state = state.lock().unwrap();
-        
+        
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+        
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+    
+    let mod_manager = create_mod_manager(profile_dir);
+    
+    // インストール済みMODを取得（自動的にマイグレーションが実行される）
+    mod_manager.get_installed_mods()
+        .map_err(|e| format!("Failed to migrate installed mods: {}", e))?;
+    
+    Ok("Successfully migrated installed mods data".to_string())
+}
+
+// Get the config schema of a MOD for form-based editing
+#[tauri::command]
+async fn get_mod_config_schema(
+    profile_name: String,
+    mod_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<ConfigSchema, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.parse_mod_config_schema(&mod_name)
+        .map_err(|e| format!("Failed to parse mod config: {}", e))
+}
+
+// List which config files belong to which installed MOD (for migration and backups)
+#[tauri::command]
+async fn get_mod_config_map(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<ModConfigMap, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.map_mod_configs()
+        .map_err(|e| format!("Failed to map mod configs: {}", e))
+}
+
+// List the config files under rml_config with the MOD they belong to
+#[tauri::command]
+async fn list_mod_configs(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<ModConfig>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.list_mod_configs()
+        .map_err(|e| format!("Failed to list mod configs: {}", e))
+}
+
+// Read an rml_config file for the config editor
+#[tauri::command]
+async fn read_mod_config(
+    profile_name: String,
+    file_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<String, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.read_mod_config(&file_name)
+        .map_err(|e| format!("Failed to read mod config: {}", e))
+}
+
+// Save an rml_config file edited in the config editor
+#[tauri::command]
+async fn write_mod_config(
+    profile_name: String,
+    file_name: String,
+    content: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<(), String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.write_mod_config(&file_name, &content)
+        .map_err(|e| format!("Failed to write mod config: {}", e))
+}
+
+// Format the installed MOD list for pasting into Discord or GitHub issues
+#[tauri::command]
+async fn format_installed_mods(
+    profile_name: String,
+    format: OutputFormat,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<String, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    Ok(create_mod_manager(profile_dir).format_installed_mods(format))
+}
+
+// Export installed mods as a JSON mod list
+#[tauri::command]
+async fn export_mods(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<String, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.export_mod_list()
+        .map_err(|e| format!("Failed to export mods: {}", e))
+}
+
+// Preview the changes an import would make without touching any files
+#[tauri::command]
+async fn plan_import_mods(
+    profile_name: String,
+    mod_list_json: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<PlannedModChange>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.plan_import_mod_list(&mod_list_json)
+        .map_err(|e| format!("Failed to plan mod import: {}", e))
+}
+
+// Import a JSON mod list and reinstall each mod at the listed version
+#[tauri::command]
+async fn import_mods(
+    profile_name: String,
+    mod_list_json: String,
+    state: State<'_, Mutex<AppState>>,
+    window: Window,
+) -> Result<Vec<ModImportResult>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
         let profile_manager = app_state.profile_manager.as_ref()
             .ok_or("Profile manager not initialized")?;
-        
+
         profile_manager.get_profile_dir(&profile_name)
     }; // MutexGuard is dropped here
-    
-    let mod_manager = ModManager::new(profile_dir);
-    
-    // インストール済みMODを取得（自動的にマイグレーションが実行される）
-    mod_manager.get_installed_mods()
-        .map_err(|e| format!("Failed to migrate installed mods: {}", e))?;
-    
-    Ok("Successfully migrated installed mods data".to_string())
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.import_mod_list_with_progress(&mod_list_json, |progress| {
+        emit_mod_batch_progress(&window, &profile_name, "import", progress);
+    }).await
+        .map_err(|e| format!("Failed to import mods: {}", e))
 }
 
 // Migrate profile configuration to latest version
@@ -1523,6 +3619,7 @@ async fn migrate_profile_config(
 async fn get_mod_versions(
     profile_name: String,
     mod_info: ModInfo,
+    include_prerelease: Option<bool>,
     state: State<'_, Mutex<AppState>>,
 ) -> Result<Vec<ModRelease>, String> {
     let profile_dir = {
@@ -1534,12 +3631,56 @@ async fn get_mod_versions(
         profile_manager.get_profile_dir(&profile_name)
     };
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
-    mod_manager.get_mod_versions(&mod_info).await
+    mod_manager.get_mod_versions(&mod_info, include_prerelease.unwrap_or(false)).await
         .map_err(|e| format!("Failed to get mod versions: {}", e))
 }
 
+// Get the changelog (Markdown) of a specific MOD version
+#[tauri::command]
+async fn get_mod_changelog(
+    profile_name: String,
+    mod_info: ModInfo,
+    version: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Option<String>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+        
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+        
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+    
+    let mod_manager = create_mod_manager(profile_dir);
+    
+    mod_manager.get_mod_changelog(&mod_info, &version).await
+        .map_err(|e| format!("Failed to get mod changelog: {}", e))
+}
+
+// Get the release notes saved when each version of a MOD was installed
+#[tauri::command]
+async fn get_mod_changelog_history(
+    profile_name: String,
+    mod_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<VersionChangelog>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+        
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+        
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+    
+    let mod_manager = create_mod_manager(profile_dir);
+    
+    Ok(mod_manager.get_mod_changelog_history(&mod_name))
+}
+
 #[tauri::command]
 async fn get_github_releases(repo_url: String) -> Result<Vec<ModRelease>, String> {
     // GitHub リポジトリURLからAPI URLに変換
@@ -1588,6 +3729,9 @@ async fn get_github_releases(repo_url: String) -> Result<Vec<ModRelease>, String
             file_name: None,
             file_size: None,
             sha256: None,
+            artifacts: Vec::new(),
+            min_loader_version: None,
+            mirror_urls: Vec::new(),
         })
         .collect();
 
@@ -1611,7 +3755,7 @@ async fn update_mod(
         profile_manager.get_profile_dir(&profile_name)
     };
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
     mod_manager.update_mod(&mod_name, &target_version).await
         .map_err(|e| format!("Failed to update mod: {}", e))
@@ -1634,7 +3778,7 @@ async fn downgrade_mod(
         profile_manager.get_profile_dir(&profile_name)
     };
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
     mod_manager.downgrade_mod(&mod_name, &target_version).await
         .map_err(|e| format!("Failed to downgrade mod: {}", e))
@@ -1657,7 +3801,7 @@ async fn upgrade_mod(
         profile_manager.get_profile_dir(&profile_name)
     };
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
     mod_manager.upgrade_mod(&mod_name, target_version.as_deref()).await
         .map_err(|e| format!("Failed to upgrade mod: {}", e))
@@ -1678,17 +3822,160 @@ async fn get_upgradeable_mods(
         profile_manager.get_profile_dir(&profile_name)
     };
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
     mod_manager.get_upgradeable_mods().await
         .map_err(|e| format!("Failed to get upgradeable mods: {}", e))
 }
 
+// Get upgradeable MODs grouped by the mod loader that loads them
+#[tauri::command]
+async fn get_upgradeable_mods_by_loader(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<UpgradeableModGroup>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+        
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+        
+        profile_manager.get_profile_dir(&profile_name)
+    };
+    
+    let mod_manager = create_mod_manager(profile_dir);
+    
+    mod_manager.get_upgradeable_mods_by_loader().await
+        .map_err(|e| format!("Failed to get upgradeable mods: {}", e))
+}
+
+// Predict the order in which the MOD loader will load the installed MODs
+#[tauri::command]
+async fn get_predicted_load_order(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<String>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+        
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+        
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+    
+    let mod_manager = create_mod_manager(profile_dir);
+    
+    Ok(mod_manager.get_predicted_load_order())
+}
+
+// Set the MOD load order by renaming the MOD files with numeric prefixes
+#[tauri::command]
+async fn set_mod_load_order(
+    profile_name: String,
+    order: Vec<String>,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<InstalledMod>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.set_load_order(order)
+        .map_err(|e| format!("Failed to set load order: {}", e))
+}
+
+// Get the saved result of the previous MOD update check (shown before re-checking)
+#[tauri::command]
+async fn get_cached_upgradeable_mods(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Option<CachedUpgradeableMods>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+        
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+        
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+    
+    let mod_manager = create_mod_manager(profile_dir);
+    
+    Ok(mod_manager.get_cached_upgradeable_mods())
+}
+
+// Check whether the source repositories of installed MODs were deleted or archived
+#[tauri::command]
+async fn check_unmaintained_mods(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<UnmaintainedMod>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.check_unmaintained_mods().await
+        .map_err(|e| format!("Failed to check repositories: {}", e))
+}
+
+// Attach GitHub repository stats (stars, last update, open issues) to a MOD
+#[tauri::command]
+async fn fetch_repo_stats(
+    mut mod_info: ModInfo,
+    _state: State<'_, Mutex<AppState>>,
+) -> Result<ModInfo, String> {
+    let temp_dir = std::env::temp_dir();
+    let mod_manager = create_mod_manager(temp_dir);
+    
+    let stats = mod_manager.fetch_repo_stats(&mod_info.source_location).await
+        .map_err(|e| format!("Failed to fetch repository stats: {}", e))?;
+    mod_info.repo_stats = Some(stats);
+    
+    Ok(mod_info)
+}
+
+// Get the remaining GitHub API requests and when the limit resets
+#[tauri::command]
+async fn get_github_rate_limit(
+    _state: State<'_, Mutex<AppState>>,
+) -> Result<RateLimitInfo, String> {
+    let temp_dir = std::env::temp_dir();
+    let mod_manager = create_mod_manager(temp_dir);
+    
+    mod_manager.get_github_rate_limit().await
+        .map_err(|e| format!("Failed to fetch GitHub rate limit: {}", e))
+}
+
+// Notify the overall progress of a bulk MOD operation so the GUI can show a single progress bar
+fn emit_mod_batch_progress(window: &Window, profile_name: &str, operation: &str, progress: &BatchProgress) {
+    let _ = window.emit("mod-batch-progress", serde_json::json!({
+        "profile_name": profile_name,
+        "operation": operation,
+        "progress": progress
+    }));
+}
+
 // Bulk upgrade MODs
 #[tauri::command]
 async fn bulk_upgrade_mods(
     profile_name: String,
+    loader_type: Option<ModLoaderType>,
     state: State<'_, Mutex<AppState>>,
+    window: Window,
 ) -> Result<Vec<InstalledMod>, String> {
     let profile_dir = {
         let app_state = state.lock().unwrap();
@@ -1699,12 +3986,41 @@ async fn bulk_upgrade_mods(
         profile_manager.get_profile_dir(&profile_name)
     };
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
-    mod_manager.bulk_upgrade_mods().await
+    // 1件ごとの進捗と全体の進捗をGUIに通知（loader_type指定時はそのローダーのMODだけ）
+    mod_manager.bulk_upgrade_loader_mods_with_progress(loader_type, |progress| {
+        let _ = window.emit("mod-upgrade-progress", serde_json::json!({
+            "profile_name": profile_name,
+            "progress": progress
+        }));
+    }, |progress| {
+        emit_mod_batch_progress(&window, &profile_name, "bulk_upgrade", progress);
+    }).await
         .map_err(|e| format!("Failed to bulk upgrade mods: {}", e))
 }
 
+// Preview the upgrades bulk_upgrade_mods would perform without touching any files
+#[tauri::command]
+async fn plan_bulk_upgrade_mods(
+    profile_name: String,
+    state: State<'_, Mutex<AppState>>,
+) -> Result<Vec<PlannedModChange>, String> {
+    let profile_dir = {
+        let app_state = state.lock().unwrap();
+
+        let profile_manager = app_state.profile_manager.as_ref()
+            .ok_or("Profile manager not initialized")?;
+
+        profile_manager.get_profile_dir(&profile_name)
+    }; // MutexGuard is dropped here
+
+    let mod_manager = create_mod_manager(profile_dir);
+
+    mod_manager.plan_bulk_upgrade().await
+        .map_err(|e| format!("Failed to plan bulk upgrade: {}", e))
+}
+
 // Get all releases for a GitHub repository
 #[tauri::command]
 async fn get_all_github_releases(
@@ -1712,7 +4028,7 @@ async fn get_all_github_releases(
     _state: State<'_, Mutex<AppState>>,
 ) -> Result<Vec<GitHubRelease>, String> {
     let temp_dir = std::env::temp_dir();
-    let mod_manager = ModManager::new(temp_dir);
+    let mod_manager = create_mod_manager(temp_dir);
     
     mod_manager.get_all_releases(&repo_url).await
         .map_err(|e| format!("Failed to get releases: {}", e))
@@ -1733,7 +4049,7 @@ async fn scan_unmanaged_mods(
         profile_manager.get_profile_dir(&profile_name)
     };
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
     // MODフォルダをスキャン
     let unmanaged_mods = mod_manager.scan_mod_folder()
@@ -1762,7 +4078,7 @@ async fn add_unmanaged_mod_to_system(
         profile_manager.get_profile_dir(&profile_name)
     };
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
     mod_manager.add_unmanaged_mod_to_system(&unmanaged_mod).await
         .map_err(|e| format!("Failed to add unmanaged mod: {}", e))
@@ -1784,7 +4100,7 @@ async fn add_all_unmanaged_mods_to_system(
         profile_manager.get_profile_dir(&profile_name)
     };
     
-    let mod_manager = ModManager::new(profile_dir);
+    let mod_manager = create_mod_manager(profile_dir);
     
     mod_manager.add_multiple_unmanaged_mods(&unmanaged_mods).await
         .map_err(|e| format!("Failed to add unmanaged mods: {}", e))
@@ -1799,7 +4115,7 @@ async fn get_github_release_info(
     // 任意のプロファイルディレクトリを使用（API呼び出しのみなので実際のパスは不要）
     let temp_dir = std::env::temp_dir();
     
-    let mod_manager = ModManager::new(temp_dir);
+    let mod_manager = create_mod_manager(temp_dir);
     
     let (version, _download_url) = mod_manager.get_latest_release_info(&repo_url).await
         .map_err(|e| format!("Failed to get release info: {}", e))?;
@@ -1861,6 +4177,33 @@ async fn fetch_steam_news() -> Result<serde_json::Value, String> {
     Ok(json_data)
 }
 
+// yt-dlp executable name Resonite looks for in RuntimeData on this OS
+fn yt_dlp_executable_name() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "yt-dlp.exe"
+    } else {
+        "yt-dlp"
+    }
+}
+
+// Download URL of the standalone yt-dlp binary for this OS
+fn yt_dlp_download_url() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "https://github.com/yt-dlp/yt-dlp/releases/latest/download/yt-dlp.exe"
+    } else if cfg!(target_os = "macos") {
+        "https://github.com/yt-dlp/yt-dlp/releases/latest/download/yt-dlp_macos"
+    } else if cfg!(target_arch = "aarch64") {
+        "https://github.com/yt-dlp/yt-dlp/releases/latest/download/yt-dlp_linux_aarch64"
+    } else {
+        "https://github.com/yt-dlp/yt-dlp/releases/latest/download/yt-dlp_linux"
+    }
+}
+
+// Path of yt-dlp inside the game's RuntimeData directory
+fn yt_dlp_path(game_path: &std::path::Path) -> PathBuf {
+    game_path.join("RuntimeData").join(yt_dlp_executable_name())
+}
+
 // Get yt-dlp status for a profile
 #[tauri::command]
 async fn get_yt_dlp_status(
@@ -1874,7 +4217,7 @@ async fn get_yt_dlp_status(
     
     let profile_dir = profile_manager.get_profile_dir(&profile_name);
     let game_path = find_game_path(&profile_dir)?;
-    let yt_dlp_path = game_path.join("RuntimeData").join("yt-dlp.exe");
+    let yt_dlp_path = yt_dlp_path(&game_path);
     
     if !yt_dlp_path.exists() {
         return Ok(YtDlpInfo {
@@ -1918,11 +4261,11 @@ async fn get_yt_dlp_status(
 // Download yt-dlp using ModManager
 async fn download_yt_dlp(yt_dlp_path: &std::path::Path) -> Result<(), String> {
     let temp_dir = std::env::temp_dir();
-    let _mod_manager = ModManager::new(temp_dir);
+    let _mod_manager = create_mod_manager(temp_dir);
     
     // Create a temporary HTTP client through ModManager
     let client = reqwest::Client::new();
-    let download_url = "https://github.com/yt-dlp/yt-dlp/releases/latest/download/yt-dlp.exe";
+    let download_url = yt_dlp_download_url();
     
     let response = client.get(download_url)
         .send()
@@ -1938,7 +4281,15 @@ async fn download_yt_dlp(yt_dlp_path: &std::path::Path) -> Result<(), String> {
         .map_err(|e| format!("Failed to read yt-dlp content: {}", e))?;
     
     std::fs::write(yt_dlp_path, content)
-        .map_err(|e| format!("Failed to write yt-dlp.exe: {}", e))?;
+        .map_err(|e| format!("Failed to write {}: {}", yt_dlp_executable_name(), e))?;
+
+    // The Linux/macOS binaries are downloaded without the executable bit
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(yt_dlp_path, std::fs::Permissions::from_mode(0o755))
+            .map_err(|e| format!("Failed to make {} executable: {}", yt_dlp_executable_name(), e))?;
+    }
     
     Ok(())
 }
@@ -1961,13 +4312,14 @@ async fn update_yt_dlp(
         (profile_dir, game_path)
     }; // MutexGuard is dropped here
     
-    let runtime_data_path = game_path.join("RuntimeData");
-    let yt_dlp_path = runtime_data_path.join("yt-dlp.exe");
+    let yt_dlp_path = yt_dlp_path(&game_path);
     
     if !yt_dlp_path.exists() {
         // If yt-dlp doesn't exist, download it first
-        std::fs::create_dir_all(&runtime_data_path)
-            .map_err(|e| format!("Failed to create RuntimeData directory: {}", e))?;
+        if let Some(runtime_data_path) = yt_dlp_path.parent() {
+            std::fs::create_dir_all(runtime_data_path)
+                .map_err(|e| format!("Failed to create RuntimeData directory: {}", e))?;
+        }
         
         download_yt_dlp(&yt_dlp_path).await?;
         
@@ -2060,13 +4412,18 @@ async fn download_depot_downloader(state: State<'_, Mutex<AppState>>) -> Result<
             .clone()
     };
     
-    let depot_path = std::path::Path::new(&exe_dir).join("DepotDownloader.exe");
+    // Pick the build for the OS we are running on
+    let exe_name = DepotDownloader::executable_name();
+    let depot_path = std::path::Path::new(&exe_dir).join(exe_name);
     
     // Download DepotDownloader from GitHub releases
     let client = reqwest::Client::new();
-    let download_url = "https://github.com/SteamRE/DepotDownloader/releases/latest/download/DepotDownloader-windows-x64.zip";
+    let download_url = format!(
+        "https://github.com/SteamRE/DepotDownloader/releases/latest/download/{}",
+        DepotDownloader::release_asset_name()
+    );
     
-    let response = client.get(download_url)
+    let response = client.get(&download_url)
         .send()
         .await
         .map_err(|e| format!("Failed to download DepotDownloader: {}", e))?;
@@ -2083,17 +4440,26 @@ async fn download_depot_downloader(state: State<'_, Mutex<AppState>>) -> Result<
     let mut archive = zip::ZipArchive::new(std::io::Cursor::new(content))
         .map_err(|e| format!("Failed to open DepotDownloader ZIP: {}", e))?;
     
-    // Extract DepotDownloader.exe
+    // Extract the DepotDownloader executable
     for i in 0..archive.len() {
         let mut file = archive.by_index(i)
             .map_err(|e| format!("Failed to read ZIP entry: {}", e))?;
         
-        if file.name() == "DepotDownloader.exe" {
+        if file.name() == exe_name {
             let mut out_file = std::fs::File::create(&depot_path)
-                .map_err(|e| format!("Failed to create DepotDownloader.exe: {}", e))?;
+                .map_err(|e| format!("Failed to create {}: {}", exe_name, e))?;
             
             std::io::copy(&mut file, &mut out_file)
-                .map_err(|e| format!("Failed to extract DepotDownloader.exe: {}", e))?;
+                .map_err(|e| format!("Failed to extract {}: {}", exe_name, e))?;
+            drop(out_file);
+
+            // ZIP extraction doesn't keep the executable bit on Linux/macOS
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                std::fs::set_permissions(&depot_path, std::fs::Permissions::from_mode(0o755))
+                    .map_err(|e| format!("Failed to make {} executable: {}", exe_name, e))?;
+            }
             
             // Update state
             {
@@ -2107,7 +4473,36 @@ async fn download_depot_downloader(state: State<'_, Mutex<AppState>>) -> Result<
         }
     }
     
-    Err("DepotDownloader.exe not found in the downloaded ZIP file".to_string())
+    Err(format!("{} not found in the downloaded ZIP file", exe_name))
+}
+
+// Check whether the .NET runtime DepotDownloader needs is installed
+#[tauri::command]
+async fn check_dotnet_runtime(state: State<'_, Mutex<AppState>>) -> Result<RuntimeStatus, String> {
+    let app_state = state.lock().unwrap();
+
+    let depot_downloader = app_state.depot_downloader.as_ref()
+        .ok_or("DepotDownloader not initialized")?;
+
+    depot_downloader.check_runtime()
+        .map_err(|e| format!("Failed to check .NET runtime: {}", e))
+}
+
+// Download the .NET runtime next to DepotDownloader so the portable build works without a system install
+#[tauri::command]
+async fn install_dotnet_runtime(state: State<'_, Mutex<AppState>>) -> Result<RuntimeStatus, String> {
+    let depot_downloader = {
+        let app_state = state.lock().unwrap();
+        app_state.depot_downloader.as_ref()
+            .ok_or("DepotDownloader not initialized")?
+            .clone()
+    }; // MutexGuard is dropped here
+
+    depot_downloader.install_portable_runtime().await
+        .map_err(|e| format!("Failed to install .NET runtime: {}", e))?;
+
+    depot_downloader.check_runtime()
+        .map_err(|e| format!("Failed to check .NET runtime: {}", e))
 }
 
 // Complete first run setup
@@ -2203,6 +4598,201 @@ async fn install_app_update(app: AppHandle, window: Window) -> Result<String, St
     }
 }
 
+// Download the latest portable release and replace the running executable
+#[tauri::command]
+async fn download_and_replace_portable(asset_url: String, app: AppHandle) -> Result<String, String> {
+    if !is_portable_build() {
+        return Err("Portable update is only available in the portable version".to_string());
+    }
+
+    // Only accept assets from this project's GitHub releases
+    if !asset_url.starts_with("https://github.com/resonite-love/launcher/releases/download/") {
+        return Err(format!("Unexpected update asset URL: {}", asset_url));
+    }
+
+    // The executable is swapped by a batch script, so don't download anything elsewhere
+    if !cfg!(target_os = "windows") {
+        return Err("Portable update is only supported on Windows".to_string());
+    }
+
+    let current_exe = std::env::current_exe()
+        .map_err(|e| format!("Failed to get executable path: {}", e))?;
+    let exe_dir = current_exe.parent()
+        .ok_or("Failed to get executable directory")?
+        .to_path_buf();
+
+    // Download the asset
+    let client = reqwest::Client::new();
+    let response = client
+        .get(&asset_url)
+        .header("User-Agent", "reso-launcher")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to download update: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to download update: HTTP {}", response.status()));
+    }
+
+    let content = response.bytes().await
+        .map_err(|e| format!("Failed to read update: {}", e))?;
+
+    // Stage the new version next to the running executable
+    let staging_dir = exe_dir.join("update_staging");
+    if staging_dir.exists() {
+        std::fs::remove_dir_all(&staging_dir)
+            .map_err(|e| format!("Failed to clean staging directory: {}", e))?;
+    }
+    std::fs::create_dir_all(&staging_dir)
+        .map_err(|e| format!("Failed to create staging directory: {}", e))?;
+
+    let file_name = asset_url.rsplit('/').next().unwrap_or_default().to_lowercase();
+    let staged = (|| -> Result<(), String> {
+        let new_exe = if file_name.ends_with(".zip") {
+            extract_portable_update(&content, &staging_dir)?
+        } else if file_name.ends_with(".exe") {
+            let path = staging_dir.join(current_exe.file_name().ok_or("Failed to get executable name")?);
+            std::fs::write(&path, &content)
+                .map_err(|e| format!("Failed to save update: {}", e))?;
+            path
+        } else {
+            return Err(format!("Unsupported update asset: {}", file_name));
+        };
+
+        // Replace the executable after this process exits, keeping the old one as a backup
+        let script = write_portable_update_script(&current_exe, &new_exe, &staging_dir)?;
+        if let Err(e) = spawn_portable_update_script(&script) {
+            let _ = std::fs::remove_file(&script);
+            return Err(e);
+        }
+        Ok(())
+    })();
+
+    // Don't leave a half-staged update behind when it can't be applied
+    if let Err(e) = staged {
+        let _ = std::fs::remove_dir_all(&staging_dir);
+        return Err(e);
+    }
+
+    let app_clone = app.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        app_clone.exit(0);
+    });
+
+    Ok("Update downloaded. The application will restart to complete the update.".to_string())
+}
+
+// Extract a portable update ZIP and return the path of the executable in it
+fn extract_portable_update(content: &[u8], staging_dir: &std::path::Path) -> Result<std::path::PathBuf, String> {
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(content))
+        .map_err(|e| format!("Failed to open update ZIP: {}", e))?;
+
+    let mut new_exe: Option<std::path::PathBuf> = None;
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i)
+            .map_err(|e| format!("Failed to read ZIP entry: {}", e))?;
+
+        // Skip entries that would escape the staging directory
+        let relative = match file.enclosed_name() {
+            Some(path) => path.to_path_buf(),
+            None => continue,
+        };
+        let out_path = staging_dir.join(&relative);
+
+        if file.is_dir() {
+            std::fs::create_dir_all(&out_path)
+                .map_err(|e| format!("Failed to create directory: {}", e))?;
+            continue;
+        }
+        if let Some(parent) = out_path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directory: {}", e))?;
+        }
+
+        let mut out_file = std::fs::File::create(&out_path)
+            .map_err(|e| format!("Failed to create {}: {}", out_path.display(), e))?;
+        std::io::copy(&mut file, &mut out_file)
+            .map_err(|e| format!("Failed to extract {}: {}", out_path.display(), e))?;
+
+        // Prefer the executable closest to the archive root
+        let is_exe = out_path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("exe"));
+        let is_shallower = new_exe.as_ref().is_none_or(|current| {
+            relative.components().count() < current.strip_prefix(staging_dir).map_or(usize::MAX, |p| p.components().count())
+        });
+        if is_exe && is_shallower {
+            new_exe = Some(out_path);
+        }
+    }
+
+    new_exe.ok_or_else(|| "No executable found in the update ZIP".to_string())
+}
+
+// Write a batch script that swaps the executable once the app has exited and rolls back on failure
+fn write_portable_update_script(
+    current_exe: &std::path::Path,
+    new_exe: &std::path::Path,
+    staging_dir: &std::path::Path,
+) -> Result<std::path::PathBuf, String> {
+    let script_path = staging_dir.with_file_name("update_portable.bat");
+    let pid = std::process::id();
+    let target = current_exe.display();
+    let backup = format!("{}.old", current_exe.display());
+
+    let script = format!(
+        "@echo off\r\n\
+         setlocal\r\n\
+         :wait\r\n\
+         tasklist /FI \"PID eq {pid}\" 2>NUL | find \"{pid}\" >NUL\r\n\
+         if not errorlevel 1 (\r\n\
+         \x20 timeout /t 1 /nobreak >NUL\r\n\
+         \x20 goto wait\r\n\
+         )\r\n\
+         if exist \"{backup}\" del /f /q \"{backup}\"\r\n\
+         move /y \"{target}\" \"{backup}\" >NUL || goto failed\r\n\
+         copy /y \"{new_exe}\" \"{target}\" >NUL || goto restore\r\n\
+         rmdir /s /q \"{staging}\"\r\n\
+         start \"\" \"{target}\"\r\n\
+         del \"%~f0\" & exit /b 0\r\n\
+         :restore\r\n\
+         move /y \"{backup}\" \"{target}\" >NUL\r\n\
+         :failed\r\n\
+         start \"\" \"{target}\"\r\n\
+         exit /b 1\r\n",
+        pid = pid,
+        backup = backup,
+        target = target,
+        new_exe = new_exe.display(),
+        staging = staging_dir.display(),
+    );
+
+    std::fs::write(&script_path, script)
+        .map_err(|e| format!("Failed to write update script: {}", e))?;
+    Ok(script_path)
+}
+
+// Run the update script detached from this process
+fn spawn_portable_update_script(script: &std::path::Path) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+
+        Command::new("cmd")
+            .arg("/C")
+            .arg(script)
+            .creation_flags(0x08000000 | 0x00000008) // CREATE_NO_WINDOW | DETACHED_PROCESS
+            .spawn()
+            .map_err(|e| format!("Failed to start update script: {}", e))?;
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = script;
+        Err("Portable update is only supported on Windows".to_string())
+    }
+}
+
 // Get application version
 #[tauri::command]
 fn get_app_version() -> String {
@@ -2455,17 +5045,62 @@ fn main() {
         .manage(Mutex::new(AppState::default()))
         .manage(ws_relay_state)
         .manage(log_watcher_state)
+        .setup(|app| {
+            // Save a crash report on panic so the next launch can offer to report it
+            match crash_report_manager(&app.handle()) {
+                Ok(manager) => manager.install_panic_hook(&app.package_info().version.to_string()),
+                Err(e) => eprintln!("Failed to set up crash reporting: {}", e),
+            }
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             initialize_app,
+            inspect_install_target,
             install_game_to_profile,
             install_game_to_profile_interactive,
+            enqueue_install,
+            cancel_queued_install,
+            get_install_queue_status,
             update_profile_game,
             update_profile_game_interactive,
+            estimate_install_size,
+            verify_profile_game,
+            repair_profile_game,
             check_profile_updates,
+            auto_update_profile_games,
+            deduplicate_game_installs,
+            get_version_history,
             get_profiles,
             create_profile,
+            toggle_profile_pin,
+            set_profile_debug_logging,
+            set_profile_display_settings,
+            set_profile_webhook,
+            get_profile_defaults,
+            save_profile_defaults,
+            list_templates,
+            save_template,
+            delete_template,
+            create_profile_from_template,
+            apply_profile_template_setup,
             launch_resonite,
+            is_profile_locked,
+            preflight_check,
+            check_data_path_writable,
+            check_mod_loader_mismatches,
+            disable_mismatched_mods,
             launch_resonite_with_mode,
+            launch_and_monitor,
+            list_logs,
+            get_log,
+            get_latest_log,
+            get_launch_stats,
+            launch_safe_mode,
+            get_safe_mode_pending_mods,
+            enable_next_safe_mode_mod,
+            restore_safe_mode_mods,
+            get_known_launch_args,
+            get_placeholder_variables,
             steam_login,
             save_steam_credentials,
             load_steam_credentials,
@@ -2473,32 +5108,84 @@ fn main() {
             get_profile_config,
             update_profile_config,
             get_mod_loader_status,
+            check_mod_loader_update,
             install_mod_loader,
+            migrate_mod_loader,
+            get_mod_loader_versions,
+            check_harmony,
+            ensure_harmony,
             uninstall_mod_loader,
             open_profile_folder,
             open_folder,
             duplicate_profile,
             delete_profile,
+            remove_game_from_profile,
             clear_profile_cache,
             clear_profile_database,
             check_for_app_update,
             fetch_mod_manifest,
+            refresh_mod_caches,
+            search_mods,
             get_installed_mods,
+            classify_installed_mods,
             install_mod_from_cache,
+            get_mod_artifact_choices,
+            install_mod_artifacts,
             install_mod_from_github,
+            get_recent_mod_searches,
+            add_recent_mod_search,
+            clear_recent_mod_searches,
+            get_recently_installed_mods,
+            clear_recently_installed_mods,
+            get_crash_reports,
+            get_crash_report_issue,
+            delete_crash_report,
+            clear_crash_reports,
+            install_mod_from_local,
             check_multi_file_install,
             install_multiple_files,
             uninstall_mod,
+            get_dependent_mods,
+            find_orphaned_dependencies,
+            delete_orphaned_dependencies,
+            set_mod_hot_reload,
+            get_mod_reload_guidance,
             disable_mod,
             enable_mod,
             migrate_installed_mods,
+            get_mod_config_schema,
+            get_mod_config_map,
+            list_mod_configs,
+            read_mod_config,
+            write_mod_config,
+            enqueue_mod_install,
+            get_mod_install_queue,
+            pause_mod_install_queue,
+            resume_mod_install_queue,
+            cancel_mod_install,
+            move_mod_install,
+            clear_finished_mod_installs,
+            format_installed_mods,
+            export_mods,
+            plan_import_mods,
+            import_mods,
             migrate_profile_config,
             get_mod_versions,
+            get_mod_changelog,
+            get_mod_changelog_history,
             get_github_releases,
             update_mod,
             downgrade_mod,
             upgrade_mod,
             get_upgradeable_mods,
+            get_upgradeable_mods_by_loader,
+            get_cached_upgradeable_mods,
+            get_predicted_load_order,
+            set_mod_load_order,
+            check_unmaintained_mods,
+            fetch_repo_stats,
+            get_github_rate_limit,
+            plan_bulk_upgrade_mods,
             bulk_upgrade_mods,
             get_all_github_releases,
             scan_unmanaged_mods,
@@ -2510,9 +5197,12 @@ fn main() {
             get_yt_dlp_status,
             update_yt_dlp,
             download_depot_downloader,
+            check_dotnet_runtime,
+            install_dotnet_runtime,
             complete_first_run_setup,
             check_app_updates,
             install_app_update,
+            download_and_replace_portable,
             get_app_version,
             is_portable_version,
             ws_relay_start_host,
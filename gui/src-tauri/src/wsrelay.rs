@@ -52,9 +52,24 @@ enum RelayMessage {
     Data {
         #[serde(skip_serializing_if = "Option::is_none")]
         client_id: Option<String>,
+        // クライアント側のローカル接続ID（1つのトンネルで複数のローカル接続を多重化する）
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stream_id: Option<String>,
         payload: String,
         binary: bool,
     },
+    #[serde(rename = "stream_opened")]
+    StreamOpened {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        client_id: Option<String>,
+        stream_id: String,
+    },
+    #[serde(rename = "stream_closed")]
+    StreamClosed {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        client_id: Option<String>,
+        stream_id: String,
+    },
     #[serde(rename = "tunnel_closed")]
     TunnelClosed { reason: String },
     #[serde(rename = "close_tunnel")]
@@ -67,6 +82,31 @@ enum RelayMessage {
     Pong,
 }
 
+// Base64デコード済みのペイロード（送信元のフレーム種別を保持する）
+struct RelayFrame {
+    data: Vec<u8>,
+    binary: bool,
+}
+
+impl RelayFrame {
+    fn decode(payload: &str, binary: bool) -> Self {
+        Self {
+            data: BASE64.decode(payload).unwrap_or_default(),
+            binary,
+        }
+    }
+
+    // 送信元のbinaryフラグに従ってText/Binaryを選ぶ
+    // （内容がUTF-8として有効かどうかでは判定しない）
+    fn into_message(self) -> Message {
+        if self.binary {
+            Message::Binary(self.data)
+        } else {
+            Message::Text(String::from_utf8_lossy(&self.data).into_owned())
+        }
+    }
+}
+
 // ホスト状態
 #[allow(dead_code)]
 pub struct HostState {
@@ -292,7 +332,61 @@ pub async fn disconnect_client(state: SharedWsRelayState) -> Result<(), String>
 
 // ターゲットへの接続情報
 struct TargetConnection {
-    tx: tokio::sync::mpsc::Sender<Vec<u8>>,
+    client_id: String,
+    tx: tokio::sync::mpsc::Sender<RelayFrame>,
+}
+
+// ターゲット接続の識別キー（クライアントID + ローカル接続ID）
+fn connection_key(client_id: &str, stream_id: Option<&str>) -> String {
+    match stream_id {
+        Some(stream_id) => format!("{}/{}", client_id, stream_id),
+        None => client_id.to_string(),
+    }
+}
+
+// ターゲット接続が無ければ登録して接続を開始する
+// 接続完了前に届いたデータはチャンネルにバッファされる
+async fn ensure_target_connection(
+    target_connections: &Arc<Mutex<HashMap<String, TargetConnection>>>,
+    target_address: &str,
+    client_id: &str,
+    stream_id: Option<&str>,
+    relay_tx: &tokio::sync::mpsc::Sender<RelayMessage>,
+) {
+    let key = connection_key(client_id, stream_id);
+    let rx = {
+        let mut conns = target_connections.lock().await;
+        if conns.contains_key(&key) {
+            return;
+        }
+        let (tx, rx) = tokio::sync::mpsc::channel::<RelayFrame>(100);
+        conns.insert(key.clone(), TargetConnection {
+            client_id: client_id.to_string(),
+            tx,
+        });
+        rx
+    };
+
+    let target_conns = target_connections.clone();
+    let target = target_address.to_string();
+    let cid = client_id.to_string();
+    let sid = stream_id.map(|s| s.to_string());
+    let relay_tx_clone = relay_tx.clone();
+
+    tokio::spawn(async move {
+        if let Err(e) = connect_to_target(
+            target_conns.clone(),
+            target,
+            key.clone(),
+            cid,
+            sid,
+            rx,
+            relay_tx_clone,
+        ).await {
+            eprintln!("Failed to connect to target: {}", e);
+            target_conns.lock().await.remove(&key);
+        }
+    });
 }
 
 // ホストのメッセージループ
@@ -338,23 +432,7 @@ async fn host_message_loop(
                         if let Ok(parsed) = serde_json::from_str::<RelayMessage>(&text) {
                             match parsed {
                                 RelayMessage::ClientConnected { client_id } => {
-                                    // 新しいクライアント -> ターゲットWSに接続
-                                    let target_conns = target_connections.clone();
-                                    let target = target_address.clone();
-                                    let cid = client_id.clone();
-                                    let relay_tx_clone = relay_tx.clone();
-
-                                    tokio::spawn(async move {
-                                        if let Err(e) = connect_to_target(
-                                            target_conns,
-                                            target,
-                                            cid,
-                                            relay_tx_clone,
-                                        ).await {
-                                            eprintln!("Failed to connect to target: {}", e);
-                                        }
-                                    });
-
+                                    // ターゲット接続はストリームごと（旧クライアントは最初のデータ受信時）に作る
                                     // 状態更新
                                     let mut state_write = state.write().await;
                                     if let Some(ref mut host) = state_write.host {
@@ -367,9 +445,10 @@ async fn host_message_loop(
                                     });
                                 }
                                 RelayMessage::ClientDisconnected { client_id, .. } => {
-                                    // クライアント切断 -> ターゲット接続を閉じる
+                                    // クライアント切断 -> そのクライアントの全ターゲット接続を閉じる
                                     let mut conns = target_connections.lock().await;
-                                    conns.remove(&client_id);
+                                    conns.retain(|_, conn| conn.client_id != client_id);
+                                    drop(conns);
 
                                     let mut state_write = state.write().await;
                                     if let Some(ref mut host) = state_write.host {
@@ -381,14 +460,41 @@ async fn host_message_loop(
                                         client_id,
                                     });
                                 }
-                                RelayMessage::Data { client_id, payload, .. } => {
+                                RelayMessage::StreamOpened { client_id: Some(client_id), stream_id } => {
+                                    // クライアント側の新しいローカル接続 -> 専用のターゲット接続を作る
+                                    ensure_target_connection(
+                                        &target_connections,
+                                        &target_address,
+                                        &client_id,
+                                        Some(&stream_id),
+                                        &relay_tx,
+                                    ).await;
+                                }
+                                RelayMessage::StreamClosed { client_id: Some(client_id), stream_id } => {
+                                    // ローカル接続ごとのクリーンアップ
+                                    let mut conns = target_connections.lock().await;
+                                    conns.remove(&connection_key(&client_id, Some(&stream_id)));
+                                }
+                                RelayMessage::Data { client_id, stream_id, payload, binary } => {
                                     // クライアントからのデータをターゲットに転送
                                     // payloadは常にBase64エンコードされている
                                     if let Some(client_id) = client_id {
-                                        let conns = target_connections.lock().await;
-                                        if let Some(conn) = conns.get(&client_id) {
-                                            let data = BASE64.decode(&payload).unwrap_or_default();
-                                            let _ = conn.tx.send(data).await;
+                                        // stream_openedより先にデータが届いた場合や、stream_idを使わない
+                                        // 旧クライアントの最初のデータでも接続を用意する
+                                        ensure_target_connection(
+                                            &target_connections,
+                                            &target_address,
+                                            &client_id,
+                                            stream_id.as_deref(),
+                                            &relay_tx,
+                                        ).await;
+                                        let tx = {
+                                            let conns = target_connections.lock().await;
+                                            conns.get(&connection_key(&client_id, stream_id.as_deref()))
+                                                .map(|conn| conn.tx.clone())
+                                        };
+                                        if let Some(tx) = tx {
+                                            let _ = tx.send(RelayFrame::decode(&payload, binary)).await;
                                         }
                                     }
                                 }
@@ -420,7 +526,10 @@ async fn host_message_loop(
 async fn connect_to_target(
     target_connections: Arc<Mutex<HashMap<String, TargetConnection>>>,
     target_address: String,
+    key: String,
     client_id: String,
+    stream_id: Option<String>,
+    mut rx: tokio::sync::mpsc::Receiver<RelayFrame>,
     relay_tx: tokio::sync::mpsc::Sender<RelayMessage>,
 ) -> Result<(), String> {
     let (ws_stream, _) = connect_async(&target_address)
@@ -429,40 +538,22 @@ async fn connect_to_target(
 
     let (mut target_write, mut target_read) = ws_stream.split();
 
-    // ターゲットへの送信用チャンネル
-    let (tx, mut rx) = tokio::sync::mpsc::channel::<Vec<u8>>(100);
-
-    // 接続を登録
-    {
-        let mut conns = target_connections.lock().await;
-        conns.insert(client_id.clone(), TargetConnection { tx });
-    }
-
-    let cid = client_id.clone();
     let target_conns = target_connections.clone();
 
     // ターゲットへの送信タスク
-    let cid_send = cid.clone();
+    let key_send = key.clone();
     tokio::spawn(async move {
-        while let Some(data) = rx.recv().await {
-            // テキストとして送信を試みる
-            if let Ok(text) = String::from_utf8(data.clone()) {
-                if target_write.send(Message::Text(text)).await.is_err() {
-                    break;
-                }
-            } else {
-                if target_write.send(Message::Binary(data)).await.is_err() {
-                    break;
-                }
+        while let Some(frame) = rx.recv().await {
+            if target_write.send(frame.into_message()).await.is_err() {
+                break;
             }
         }
         // 接続を削除
         let mut conns = target_conns.lock().await;
-        conns.remove(&cid_send);
+        conns.remove(&key_send);
     });
 
     // ターゲットからの受信タスク
-    let cid_recv = cid.clone();
     tokio::spawn(async move {
         while let Some(Ok(msg)) = target_read.next().await {
             let (payload, binary) = match msg {
@@ -473,7 +564,8 @@ async fn connect_to_target(
             };
 
             let data_msg = RelayMessage::Data {
-                client_id: Some(cid_recv.clone()),
+                client_id: Some(client_id.clone()),
+                stream_id: stream_id.clone(),
                 payload,
                 binary,
             };
@@ -488,7 +580,9 @@ async fn connect_to_target(
 
 // ローカルクライアント接続情報
 struct LocalClient {
-    tx: tokio::sync::mpsc::Sender<Vec<u8>>,
+    tx: tokio::sync::mpsc::Sender<RelayFrame>,
+    // 接続順（stream_idを返さない旧ホストからのデータは最新の接続に送る）
+    order: u64,
 }
 
 // クライアントのメッセージループ
@@ -500,8 +594,9 @@ async fn client_message_loop(
     mut shutdown_rx: tokio::sync::broadcast::Receiver<()>,
     app_handle: AppHandle,
 ) {
-    // 現在のローカルクライアント（単一接続）
-    let local_client: Arc<Mutex<Option<LocalClient>>> = Arc::new(Mutex::new(None));
+    // ローカルクライアント（stream_idで識別）
+    let local_clients: Arc<Mutex<HashMap<String, LocalClient>>> = Arc::new(Mutex::new(HashMap::new()));
+    let mut next_stream: u64 = 0;
 
     // 中継サーバーへの送信用チャンネル
     let (relay_tx, mut relay_rx) = tokio::sync::mpsc::channel::<RelayMessage>(100);
@@ -534,37 +629,33 @@ async fn client_message_loop(
                                 let (mut local_write, mut local_read) = ws_stream.split();
 
                                 // ローカルクライアントへの送信用チャンネル
-                                let (tx, mut rx) = tokio::sync::mpsc::channel::<Vec<u8>>(100);
+                                let (tx, mut rx) = tokio::sync::mpsc::channel::<RelayFrame>(100);
 
-                                // ローカルクライアントを登録
+                                // ローカルクライアントを登録し、ホストに新しい接続を通知
+                                next_stream += 1;
+                                let stream_id = format!("local-{}", next_stream);
                                 {
-                                    let mut lc = local_client.lock().await;
-                                    *lc = Some(LocalClient { tx });
+                                    let mut clients = local_clients.lock().await;
+                                    clients.insert(stream_id.clone(), LocalClient { tx, order: next_stream });
                                 }
+                                let _ = relay_tx.send(RelayMessage::StreamOpened {
+                                    client_id: None,
+                                    stream_id: stream_id.clone(),
+                                }).await;
 
                                 // ローカルクライアントへの送信タスク
-                                let lc_clone = local_client.clone();
+                                // （登録が解除されてチャンネルが閉じると終了する）
                                 tokio::spawn(async move {
-                                    while let Some(data) = rx.recv().await {
-                                        // テキストとして送信を試みる
-                                        if let Ok(text) = String::from_utf8(data.clone()) {
-                                            if local_write.send(Message::Text(text)).await.is_err() {
-                                                break;
-                                            }
-                                        } else {
-                                            if local_write.send(Message::Binary(data)).await.is_err() {
-                                                break;
-                                            }
+                                    while let Some(frame) = rx.recv().await {
+                                        if local_write.send(frame.into_message()).await.is_err() {
+                                            break;
                                         }
                                     }
-                                    // 接続終了
-                                    let mut lc = lc_clone.lock().await;
-                                    *lc = None;
                                 });
 
                                 // ローカルクライアントからの受信タスク
                                 let relay_tx_clone = relay_tx.clone();
-                                let lc_clone2 = local_client.clone();
+                                let clients_clone = local_clients.clone();
                                 tokio::spawn(async move {
                                     while let Some(Ok(msg)) = local_read.next().await {
                                         let (payload, binary) = match msg {
@@ -576,6 +667,7 @@ async fn client_message_loop(
 
                                         let data_msg = RelayMessage::Data {
                                             client_id: None,
+                                            stream_id: Some(stream_id.clone()),
                                             payload,
                                             binary,
                                         };
@@ -583,9 +675,12 @@ async fn client_message_loop(
                                             break;
                                         }
                                     }
-                                    // 接続終了
-                                    let mut lc = lc_clone2.lock().await;
-                                    *lc = None;
+                                    // 接続終了 -> この接続だけを片付けてホストに通知
+                                    clients_clone.lock().await.remove(&stream_id);
+                                    let _ = relay_tx_clone.send(RelayMessage::StreamClosed {
+                                        client_id: None,
+                                        stream_id,
+                                    }).await;
                                 });
                             }
                             Err(_) => {}
@@ -603,13 +698,19 @@ async fn client_message_loop(
                                     // ループを抜けてクリーンアップでイベント発火
                                     break;
                                 }
-                                RelayMessage::Data { payload, .. } => {
-                                    // 中継サーバーからのデータをローカルクライアントに転送
+                                RelayMessage::Data { stream_id, payload, binary, .. } => {
+                                    // 中継サーバーからのデータを該当するローカルクライアントに転送
                                     // payloadは常にBase64エンコードされている
-                                    let lc = local_client.lock().await;
-                                    if let Some(ref client) = *lc {
-                                        let data = BASE64.decode(&payload).unwrap_or_default();
-                                        let _ = client.tx.send(data).await;
+                                    let tx = {
+                                        let clients = local_clients.lock().await;
+                                        let client = match stream_id {
+                                            Some(stream_id) => clients.get(&stream_id),
+                                            None => clients.values().max_by_key(|client| client.order),
+                                        };
+                                        client.map(|client| client.tx.clone())
+                                    };
+                                    if let Some(tx) = tx {
+                                        let _ = tx.send(RelayFrame::decode(&payload, binary)).await;
                                     }
                                 }
                                 RelayMessage::Ping => {
@@ -397,9 +397,24 @@ function ProfilesTab() {
     return sortedVersions[0];
   };
 
+  // Warn about enabled MODs that the profile's mod loader won't load
+  const warnModLoaderMismatches = async (profileName: string) => {
+    try {
+      const mismatches = await invoke<{ mod_name: string }[]>('check_mod_loader_mismatches', {
+        profileName,
+      });
+      if (mismatches.length > 0) {
+        toast(t('toasts.modLoaderMismatch', { mods: mismatches.map(m => m.mod_name).join(', ') }), { icon: '⚠️' });
+      }
+    } catch (err) {
+      console.error('Failed to check mod loader mismatches:', err);
+    }
+  };
+
   const launchProfile = async (profileName: string) => {
     try {
       setIsLoading(true);
+      await warnModLoaderMismatches(profileName);
       const result = await invoke<string>('launch_resonite', {
         profileName,
       });
@@ -415,6 +430,7 @@ function ProfilesTab() {
   const launchProfileWithMode = async (profileName: string, mode: string) => {
     try {
       setIsLoading(true);
+      await warnModLoaderMismatches(profileName);
       const result = await invoke<string>('launch_resonite_with_mode', {
         profileName,
         mode,
@@ -4,6 +4,7 @@ use std::fs;
 use std::io::Read;
 use std::path::PathBuf;
 
+use crate::game_dedup;
 use crate::thunderstore::{ThunderstoreClient, ThunderstorePackage};
 
 /// BepisLoaderのステータス
@@ -286,7 +287,7 @@ impl BepisLoader {
 
                 let mut contents = Vec::new();
                 file.read_to_end(&mut contents)?;
-                fs::write(&dest, contents)?;
+                game_dedup::write_unshared(&dest, contents)?;
 
                 println!("Extracted: {} -> {}", file_name, dest.display());
                 extracted_files.push(dest);
@@ -369,7 +370,7 @@ impl BepisLoader {
             "[hookfxr]\nenable=false\n"
         };
 
-        fs::write(&ini_path, content)?;
+        game_dedup::write_unshared(&ini_path, content)?;
         Ok(())
     }
 
@@ -0,0 +1,235 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::backtrace::Backtrace;
+use std::error::Error;
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+/// 保持するクラッシュレポートの最大件数
+const MAX_CRASH_REPORTS: usize = 10;
+
+/// ランチャーのクラッシュレポート
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashReport {
+    /// レポートファイル名（拡張子無し）
+    #[serde(default)]
+    pub id: String,
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    pub crashed_at: String,
+    pub message: String,
+    pub location: Option<String>,
+    pub backtrace: String,
+}
+
+impl CrashReport {
+    /// GitHub issueに貼り付けられる形式に整形する
+    pub fn to_issue_markdown(&self) -> String {
+        format!(
+            "## クラッシュレポート\n\n\
+             - バージョン: {}\n\
+             - OS: {} ({})\n\
+             - 発生日時: {}\n\
+             - 発生箇所: {}\n\n\
+             ### メッセージ\n\n```\n{}\n```\n\n\
+             ### スタックトレース\n\n```\n{}\n```\n",
+            self.app_version,
+            self.os,
+            self.arch,
+            self.crashed_at,
+            self.location.as_deref().unwrap_or("不明"),
+            self.message,
+            self.backtrace.trim_end(),
+        )
+    }
+}
+
+/// ホームディレクトリとユーザー名をレポートから取り除く
+///
+/// パスに含まれるユーザー名などの個人情報をGitHub issueへ載せないため。
+fn sanitize_with(text: &str, home: Option<&str>, user: Option<&str>) -> String {
+    let mut sanitized = text.to_string();
+    if let Some(home) = home.filter(|h| h.len() > 1) {
+        sanitized = sanitized.replace(home, "~");
+        // バックトレース内ではWindowsのパス区切りが混在することがある
+        sanitized = sanitized.replace(&home.replace('\\', "/"), "~");
+    }
+    if let Some(user) = user.filter(|u| !u.trim().is_empty()) {
+        sanitized = sanitized.replace(user, "<user>");
+    }
+    sanitized
+}
+
+fn sanitize(text: &str) -> String {
+    let home = std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE")).ok();
+    let user = std::env::var("USER").or_else(|_| std::env::var("USERNAME")).ok();
+    sanitize_with(text, home.as_deref(), user.as_deref())
+}
+
+/// app_data内の `crash_reports` ディレクトリでクラッシュレポートを管理する
+#[derive(Clone)]
+pub struct CrashReportManager {
+    reports_dir: PathBuf,
+}
+
+impl CrashReportManager {
+    pub fn new(data_dir: &Path) -> Self {
+        CrashReportManager {
+            reports_dir: data_dir.join("crash_reports"),
+        }
+    }
+
+    /// panicフックを設定し、panic発生時にクラッシュレポートを保存する
+    ///
+    /// 既存のフック（標準エラーへの出力）もそのまま呼び出す。
+    pub fn install_panic_hook(&self, app_version: &str) {
+        let manager = self.clone();
+        let app_version = app_version.to_string();
+        let previous_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            let message = if let Some(s) = info.payload().downcast_ref::<&str>() {
+                s.to_string()
+            } else if let Some(s) = info.payload().downcast_ref::<String>() {
+                s.clone()
+            } else {
+                "Unknown panic".to_string()
+            };
+            let location = info
+                .location()
+                .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()));
+
+            let report = CrashReport {
+                id: String::new(),
+                app_version: app_version.clone(),
+                os: std::env::consts::OS.to_string(),
+                arch: std::env::consts::ARCH.to_string(),
+                crashed_at: Utc::now().to_rfc3339(),
+                message: sanitize(&message),
+                location: location.map(|l| sanitize(&l)),
+                backtrace: sanitize(&Backtrace::force_capture().to_string()),
+            };
+            if let Err(e) = manager.save(&report) {
+                eprintln!("Failed to save crash report: {}", e);
+            }
+
+            previous_hook(info);
+        }));
+    }
+
+    /// 保存されているクラッシュレポートを取得する（新しいものが先頭）
+    pub fn list(&self) -> Vec<CrashReport> {
+        let Ok(entries) = fs::read_dir(&self.reports_dir) else {
+            return Vec::new();
+        };
+
+        let mut reports: Vec<CrashReport> = entries
+            .flatten()
+            .filter(|entry| entry.path().extension().and_then(|e| e.to_str()) == Some("json"))
+            .filter_map(|entry| {
+                let content = fs::read_to_string(entry.path()).ok()?;
+                let mut report: CrashReport = serde_json::from_str(&content).ok()?;
+                report.id = entry.path().file_stem()?.to_string_lossy().to_string();
+                Some(report)
+            })
+            .collect();
+        reports.sort_by(|a, b| b.id.cmp(&a.id));
+        reports
+    }
+
+    /// IDを指定してクラッシュレポートを取得する
+    pub fn get(&self, id: &str) -> Option<CrashReport> {
+        self.list().into_iter().find(|report| report.id == id)
+    }
+
+    /// クラッシュレポートを削除する（提示済み・送信済みのもの）
+    pub fn delete(&self, id: &str) -> Result<(), Box<dyn Error>> {
+        let path = self.reports_dir.join(format!("{}.json", id));
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// すべてのクラッシュレポートを削除する
+    pub fn clear(&self) -> Result<(), Box<dyn Error>> {
+        for report in self.list() {
+            self.delete(&report.id)?;
+        }
+        Ok(())
+    }
+
+    fn save(&self, report: &CrashReport) -> Result<(), Box<dyn Error>> {
+        fs::create_dir_all(&self.reports_dir)?;
+
+        let id = format!("crash-{}", Utc::now().format("%Y%m%d-%H%M%S%.3f"));
+        fs::write(
+            self.reports_dir.join(format!("{}.json", id)),
+            serde_json::to_string_pretty(report)?,
+        )?;
+
+        // 古いレポートを削除
+        for old in self.list().iter().skip(MAX_CRASH_REPORTS) {
+            self.delete(&old.id)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_report(message: &str) -> CrashReport {
+        CrashReport {
+            id: String::new(),
+            app_version: "1.0.0".to_string(),
+            os: "windows".to_string(),
+            arch: "x86_64".to_string(),
+            crashed_at: "2025-01-01T00:00:00+00:00".to_string(),
+            message: message.to_string(),
+            location: Some("src/main.rs:10:5".to_string()),
+            backtrace: "0: main\n".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_sanitize_removes_home_and_user() {
+        let text = "failed to open C:\\Users\\alice\\AppData\\profile.json (alice)";
+        let sanitized = sanitize_with(text, Some("C:\\Users\\alice"), Some("alice"));
+        assert_eq!(sanitized, "failed to open ~\\AppData\\profile.json (<user>)");
+
+        let text = "at /home/bob/.cargo/registry/src/lib.rs";
+        assert_eq!(sanitize_with(text, Some("/home/bob"), None), "at ~/.cargo/registry/src/lib.rs");
+        assert_eq!(sanitize_with(text, Some("/"), Some(" ")), text);
+    }
+
+    #[test]
+    fn test_save_list_and_delete_reports() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = CrashReportManager::new(temp_dir.path());
+        assert!(manager.list().is_empty());
+
+        manager.save(&test_report("first")).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        manager.save(&test_report("second")).unwrap();
+
+        let reports = manager.list();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].message, "second");
+        assert!(reports[0].id.starts_with("crash-"));
+        assert!(manager.get(&reports[1].id).is_some());
+
+        let markdown = reports[0].to_issue_markdown();
+        assert!(markdown.contains("- バージョン: 1.0.0"));
+        assert!(markdown.contains("src/main.rs:10:5"));
+
+        manager.delete(&reports[0].id).unwrap();
+        assert_eq!(manager.list().len(), 1);
+        manager.clear().unwrap();
+        assert!(manager.list().is_empty());
+    }
+}
@@ -1,10 +1,197 @@
 use std::path::{Path, PathBuf};
 use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{Read, Write};
 use std::process::{Command, Output, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{self, NetworkStatus};
+
+/// ダウンロード中にネットワーク接続を確認する間隔
+const NETWORK_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+/// この回数続けて接続できなければ切断とみなす
+const NETWORK_FAILURE_THRESHOLD: u32 = 2;
+
+/// ダウンロード中にネットワークが切断された
+#[derive(Debug)]
+pub struct NetworkDisconnectedError;
+
+impl fmt::Display for NetworkDisconnectedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ネットワークが切断されました。再接続後に再開できます")
+    }
+}
+
+impl Error for NetworkDisconnectedError {}
+
+/// DepotDownloaderがSteam Guardの認証コードを要求したときに出力するプロンプト
+const STEAM_GUARD_PROMPTS: [&str; 3] = [
+    "2 factor auth code",
+    "2-factor auth code",
+    "auth code sent to the email",
+];
+
+/// Steam Guardの認証コードが必要（未入力、または入力したコードが拒否された）
+#[derive(Debug)]
+pub struct SteamGuardRequiredError;
+
+impl fmt::Display for SteamGuardRequiredError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Steam Guardの認証コードが必要です。認証コードを入力して再試行してください")
+    }
+}
+
+impl Error for SteamGuardRequiredError {}
+
+/// 出力にSteam Guardの認証コード要求が含まれているか
+fn contains_steam_guard_prompt(output: &str) -> bool {
+    let output = output.to_lowercase();
+    STEAM_GUARD_PROMPTS.iter().any(|prompt| output.contains(prompt))
+}
+
+/// DepotDownloaderの実行に必要な.NETランタイムのメジャーバージョン
+pub const REQUIRED_DOTNET_MAJOR: u32 = 8;
+
+/// .NETランタイムの公式ダウンロードページ
+pub const DOTNET_DOWNLOAD_URL: &str = "https://dotnet.microsoft.com/download/dotnet/8.0";
+
+/// ポータブル版の.NETランタイムを展開する、DepotDownloaderと同じフォルダ内のフォルダ名
+pub const PORTABLE_DOTNET_DIR: &str = "dotnet";
+
+/// DepotDownloaderの実行に必要な.NETランタイムが見つからない
+#[derive(Debug)]
+pub struct RuntimeMissingError {
+    pub status: RuntimeStatus,
+}
+
+impl fmt::Display for RuntimeMissingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.status.dotnet_found {
+            write!(
+                f,
+                "DepotDownloaderの実行には.NET {}以上のランタイムが必要です（インストール済み: {}）。{} からインストールするか、ランチャーからポータブル版のランタイムを導入してください",
+                self.status.required_major,
+                self.status.runtime_versions.join(", "),
+                DOTNET_DOWNLOAD_URL
+            )
+        } else {
+            write!(
+                f,
+                "DepotDownloaderの実行に必要な.NET {}ランタイムが見つかりません。{} からインストールするか、ランチャーからポータブル版のランタイムを導入してください",
+                self.status.required_major,
+                DOTNET_DOWNLOAD_URL
+            )
+        }
+    }
+}
+
+impl Error for RuntimeMissingError {}
+
+/// 実行時のOS・アーキテクチャ向けの.NETランタイムのアーカイブURL
+pub fn dotnet_runtime_archive_url() -> String {
+    let os = if cfg!(target_os = "windows") {
+        "win"
+    } else if cfg!(target_os = "macos") {
+        "osx"
+    } else {
+        "linux"
+    };
+    let arch = if cfg!(target_arch = "aarch64") { "arm64" } else { "x64" };
+    let extension = if cfg!(target_os = "windows") { "zip" } else { "tar.gz" };
+
+    format!(
+        "https://aka.ms/dotnet/{}.0/dotnet-runtime-{}-{}.{}",
+        REQUIRED_DOTNET_MAJOR, os, arch, extension
+    )
+}
+
+/// .NETランタイムの検査結果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeStatus {
+    /// `dotnet` コマンドが実行できたか
+    pub dotnet_found: bool,
+    /// インストール済みの.NETランタイム（Microsoft.NETCore.App）のバージョン
+    pub runtime_versions: Vec<String>,
+    /// 必要なバージョン以上のランタイムがあるか
+    pub meets_requirement: bool,
+    /// 必要なランタイムのメジャーバージョン
+    pub required_major: u32,
+}
+
+/// `dotnet --list-runtimes` の出力から Microsoft.NETCore.App のバージョンを取り出す
+///
+/// 各行は「Microsoft.NETCore.App 8.0.1 [C:\Program Files\dotnet\shared\Microsoft.NETCore.App]」の形式。
+pub fn parse_dotnet_runtimes(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            if parts.next()? != "Microsoft.NETCore.App" {
+                return None;
+            }
+            parts.next().map(|version| version.to_string())
+        })
+        .collect()
+}
+
+/// ランタイムのバージョン一覧に必要なメジャーバージョン以上のものがあるか
+fn has_required_runtime(versions: &[String]) -> bool {
+    versions.iter().any(|version| {
+        version
+            .split('.')
+            .next()
+            .and_then(|major| major.parse::<u32>().ok())
+            .is_some_and(|major| major >= REQUIRED_DOTNET_MAJOR)
+    })
+}
+
+/// マニフェストに記載されたファイル情報（`-manifest-only` の出力）
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestFileEntry {
+    /// Gameフォルダからの相対パス（区切りは `/`）
+    pub name: String,
+    pub size: u64,
+    /// SHA1ハッシュ（小文字16進）
+    pub sha1: String,
+}
+
+/// マニフェストのフラグのうちディレクトリを表すビット
+const MANIFEST_FLAG_DIRECTORY: u32 = 64;
+
+/// `-manifest-only` で出力されるマニフェストのファイル一覧をパースする
+///
+/// 各行は「サイズ チャンク数 SHA1 フラグ ファイル名」の形式。ディレクトリは除外する。
+pub fn parse_manifest_listing(content: &str) -> Vec<ManifestFileEntry> {
+    let line_regex = regex::Regex::new(r"^\s*(\d+)\s+\d+\s+([0-9a-fA-F]{40})\s+(\d+)\s+(.+?)\s*$").unwrap();
+
+    content
+        .lines()
+        .filter_map(|line| {
+            let caps = line_regex.captures(line)?;
+            let flags: u32 = caps[3].parse().ok()?;
+            if flags & MANIFEST_FLAG_DIRECTORY != 0 {
+                return None;
+            }
+            Some(ManifestFileEntry {
+                name: caps[4].replace('\\', "/"),
+                size: caps[1].parse().ok()?,
+                sha1: caps[2].to_lowercase(),
+            })
+        })
+        .collect()
+}
 
 /// DepotDownloaderを操作するための構造体
+#[derive(Clone)]
 pub struct DepotDownloader {
     path: PathBuf,
+    /// パスワード付きのbetaブランチのパスワード
+    beta_password: Option<String>,
 }
 
 impl DepotDownloader {
@@ -12,19 +199,50 @@ impl DepotDownloader {
     pub fn new(depot_downloader_path: &Path) -> Self {
         DepotDownloader {
             path: depot_downloader_path.to_path_buf(),
+            beta_password: None,
         }
     }
 
-    /// デフォルトの場所を使用して新しいインスタンスを作成
-    /// DepotDownloaderバイナリはreleaseフォルダに配置される
-    pub fn with_default_path(base_dir: &Path) -> Self {
-        let exe_name = if cfg!(target_os = "windows") {
+    /// betaブランチのパスワードを設定（空文字は未設定として扱う）
+    pub fn with_beta_password(mut self, beta_password: Option<String>) -> Self {
+        self.beta_password = beta_password.filter(|password| !password.is_empty());
+        self
+    }
+
+    /// 実行時のOSでのDepotDownloaderの実行ファイル名
+    pub fn executable_name() -> &'static str {
+        if cfg!(target_os = "windows") {
             "DepotDownloader.exe"
-        } else if cfg!(target_os = "macos") {
-            "DepotDownloader"
         } else {
             "DepotDownloader"
-        };
+        }
+    }
+
+    /// 実行時のOS・アーキテクチャ向けのリリースZIP名（GitHub Releasesのアセット名）
+    pub fn release_asset_name() -> &'static str {
+        if cfg!(target_os = "windows") {
+            if cfg!(target_arch = "aarch64") {
+                "DepotDownloader-windows-arm64.zip"
+            } else {
+                "DepotDownloader-windows-x64.zip"
+            }
+        } else if cfg!(target_os = "macos") {
+            if cfg!(target_arch = "aarch64") {
+                "DepotDownloader-macos-arm64.zip"
+            } else {
+                "DepotDownloader-macos-x64.zip"
+            }
+        } else if cfg!(target_arch = "aarch64") {
+            "DepotDownloader-linux-arm64.zip"
+        } else {
+            "DepotDownloader-linux-x64.zip"
+        }
+    }
+
+    /// デフォルトの場所を使用して新しいインスタンスを作成
+    /// DepotDownloaderバイナリはreleaseフォルダに配置される
+    pub fn with_default_path(base_dir: &Path) -> Self {
+        let exe_name = Self::executable_name();
 
         // 複数の場所でDepotDownloaderを検索
         let possible_paths = vec![
@@ -49,10 +267,13 @@ impl DepotDownloader {
 
         DepotDownloader {
             path: depot_downloader_exe,
+            beta_password: None,
         }
     }
 
-    /// DepotDownloaderの存在を確認する
+    /// DepotDownloaderの存在と、実行に必要な.NETランタイムの有無を確認する
+    ///
+    /// ランタイムが不足している場合は `RuntimeMissingError` を返す。
     pub fn check_exists(&self) -> Result<(), Box<dyn Error>> {
         if !self.path.exists() {
             return Err(format!(
@@ -62,9 +283,126 @@ impl DepotDownloader {
             .into());
         }
 
+        if self.requires_runtime() {
+            let status = self.check_runtime()?;
+            if !status.meets_requirement {
+                return Err(Box::new(RuntimeMissingError { status }));
+            }
+        }
+
         Ok(())
     }
 
+    /// DepotDownloaderが.NETランタイムを必要とするビルドか
+    ///
+    /// フレームワーク依存のビルドは実行ファイルと同じフォルダに `*.runtimeconfig.json` を持つ。
+    /// 自己完結型（単一ファイル）のビルドはランタイムが無くても実行できる。
+    pub fn requires_runtime(&self) -> bool {
+        self.path.with_extension("runtimeconfig.json").exists()
+    }
+
+    /// ポータブル版の.NETランタイムを展開するフォルダ
+    pub fn portable_runtime_dir(&self) -> PathBuf {
+        self.path
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join(PORTABLE_DOTNET_DIR)
+    }
+
+    /// ポータブル版の.NETランタイムの `dotnet` 実行ファイル（導入済みの場合のみ）
+    fn portable_dotnet(&self) -> Option<PathBuf> {
+        let exe_name = if cfg!(target_os = "windows") { "dotnet.exe" } else { "dotnet" };
+        Some(self.portable_runtime_dir().join(exe_name)).filter(|path| path.exists())
+    }
+
+    /// DepotDownloaderを実行するコマンド（ポータブル版のランタイムがあれば `DOTNET_ROOT` で指定する）
+    fn command(&self) -> Command {
+        let mut cmd = Command::new(&self.path);
+        if self.portable_dotnet().is_some() {
+            cmd.env("DOTNET_ROOT", self.portable_runtime_dir());
+        }
+        cmd
+    }
+
+    /// DepotDownloaderの実行に必要な.NETランタイムがインストールされているか検査する
+    ///
+    /// ポータブル版のランタイムが導入済みの場合はそちらを検査する。
+    /// `dotnet` コマンドが見つからない場合もエラーにはせず、`dotnet_found: false` を返す。
+    pub fn check_runtime(&self) -> Result<RuntimeStatus, Box<dyn Error>> {
+        let mut cmd = Command::new(self.portable_dotnet().unwrap_or_else(|| PathBuf::from("dotnet")));
+        cmd.arg("--list-runtimes");
+
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::process::CommandExt;
+            cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+        }
+
+        let output = match cmd.output() {
+            Ok(output) if output.status.success() => output,
+            Ok(_) | Err(_) => {
+                return Ok(RuntimeStatus {
+                    dotnet_found: false,
+                    runtime_versions: Vec::new(),
+                    meets_requirement: false,
+                    required_major: REQUIRED_DOTNET_MAJOR,
+                });
+            }
+        };
+
+        let runtime_versions = parse_dotnet_runtimes(&String::from_utf8_lossy(&output.stdout));
+        Ok(RuntimeStatus {
+            dotnet_found: true,
+            meets_requirement: has_required_runtime(&runtime_versions),
+            runtime_versions,
+            required_major: REQUIRED_DOTNET_MAJOR,
+        })
+    }
+
+    /// 公式配布の.NETランタイムをダウンロードし、ポータブル版としてDepotDownloaderと同じフォルダに展開する
+    ///
+    /// システムへのインストールは行わないため、管理者権限の無いPCでも使える。
+    pub async fn install_portable_runtime(&self) -> Result<PathBuf, Box<dyn Error + Send + Sync>> {
+        let url = dotnet_runtime_archive_url();
+        log_println!("Downloading .NET runtime from {}", url);
+
+        let response = reqwest::get(&url).await?;
+        if !response.status().is_success() {
+            return Err(format!("Failed to download .NET runtime: HTTP {}", response.status()).into());
+        }
+        let content = response.bytes().await?;
+
+        let runtime_dir = self.portable_runtime_dir();
+        if runtime_dir.exists() {
+            fs::remove_dir_all(&runtime_dir)?;
+        }
+        fs::create_dir_all(&runtime_dir)?;
+
+        if url.ends_with(".zip") {
+            zip::ZipArchive::new(std::io::Cursor::new(content))?.extract(&runtime_dir)?;
+        } else {
+            // tar.gzはtarコマンドで展開する（実行ビットも保持される）
+            let archive_path = runtime_dir.join("dotnet-runtime.tar.gz");
+            fs::write(&archive_path, &content)?;
+            let status = Command::new("tar")
+                .arg("-xzf")
+                .arg(&archive_path)
+                .arg("-C")
+                .arg(&runtime_dir)
+                .status()?;
+            let _ = fs::remove_file(&archive_path);
+            if !status.success() {
+                return Err("Failed to extract .NET runtime archive".into());
+            }
+        }
+
+        if self.portable_dotnet().is_none() {
+            return Err("dotnet executable not found in the downloaded runtime".into());
+        }
+        log_println!(".NET runtime installed to {}", runtime_dir.display());
+        Ok(runtime_dir)
+    }
+
     /// 認証引数を構築する
     pub fn build_auth_args(
         &self,
@@ -88,9 +426,9 @@ impl DepotDownloader {
 
     /// 対話型Steamログインを実行する
     pub fn interactive_login(&self, username: &str) -> Result<(), Box<dyn Error>> {
-        println!("Interactive Steam login for user: {}", username);
-        println!("This will save your credentials for future use.");
-        println!("You will be prompted for your password and Steam Guard code if needed.");
+        log_println!("Interactive Steam login for user: {}", username);
+        log_println!("This will save your credentials for future use.");
+        log_println!("You will be prompted for your password and Steam Guard code if needed.");
 
         // DepotDownloaderでは認証情報を保存するために-remember-passwordオプションを使用
         let args = vec![
@@ -103,7 +441,7 @@ impl DepotDownloader {
         ];
 
         // Run DepotDownloader with the login command in interactive mode
-        let mut cmd = Command::new(&self.path)
+        let mut cmd = self.command()
             .args(&args)
             .stdin(Stdio::inherit())
             .stdout(Stdio::inherit())
@@ -114,11 +452,11 @@ impl DepotDownloader {
         let status = cmd.wait()?;
 
         if status.success() {
-            println!("\nLogin successful! Your credentials have been saved by DepotDownloader.");
-            println!("You can now use other commands without specifying login details.");
+            log_println!("\nLogin successful! Your credentials have been saved by DepotDownloader.");
+            log_println!("You can now use other commands without specifying login details.");
             Ok(())
         } else {
-            println!("\nLogin failed. Please try again.");
+            log_println!("\nLogin failed. Please try again.");
             Err("Steam login failed".into())
         }
     }
@@ -143,10 +481,15 @@ impl DepotDownloader {
         args.push("-depot".to_string());
         args.push("2519832".to_string());
 
-        // ブランチを指定（prereleaseの場合）
-        if branch == "prerelease" {
+        // ブランチを指定（release以外の場合）
+        if branch != "release" {
             args.push("-branch".to_string());
-            args.push("prerelease".to_string());
+            args.push(branch.to_string());
+
+            if let Some(beta_password) = &self.beta_password {
+                args.push("-betapassword".to_string());
+                args.push(beta_password.clone());
+            }
         }
 
         // ManifestIDが指定されている場合は追加
@@ -171,15 +514,15 @@ impl DepotDownloader {
 
     /// 指定された引数でDepotDownloaderを実行する（バックグラウンド）
     pub fn run(&self, args: &[String]) -> Result<Output, Box<dyn Error>> {
-        println!("Using DepotDownloader path: {}", self.path.display());
-        println!("Running with args: {:?}", args);
+        log_println!("Using DepotDownloader path: {}", self.path.display());
+        log_println!("Running with args: {:?}", args);
 
         // リリースビルドではウィンドウを非表示にしてDepotDownloaderを実行
         #[cfg(all(target_os = "windows", not(debug_assertions)))]
         {
             use std::os::windows::process::CommandExt;
             
-            let output = Command::new(&self.path)
+            let output = self.command()
                 .args(args)
                 .creation_flags(0x08000000) // CREATE_NO_WINDOW
                 .output()?;
@@ -190,15 +533,121 @@ impl DepotDownloader {
         // デバッグビルドまたはWindows以外では通常通り実行
         #[cfg(any(not(target_os = "windows"), debug_assertions))]
         {
-            let output = Command::new(&self.path).args(args).output()?;
+            let output = self.command().args(args).output()?;
             Ok(output)
         }
     }
 
+    /// 指定された引数でDepotDownloaderを実行し、実行中のネットワーク切断を検知する（バックグラウンド）
+    ///
+    /// 切断を検知した場合はDepotDownloaderを停止して `NetworkDisconnectedError` を返す。
+    /// ダウンロード済みのファイルは残るため、再実行すると続きから再開される。
+    ///
+    /// Steam Guardの認証コードを要求された場合は `auth_code` を一度だけ入力する。
+    /// コードが無い、または再度要求された場合は停止して `SteamGuardRequiredError` を返す。
+    pub fn run_with_network_monitor(&self, args: &[String], auth_code: Option<&str>) -> Result<Output, Box<dyn Error>> {
+        log_println!("Using DepotDownloader path: {}", self.path.display());
+        log_println!("Running with args: {:?}", args);
+
+        let mut cmd = self.command();
+        cmd.args(args).stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped());
+
+        // リリースビルドではウィンドウを非表示にしてDepotDownloaderを実行
+        #[cfg(all(target_os = "windows", not(debug_assertions)))]
+        {
+            use std::os::windows::process::CommandExt;
+            cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+        }
+
+        let mut child = cmd.spawn()?;
+        let mut stdin = child.stdin.take();
+
+        // パイプが詰まらないよう出力は別スレッドで読み続け、認証コードの要求を数える
+        // （プロンプトは改行なしで出力されるため、行単位ではなく読み込んだ分ごとに確認する）
+        let prompts = Arc::new(AtomicUsize::new(0));
+        let read_pipe = |pipe: Option<Box<dyn Read + Send>>| {
+            let prompts = Arc::clone(&prompts);
+            std::thread::spawn(move || {
+                let mut buf = Vec::new();
+                if let Some(mut pipe) = pipe {
+                    let mut chunk = [0u8; 4096];
+                    let mut scanned = 0;
+                    while let Ok(n) = pipe.read(&mut chunk) {
+                        if n == 0 {
+                            break;
+                        }
+                        buf.extend_from_slice(&chunk[..n]);
+                        // 前回の読み込みとの境界をまたぐプロンプトも拾えるよう少し手前から確認する
+                        let start = scanned.max(buf.len().saturating_sub(n + 64));
+                        if contains_steam_guard_prompt(&String::from_utf8_lossy(&buf[start..])) {
+                            prompts.fetch_add(1, Ordering::SeqCst);
+                            scanned = buf.len();
+                        }
+                    }
+                }
+                buf
+            })
+        };
+        let stdout = read_pipe(child.stdout.take().map(|p| Box::new(p) as Box<dyn Read + Send>));
+        let stderr = read_pipe(child.stderr.take().map(|p| Box::new(p) as Box<dyn Read + Send>));
+
+        let mut last_check = Instant::now();
+        let mut network_failures = 0;
+        let mut codes_sent = 0;
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
+
+            if prompts.load(Ordering::SeqCst) > codes_sent {
+                let sent = match (auth_code, stdin.as_mut()) {
+                    (Some(code), Some(stdin)) if codes_sent == 0 => {
+                        log_println!("Steam Guard code requested, sending the provided code");
+                        writeln!(stdin, "{}", code.trim()).and_then(|_| stdin.flush()).is_ok()
+                    }
+                    _ => false,
+                };
+                if !sent {
+                    log_println!("Steam Guard code is required but not available");
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(SteamGuardRequiredError.into());
+                }
+                codes_sent += 1;
+            }
+
+            if last_check.elapsed() >= NETWORK_CHECK_INTERVAL {
+                last_check = Instant::now();
+                match utils::check_network() {
+                    NetworkStatus::Online => network_failures = 0,
+                    // 確認用のホストに繋がらないだけの可能性があるため、ダウンロードは続ける
+                    NetworkStatus::Unknown => log_println!("Could not confirm the network connection, continuing the download"),
+                    NetworkStatus::Offline => {
+                        network_failures += 1;
+                        log_println!("Network check failed during download ({}/{})", network_failures, NETWORK_FAILURE_THRESHOLD);
+                        if network_failures >= NETWORK_FAILURE_THRESHOLD {
+                            let _ = child.kill();
+                            let _ = child.wait();
+                            return Err(NetworkDisconnectedError.into());
+                        }
+                    }
+                }
+            }
+
+            std::thread::sleep(Duration::from_millis(500));
+        };
+
+        Ok(Output {
+            status,
+            stdout: stdout.join().unwrap_or_default(),
+            stderr: stderr.join().unwrap_or_default(),
+        })
+    }
+
     /// 指定された引数でDepotDownloaderを別のコマンドウィンドウで実行する（2FA対応）
     pub fn run_interactive(&self, args: &[String]) -> Result<(), Box<dyn Error>> {
-        println!("Using DepotDownloader path: {}", self.path.display());
-        println!("Running interactively with args: {:?}", args);
+        log_println!("Using DepotDownloader path: {}", self.path.display());
+        log_println!("Running interactively with args: {:?}", args);
         
         // DepotDownloaderの存在確認
         self.check_exists()?;
@@ -210,6 +659,9 @@ impl DepotDownloader {
             // 新しいアプローチ：PowerShellを使用してDepotDownloaderを実行
             let mut cmd = Command::new("powershell");
             cmd.args(&["-NoExit", "-Command"]);
+            if self.portable_dotnet().is_some() {
+                cmd.env("DOTNET_ROOT", self.portable_runtime_dir());
+            }
 
             // PowerShellスクリプトを構築
             let depot_path_str = self.path.to_string_lossy();
@@ -232,18 +684,18 @@ impl DepotDownloader {
             cmd.arg(&powershell_script);
             cmd.creation_flags(0x00000010); // CREATE_NEW_CONSOLE - 新しいコンソールウィンドウを作成
 
-            println!("Using DepotDownloader path: {}", depot_path_str);
-            println!("Running interactively with args: {:?}", args);
-            println!("Launching PowerShell in new console window...");
+            log_println!("Using DepotDownloader path: {}", depot_path_str);
+            log_println!("Running interactively with args: {:?}", args);
+            log_println!("Launching PowerShell in new console window...");
 
             let _child = cmd.spawn()?;
-            println!("DepotDownloader launched in new PowerShell console window");
+            log_println!("DepotDownloader launched in new PowerShell console window");
         }
 
         #[cfg(not(target_os = "windows"))]
         {
             // Windows以外ではターミナルで実行
-            let mut cmd = Command::new(&self.path)
+            let mut cmd = self.command()
                 .args(args)
                 .stdin(Stdio::inherit())
                 .stdout(Stdio::inherit())
@@ -268,17 +720,26 @@ impl DepotDownloader {
         manifest_id: Option<&str>,
         username: Option<&str>,
         password: Option<&str>,
+        auth_code: Option<&str>,
     ) -> Result<(), Box<dyn Error>> {
-        let args = self.build_resonite_args(install_dir, branch, manifest_id, username, password);
+        let mut args = self.build_resonite_args(install_dir, branch, manifest_id, username, password);
+        if auth_code.is_some() {
+            // 認証に成功したらログイン情報を保存し、次回以降はコードを不要にする
+            args.push("-remember-password".to_string());
+        }
         
-        let output = self.run(&args)?;
+        let output = self.run_with_network_monitor(&args, auth_code)?;
 
         if !output.status.success() {
+            // 監視の合間に切断された場合もエラー内容ではなく切断として扱う
+            if !utils::is_network_available() {
+                return Err(NetworkDisconnectedError.into());
+            }
             let stderr = String::from_utf8_lossy(&output.stderr);
             return Err(format!("DepotDownloader failed: {}", stderr).into());
         }
 
-        println!("Resonite download completed successfully");
+        log_println!("Resonite download completed successfully");
         Ok(())
     }
 
@@ -295,7 +756,77 @@ impl DepotDownloader {
         
         self.run_interactive(&args)?;
 
-        println!("Resonite download process launched in separate window");
+        log_println!("Resonite download process launched in separate window");
+        Ok(())
+    }
+
+    /// Resoniteのマニフェストのファイル一覧を取得する（ファイルはダウンロードしない）
+    pub fn fetch_manifest_files(
+        &self,
+        branch: &str,
+        manifest_id: Option<&str>,
+        username: Option<&str>,
+        password: Option<&str>,
+    ) -> Result<Vec<ManifestFileEntry>, Box<dyn Error>> {
+        let work_dir = std::env::temp_dir().join(format!("reso-launcher-manifest-{}", std::process::id()));
+        fs::create_dir_all(&work_dir)?;
+
+        let mut args = self.build_resonite_args(&work_dir.to_string_lossy(), branch, manifest_id, username, password);
+        args.push("-manifest-only".to_string());
+
+        let result = self.run(&args).and_then(|output| {
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(format!("DepotDownloader failed: {}", stderr).into());
+            }
+
+            // manifest_<depot>_<manifest>.txt が出力される
+            let listing = fs::read_dir(&work_dir)?
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .find(|path| {
+                    let name = path.file_name().unwrap_or_default().to_string_lossy();
+                    name.starts_with("manifest_") && name.ends_with(".txt")
+                })
+                .ok_or("Manifest listing was not written by DepotDownloader")?;
+
+            Ok(parse_manifest_listing(&fs::read_to_string(listing)?))
+        });
+
+        let _ = fs::remove_dir_all(&work_dir);
+        result
+    }
+
+    /// 指定したファイルだけを再ダウンロードする（バックグラウンド）
+    ///
+    /// `files` はGameフォルダからの相対パス。
+    pub fn download_resonite_files(
+        &self,
+        install_dir: &str,
+        branch: &str,
+        manifest_id: Option<&str>,
+        username: Option<&str>,
+        password: Option<&str>,
+        files: &[String],
+    ) -> Result<(), Box<dyn Error>> {
+        let file_list = std::env::temp_dir().join(format!("reso-launcher-filelist-{}.txt", std::process::id()));
+        fs::write(&file_list, files.join("\n"))?;
+
+        let mut args = self.build_resonite_args(install_dir, branch, manifest_id, username, password);
+        args.push("-filelist".to_string());
+        args.push(file_list.to_string_lossy().to_string());
+        args.push("-validate".to_string());
+
+        let result = self.run_with_network_monitor(&args, None);
+        let _ = fs::remove_file(&file_list);
+        let output = result?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("DepotDownloader failed: {}", stderr).into());
+        }
+
+        log_println!("Downloaded {} files successfully", files.len());
         Ok(())
     }
 
@@ -312,7 +843,7 @@ impl DepotDownloader {
         let install_path = Path::new(install_dir);
         let resonite_exe = install_path.join("Resonite.exe");
         
-        println!("Monitoring installation at: {}", resonite_exe.display());
+        log_println!("Monitoring installation at: {}", resonite_exe.display());
 
         // バックグラウンドスレッドで監視
         thread::spawn(move || {
@@ -344,7 +875,7 @@ impl DepotDownloader {
                     };
 
                     if final_size == current_size && final_size > 0 {
-                        println!("Installation completed! Resonite.exe detected at: {}", resonite_exe.display());
+                        log_println!("Installation completed! Resonite.exe detected at: {}", resonite_exe.display());
                         callback(true);
                         break;
                     }
@@ -469,6 +1000,28 @@ mod tests {
         assert!(args.contains(&"prerelease".to_string()));
     }
 
+    #[test]
+    fn test_build_resonite_args_beta_branch_with_password() {
+        let (_temp, downloader) = create_test_downloader();
+        let downloader = downloader.with_beta_password(Some("secret".to_string()));
+        let args = downloader.build_resonite_args(
+            "/install/path",
+            "headless",
+            None,
+            None,
+            None,
+        );
+
+        let branch_pos = args.iter().position(|arg| arg == "-branch").unwrap();
+        assert_eq!(args[branch_pos + 1], "headless");
+        let password_pos = args.iter().position(|arg| arg == "-betapassword").unwrap();
+        assert_eq!(args[password_pos + 1], "secret");
+
+        // releaseブランチにはパスワードを渡さない
+        let args = downloader.build_resonite_args("/install/path", "release", None, None, None);
+        assert!(!args.contains(&"-betapassword".to_string()));
+    }
+
     #[test]
     fn test_build_resonite_args_with_manifest() {
         let (_temp, downloader) = create_test_downloader();
@@ -501,6 +1054,47 @@ mod tests {
         assert!(args.contains(&"testpass".to_string()));
     }
 
+    #[test]
+    fn test_contains_steam_guard_prompt() {
+        assert!(contains_steam_guard_prompt("Please enter your 2 factor auth code from your authenticator app: "));
+        assert!(contains_steam_guard_prompt("STEAM GUARD! Please enter your 2-factor auth code from your authenticator app: "));
+        assert!(contains_steam_guard_prompt("Please enter the auth code sent to the email at ***@example.com: "));
+        assert!(!contains_steam_guard_prompt("Logging 'testuser' into Steam3..."));
+    }
+
+    #[test]
+    fn test_parse_dotnet_runtimes() {
+        let output = "Microsoft.AspNetCore.App 8.0.1 [C:\\Program Files\\dotnet\\shared\\Microsoft.AspNetCore.App]\n\
+                      Microsoft.NETCore.App 6.0.25 [C:\\Program Files\\dotnet\\shared\\Microsoft.NETCore.App]\n\
+                      Microsoft.NETCore.App 8.0.1 [C:\\Program Files\\dotnet\\shared\\Microsoft.NETCore.App]\n";
+
+        let versions = parse_dotnet_runtimes(output);
+        assert_eq!(versions, vec!["6.0.25", "8.0.1"]);
+        assert!(has_required_runtime(&versions));
+        assert!(!has_required_runtime(&["6.0.25".to_string()]));
+        assert!(!has_required_runtime(&[]));
+    }
+
+    #[test]
+    fn test_parse_manifest_listing() {
+        let content = "Content Manifest for Depot 2519832 \n\
+\n\
+Manifest ID / date     : 1234567890 / 01/01/2024 00:00:00 \n\
+Total number of files  : 2 \n\
+\n\
+          Size Chunks File SHA                                 Flags Name\n\
+             0      0 0000000000000000000000000000000000000000    64 Resonite_Data\n\
+        651264      1 A94A8FE5CCB19BA61C4C0873D391E987982FBBD3     0 Resonite.exe\n\
+          1024      1 da39a3ee5e6b4b0d3255bfef95601890afd80709     0 Resonite_Data\\Managed\\My File.dll\n";
+
+        let entries = parse_manifest_listing(content);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "Resonite.exe");
+        assert_eq!(entries[0].size, 651264);
+        assert_eq!(entries[0].sha1, "a94a8fe5ccb19ba61c4c0873d391e987982fbbd3");
+        assert_eq!(entries[1].name, "Resonite_Data/Managed/My File.dll");
+    }
+
     #[test]
     fn test_check_exists_fails_for_missing() {
         // 存在しないパスを明示的に指定
@@ -510,6 +1104,22 @@ mod tests {
         assert!(downloader.check_exists().is_err());
     }
 
+    #[test]
+    fn test_requires_runtime_only_for_framework_dependent_build() {
+        let temp_dir = TempDir::new().unwrap();
+        let exe_path = temp_dir.path().join(DepotDownloader::executable_name());
+        fs::write(&exe_path, b"").unwrap();
+        let downloader = DepotDownloader::new(&exe_path);
+
+        // 自己完結型のビルドはランタイムの確認を行わない
+        assert!(!downloader.requires_runtime());
+        assert!(downloader.check_exists().is_ok());
+
+        fs::write(temp_dir.path().join("DepotDownloader.runtimeconfig.json"), b"{}").unwrap();
+        assert!(downloader.requires_runtime());
+        assert_eq!(downloader.portable_runtime_dir(), temp_dir.path().join(PORTABLE_DOTNET_DIR));
+    }
+
     #[test]
     fn test_new_sets_correct_path() {
         let temp_dir = TempDir::new().unwrap();
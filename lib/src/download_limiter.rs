@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::{Notify, OwnedSemaphorePermit, Semaphore};
+
+/// レート制限時に待機する時間の上限
+pub const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(5 * 60);
+/// レート制限の解除時刻がわからない場合の待機時間
+const DEFAULT_RATE_LIMIT_WAIT: Duration = Duration::from_secs(60);
+
+/// ホストごとの同時接続状態
+#[derive(Debug)]
+struct HostState {
+    /// 現在の同時接続数の上限（レート制限を受けると下がる）
+    limit: usize,
+    active: usize,
+    blocked_until: Option<Instant>,
+}
+
+/// MODダウンロードの並列度を制御する
+///
+/// 全体の同時ダウンロード数とホスト（github.com等）ごとの同時接続数を制限し、
+/// レート制限を検知したホストは並列度を下げて解除まで待機させる。
+#[derive(Debug)]
+pub struct DownloadLimiter {
+    total: Arc<Semaphore>,
+    max_per_host: usize,
+    hosts: Mutex<HashMap<String, HostState>>,
+    notify: Notify,
+}
+
+/// ダウンロード1件分の実行許可（dropで解放される）
+pub struct DownloadPermit<'a> {
+    limiter: &'a DownloadLimiter,
+    host: String,
+    _total: OwnedSemaphorePermit,
+}
+
+impl Drop for DownloadPermit<'_> {
+    fn drop(&mut self) {
+        if let Some(state) = self.limiter.hosts.lock().unwrap().get_mut(&self.host) {
+            state.active = state.active.saturating_sub(1);
+        }
+        self.limiter.notify.notify_waiters();
+    }
+}
+
+impl DownloadLimiter {
+    /// 全体の並列度とホストごとの同時接続数を指定して作成
+    pub fn new(max_parallel: usize, max_per_host: usize) -> Self {
+        DownloadLimiter {
+            total: Arc::new(Semaphore::new(max_parallel.max(1))),
+            max_per_host: max_per_host.max(1),
+            hosts: Mutex::new(HashMap::new()),
+            notify: Notify::new(),
+        }
+    }
+
+    /// URLからホスト名を取り出す
+    pub fn host_of(url: &str) -> String {
+        url.split("://")
+            .nth(1)
+            .unwrap_or(url)
+            .split(['/', '?', '#'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase()
+    }
+
+    /// ダウンロードの実行許可を取得する（空きが出るかレート制限が解除されるまで待機）
+    pub async fn acquire(&self, url: &str) -> DownloadPermit<'_> {
+        let host = Self::host_of(url);
+
+        // 先にホストの枠を確保し、制限中のホストが全体の枠を塞がないようにする
+        loop {
+            // notify_waitersを取りこぼさないよう、状態確認の前に待機を登録する
+            let notified = self.notify.notified();
+
+            let wait = {
+                let mut hosts = self.hosts.lock().unwrap();
+                let state = hosts.entry(host.clone()).or_insert_with(|| HostState {
+                    limit: self.max_per_host,
+                    active: 0,
+                    blocked_until: None,
+                });
+
+                match state.blocked_until {
+                    Some(until) if until > Instant::now() => Some(until - Instant::now()),
+                    _ if state.active < state.limit => {
+                        state.active += 1;
+                        break;
+                    }
+                    _ => None,
+                }
+            };
+
+            match wait {
+                Some(duration) => tokio::time::sleep(duration).await,
+                None => notified.await,
+            }
+        }
+
+        let total = self.total.clone().acquire_owned().await.expect("download semaphore closed");
+        DownloadPermit {
+            limiter: self,
+            host,
+            _total: total,
+        }
+    }
+
+    /// ダウンロード成功を記録し、下げた並列度を徐々に戻す
+    pub fn report_success(&self, url: &str) {
+        let host = Self::host_of(url);
+        let mut hosts = self.hosts.lock().unwrap();
+        if let Some(state) = hosts.get_mut(&host) {
+            if state.limit < self.max_per_host {
+                state.limit += 1;
+                drop(hosts);
+                self.notify.notify_waiters();
+            }
+        }
+    }
+
+    /// レート制限を記録し、ホストの並列度を半分に下げて指定時間ブロックする
+    pub fn report_rate_limited(&self, url: &str, wait: Duration) {
+        let host = Self::host_of(url);
+        let mut hosts = self.hosts.lock().unwrap();
+        let state = hosts.entry(host.clone()).or_insert_with(|| HostState {
+            limit: self.max_per_host,
+            active: 0,
+            blocked_until: None,
+        });
+
+        state.limit = (state.limit / 2).max(1);
+        state.blocked_until = Some(Instant::now() + wait.min(MAX_RATE_LIMIT_WAIT));
+        eprintln!(
+            "Rate limited by {}, waiting {}s (max connections: {})",
+            host,
+            wait.min(MAX_RATE_LIMIT_WAIT).as_secs(),
+            state.limit
+        );
+    }
+
+    /// ホストの現在の同時接続数の上限
+    pub fn host_limit(&self, url: &str) -> usize {
+        self.hosts
+            .lock()
+            .unwrap()
+            .get(&Self::host_of(url))
+            .map(|state| state.limit)
+            .unwrap_or(self.max_per_host)
+    }
+}
+
+/// レート制限レスポンスなら待機すべき時間を返す（403/429 + `Retry-After` / `X-RateLimit-*` ヘッダ）
+pub fn rate_limit_wait(status: u16, headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    if status != 403 && status != 429 {
+        return None;
+    }
+
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim().to_string())
+    };
+
+    if let Some(seconds) = header("retry-after").and_then(|v| v.parse::<u64>().ok()) {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let remaining = header("x-ratelimit-remaining").and_then(|v| v.parse::<u64>().ok());
+    if remaining == Some(0) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        return Some(
+            header("x-ratelimit-reset")
+                .and_then(|v| v.parse::<u64>().ok())
+                .map(|reset| Duration::from_secs(reset.saturating_sub(now).max(1)))
+                .unwrap_or(DEFAULT_RATE_LIMIT_WAIT),
+        );
+    }
+
+    // 429はヘッダが無くてもレート制限とみなす
+    if status == 429 {
+        return Some(DEFAULT_RATE_LIMIT_WAIT);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    #[test]
+    fn test_host_of() {
+        assert_eq!(
+            DownloadLimiter::host_of("https://GitHub.com/owner/repo/releases/download/v1/Mod.dll"),
+            "github.com"
+        );
+        assert_eq!(DownloadLimiter::host_of("https://example.com?x=1"), "example.com");
+    }
+
+    #[test]
+    fn test_rate_limit_wait_detection() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(rate_limit_wait(403, &headers), None);
+        assert_eq!(rate_limit_wait(429, &headers), Some(DEFAULT_RATE_LIMIT_WAIT));
+        assert_eq!(rate_limit_wait(404, &headers), None);
+
+        headers.insert("retry-after", HeaderValue::from_static("30"));
+        assert_eq!(rate_limit_wait(403, &headers), Some(Duration::from_secs(30)));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        assert_eq!(rate_limit_wait(403, &headers), Some(DEFAULT_RATE_LIMIT_WAIT));
+        assert_eq!(rate_limit_wait(200, &headers), None);
+    }
+
+    #[test]
+    fn test_rate_limited_host_reduces_limit_and_recovers() {
+        let limiter = DownloadLimiter::new(8, 4);
+        let url = "https://github.com/owner/repo";
+
+        limiter.report_rate_limited(url, Duration::from_secs(1));
+        assert_eq!(limiter.host_limit(url), 2);
+        limiter.report_rate_limited(url, Duration::from_secs(1));
+        limiter.report_rate_limited(url, Duration::from_secs(1));
+        assert_eq!(limiter.host_limit(url), 1);
+
+        // 他のホストには影響しない
+        assert_eq!(limiter.host_limit("https://example.com/file"), 4);
+
+        for _ in 0..10 {
+            limiter.report_success(url);
+        }
+        assert_eq!(limiter.host_limit(url), 4);
+    }
+}
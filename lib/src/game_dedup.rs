@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// ハードリンクで共有しているファイルの記録（Gameフォルダ内）
+pub(crate) const LINK_RECORD_FILE: &str = ".dedup_links.json";
+
+/// MODローダーやログなど、プロファイルごとに内容が変わるため共有しないエントリ（Game直下）
+const EXCLUDED_ENTRIES: &[&str] = &[
+    LINK_RECORD_FILE,
+    "Logs",
+    "Libraries",
+    "rml_mods",
+    "rml_libs",
+    "rml_config",
+    "MonkeyLoader",
+    "BepInEx",
+    "winhttp.dll",
+    "doorstop_config.ini",
+    "run_monkeyloader.sh",
+    "hostfxr.dll",
+    "hookfxr.ini",
+    "BepisLoader.dll",
+    "BepisLoader.deps.json",
+    "BepisLoader.runtimeconfig.json",
+];
+
+/// Gameフォルダの重複排除の結果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DedupReport {
+    /// ファイルを共有したプロファイル
+    pub linked_profiles: Vec<String>,
+    /// 新たにハードリンクにしたファイル数
+    pub linked_files: usize,
+    /// 節約できた容量（バイト）
+    pub saved_bytes: u64,
+    /// 重複排除できなかったプロファイルと理由
+    pub skipped: Vec<String>,
+}
+
+/// `target` のGameフォルダ内で `source` と同一内容のファイルをハードリンクに置き換える
+///
+/// 戻り値は（リンクしたファイル数, 節約した容量）。
+pub fn link_identical_files(source: &Path, target: &Path) -> Result<(usize, u64), Box<dyn Error>> {
+    let mut source_links = load_link_record(source);
+    let mut target_links = load_link_record(target);
+
+    // 途中で失敗しても、それまでに作ったリンクは記録しておく（記録がないと更新前に解除できない）
+    let result = link_files(source, target, &mut source_links, &mut target_links);
+    save_link_record(source, &source_links)?;
+    save_link_record(target, &target_links)?;
+    result
+}
+
+fn link_files(
+    source: &Path,
+    target: &Path,
+    source_links: &mut BTreeSet<String>,
+    target_links: &mut BTreeSet<String>,
+) -> Result<(usize, u64), Box<dyn Error>> {
+    let mut linked_files = 0;
+    let mut saved_bytes = 0;
+
+    for relative in collect_files(target)? {
+        let key = relative.to_string_lossy().replace('\\', "/");
+        if target_links.contains(&key) {
+            continue;
+        }
+
+        let source_file = source.join(&relative);
+        let target_file = target.join(&relative);
+        let size = match (fs::metadata(&source_file), fs::metadata(&target_file)) {
+            (Ok(a), Ok(b)) if a.is_file() && a.len() == b.len() => b.len(),
+            _ => continue,
+        };
+
+        if file_sha256(&source_file)? != file_sha256(&target_file)? {
+            continue;
+        }
+
+        // 一時ファイル名でリンクを作ってから置き換え、失敗しても元のファイルを残す
+        let temp_link = with_suffix(&target_file, ".dedup");
+        let _ = fs::remove_file(&temp_link);
+        fs::hard_link(&source_file, &temp_link)?;
+        fs::rename(&temp_link, &target_file)?;
+
+        source_links.insert(key.clone());
+        target_links.insert(key);
+        linked_files += 1;
+        saved_bytes += size;
+    }
+
+    Ok((linked_files, saved_bytes))
+}
+
+/// ファイルを書き込む。既存のファイルがハードリンクで共有されていても、他のプロファイルには影響しない
+///
+/// 既存のファイルを先に削除してから新しいファイルとして書き込む。
+pub fn write_unshared(path: &Path, contents: impl AsRef<[u8]>) -> std::io::Result<()> {
+    match fs::remove_file(path) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    fs::write(path, contents)
+}
+
+/// ハードリンクで共有しているファイルを個別のコピーに戻す（ゲームの更新前に呼ぶ）
+pub fn unshare_game_files(game_dir: &Path) -> Result<usize, Box<dyn Error>> {
+    let links = load_link_record(game_dir);
+    let mut unshared = 0;
+
+    for key in &links {
+        let file = game_dir.join(key);
+        if !file.is_file() {
+            continue;
+        }
+
+        let temp_copy = with_suffix(&file, ".unlink");
+        fs::copy(&file, &temp_copy)?;
+        fs::rename(&temp_copy, &file)?;
+        unshared += 1;
+    }
+
+    let record = game_dir.join(LINK_RECORD_FILE);
+    if record.exists() {
+        fs::remove_file(record)?;
+    }
+
+    if unshared > 0 {
+        log_println!("Unshared {} deduplicated files in {}", unshared, game_dir.display());
+    }
+    Ok(unshared)
+}
+
+fn collect_files(game_dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut files = Vec::new();
+    let mut pending = vec![PathBuf::new()];
+
+    while let Some(relative_dir) = pending.pop() {
+        for entry in fs::read_dir(game_dir.join(&relative_dir))? {
+            let entry = entry?;
+            let name = entry.file_name();
+            if relative_dir.as_os_str().is_empty() && EXCLUDED_ENTRIES.iter().any(|e| name.to_string_lossy().eq_ignore_ascii_case(e)) {
+                continue;
+            }
+
+            let relative = relative_dir.join(&name);
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                pending.push(relative);
+            } else if file_type.is_file() {
+                files.push(relative);
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+fn file_sha256(path: &Path) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(hasher.finalize().to_vec())
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+fn load_link_record(game_dir: &Path) -> BTreeSet<String> {
+    fs::read_to_string(game_dir.join(LINK_RECORD_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_link_record(game_dir: &Path, links: &BTreeSet<String>) -> Result<(), Box<dyn Error>> {
+    if links.is_empty() {
+        return Ok(());
+    }
+    fs::write(game_dir.join(LINK_RECORD_FILE), serde_json::to_string_pretty(links)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_link_and_unshare_identical_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("a").join("Game");
+        let target = temp_dir.path().join("b").join("Game");
+        for dir in [&source, &target] {
+            fs::create_dir_all(dir.join("Resonite_Data")).unwrap();
+            fs::create_dir_all(dir.join("rml_mods")).unwrap();
+            fs::write(dir.join("Resonite_Data").join("data.bin"), b"same content").unwrap();
+            fs::write(dir.join("rml_mods").join("Mod.dll"), b"mod").unwrap();
+        }
+        fs::write(source.join("Build.version"), b"2024.1.1").unwrap();
+        fs::write(target.join("Build.version"), b"2024.1.2").unwrap();
+
+        let (linked, saved) = link_identical_files(&source, &target).unwrap();
+        assert_eq!(linked, 1);
+        assert_eq!(saved, "same content".len() as u64);
+
+        // 2回目は既にリンク済みなので何もしない
+        assert_eq!(link_identical_files(&source, &target).unwrap(), (0, 0));
+
+        // リンク解除後は片方を変更してももう片方に影響しない
+        assert_eq!(unshare_game_files(&target).unwrap(), 1);
+        fs::write(target.join("Resonite_Data").join("data.bin"), b"updated").unwrap();
+        assert_eq!(fs::read(source.join("Resonite_Data").join("data.bin")).unwrap(), b"same content");
+        assert!(!target.join(LINK_RECORD_FILE).exists());
+    }
+
+    #[test]
+    fn test_write_unshared_keeps_linked_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("a").join("Game");
+        let target = temp_dir.path().join("b").join("Game");
+        for dir in [&source, &target] {
+            fs::create_dir_all(dir.join("Renderer")).unwrap();
+            fs::write(dir.join("Renderer").join("Renderite.dll"), b"renderer").unwrap();
+        }
+        assert_eq!(link_identical_files(&source, &target).unwrap().0, 1);
+
+        // MODローダーなどがリンク済みのファイルを上書きしても、もう片方は元のまま
+        write_unshared(&target.join("Renderer").join("Renderite.dll"), b"patched").unwrap();
+        assert_eq!(fs::read(source.join("Renderer").join("Renderite.dll")).unwrap(), b"renderer");
+        assert_eq!(fs::read(target.join("Renderer").join("Renderite.dll")).unwrap(), b"patched");
+    }
+}
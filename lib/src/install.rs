@@ -1,10 +1,111 @@
 use std::path::{Path, PathBuf};
 use std::error::Error;
+use std::fmt;
 use std::fs;
+use std::process::{Child, Command, ExitStatus};
+use std::time::{Duration, SystemTime};
 use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::depotdownloader::{DepotDownloader, NetworkDisconnectedError, SteamGuardRequiredError};
+use crate::profile::{GameInfo, Profile, ProfileManager};
+use crate::launch_stats::{collect_enabled_mods, wait_for_startup_complete, LaunchRecord, LaunchStats, LaunchStatsManager};
+use crate::game_dedup;
+use crate::launch_args::LaunchOverrides;
+use crate::launch_lock;
+use crate::utils;
+use crate::version_history::{self, VersionHistoryManager, VersionRecord};
+use crate::webhook::{self, LaunchWebhookEvent, WebhookPayload};
+
+/// ResoniteのSteamデポID
+const RESONITE_DEPOT_ID: &str = "2519832";
+/// ネットワーク切断時に復帰を待つ時間の上限
+const NETWORK_RESUME_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+/// ネットワーク復帰後に自動再開する回数の上限
+const MAX_NETWORK_RESUMES: u32 = 5;
+
+/// インストール先に既にResoniteがあるとみなすファイル（Gameフォルダ直下）
+const RESONITE_INSTALL_MARKERS: &[&str] = &["Resonite.exe", "Build.version", ".DepotDownloader"];
+/// 衝突の警告に列挙するファイル数の上限
+const MAX_REPORTED_FOREIGN_ENTRIES: usize = 10;
+
+/// インストール先の状態のうち、そのまま上書きすると安全でないもの
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum InstallConflict {
+    /// Resonite以外のファイルがある（Gameフォルダ直下のエントリ名）
+    ForeignFiles { entries: Vec<String> },
+    /// 別ブランチのResoniteが既にインストールされている
+    DifferentBranch { installed_branch: String },
+}
 
-use crate::depotdownloader::DepotDownloader;
-use crate::profile::{GameInfo, ProfileManager};
+impl fmt::Display for InstallConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InstallConflict::ForeignFiles { entries } => {
+                write!(f, "インストール先にResonite以外のファイルがあります: {}", entries.join(", "))
+            }
+            InstallConflict::DifferentBranch { installed_branch } => {
+                write!(f, "インストール先に {} ブランチのResoniteが既にあります", installed_branch)
+            }
+        }
+    }
+}
+
+/// インストール先の衝突により、確認なしではインストールできない
+#[derive(Debug)]
+pub struct InstallConflictError {
+    pub conflicts: Vec<InstallConflict>,
+}
+
+impl fmt::Display for InstallConflictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<String> = self.conflicts.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}。上書きする場合は確認の上で再実行してください", messages.join("。"))
+    }
+}
+
+impl Error for InstallConflictError {}
+
+/// 自動フォールバック付きインストールの状態（`install_with_fallback` のコールバックに渡す）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InstallStatus {
+    /// 実行中（インタラクティブモードへのフォールバック中を含む）
+    InProgress,
+    /// 完了した
+    Succeeded,
+    /// 失敗した
+    Failed,
+}
+
+impl InstallStatus {
+    /// 完了・失敗のどちらかで終わったか
+    pub fn is_complete(self) -> bool {
+        self != InstallStatus::InProgress
+    }
+}
+
+/// インストールに必要な容量とインストール先ドライブの空き容量
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstallSizeEstimate {
+    /// マニフェストのファイルサイズの合計
+    pub required_bytes: u64,
+    /// インストール先ドライブの空き容量（取得できない場合はNone）
+    pub available_bytes: Option<u64>,
+    /// 空き容量が足りているか（取得できない場合は足りているとみなす）
+    pub sufficient: bool,
+}
+
+impl InstallSizeEstimate {
+    /// 一時フォルダにすべてダウンロードしてから入れ替えるため、既存のインストールがあってもマニフェストの全サイズが必要
+    pub fn new(required_bytes: u64, available_bytes: Option<u64>) -> Self {
+        InstallSizeEstimate {
+            required_bytes,
+            available_bytes,
+            sufficient: available_bytes.is_none_or(|available| available >= required_bytes),
+        }
+    }
+}
 
 /// Resoniteのプロファイルベースインストール情報を保持する構造体
 pub struct ResoniteInstall {
@@ -13,6 +114,14 @@ pub struct ResoniteInstall {
     pub manifest_id: Option<String>,
     pub username: Option<String>,
     pub password: Option<String>,
+    /// ネットワーク切断時に復帰を待って自動で再開する
+    pub wait_for_network: bool,
+    /// インストール先に衝突があっても上書きする（ユーザーが確認済み）
+    pub overwrite: bool,
+    /// Steam Guardの認証コード（要求された場合にDepotDownloaderへ入力する）
+    pub auth_code: Option<String>,
+    /// パスワード付きのbetaブランチのパスワード
+    pub beta_password: Option<String>,
 }
 
 impl ResoniteInstall {
@@ -30,7 +139,215 @@ impl ResoniteInstall {
             manifest_id,
             username,
             password,
+            wait_for_network: false,
+            overwrite: false,
+            auth_code: None,
+            beta_password: None,
+        }
+    }
+
+    /// ネットワーク切断時に復帰を待って自動で再開するかを設定
+    pub fn with_wait_for_network(mut self, wait_for_network: bool) -> Self {
+        self.wait_for_network = wait_for_network;
+        self
+    }
+
+    /// インストール先に衝突があっても上書きするかを設定
+    pub fn with_overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// Steam Guardの認証コードを設定（空文字は未入力として扱う）
+    pub fn with_auth_code(mut self, auth_code: Option<String>) -> Self {
+        self.auth_code = auth_code.filter(|code| !code.trim().is_empty());
+        self
+    }
+
+    /// betaブランチのパスワードを設定（空文字は未入力として扱う）
+    pub fn with_beta_password(mut self, beta_password: Option<String>) -> Self {
+        self.beta_password = beta_password.filter(|password| !password.is_empty());
+        self
+    }
+
+    /// ブランチ名を検証し、betaブランチのパスワードを渡すDepotDownloaderを返す
+    fn branch_downloader(&self, depot_downloader: &DepotDownloader) -> Result<DepotDownloader, Box<dyn Error>> {
+        utils::validate_branch(&self.branch)?;
+        Ok(depot_downloader.clone().with_beta_password(self.beta_password.clone()))
+    }
+
+    /// インストール先（プロファイルのGameフォルダ）を検査し、上書きが安全でない状態を返す
+    pub fn inspect_install_target(&self, profile_manager: &ProfileManager) -> Result<Vec<InstallConflict>, Box<dyn Error>> {
+        let profile = profile_manager.get_profile(&self.profile_name)?;
+        let game_dir = profile_manager.get_profile_dir(&self.profile_name).join("Game");
+        Ok(inspect_game_dir(&game_dir, profile.game_info.as_ref(), &self.branch))
+    }
+
+    /// 衝突がある場合は、上書きが許可されていなければエラーにする
+    fn ensure_install_target_safe(&self, profile_manager: &ProfileManager) -> Result<(), Box<dyn Error>> {
+        if self.overwrite {
+            return Ok(());
+        }
+        let conflicts = self.inspect_install_target(profile_manager)?;
+        if conflicts.is_empty() {
+            return Ok(());
         }
+        Err(InstallConflictError { conflicts }.into())
+    }
+
+    /// DepotDownloaderでダウンロードする（切断時は設定に応じてネットワーク復帰後に再開）
+    fn download_with_resume(&self, depot_downloader: &DepotDownloader, game_dir: &Path) -> Result<(), Box<dyn Error>> {
+        let depot_downloader = self.branch_downloader(depot_downloader)?;
+        let mut resumes = 0;
+        loop {
+            let result = depot_downloader.download_resonite(
+                &game_dir.to_string_lossy(),
+                &self.branch,
+                self.manifest_id.as_deref(),
+                self.username.as_deref(),
+                self.password.as_deref(),
+                self.auth_code.as_deref(),
+            );
+
+            match result {
+                Err(e) if e.is::<NetworkDisconnectedError>() && self.wait_for_network && resumes < MAX_NETWORK_RESUMES => {
+                    log_println!("{} Waiting for the network to come back...", e);
+                    if !utils::wait_for_network(NETWORK_RESUME_TIMEOUT) {
+                        return Err(e);
+                    }
+                    resumes += 1;
+                    log_println!("Network is back, resuming download ({}/{})", resumes, MAX_NETWORK_RESUMES);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// 一時フォルダにダウンロードしてから、完了後にGameフォルダと入れ替える
+    ///
+    /// ダウンロード中・失敗時も元のGameフォルダには手を加えないため、前のバージョンのまま起動できる。
+    fn download_staged(&self, depot_downloader: &DepotDownloader, game_dir: &Path) -> Result<(), Box<dyn Error>> {
+        let staging_dir = prepare_staging_dir(game_dir)?;
+
+        if let Err(e) = self.download_with_resume(depot_downloader, &staging_dir) {
+            let _ = fs::remove_dir_all(&staging_dir);
+            return Err(e);
+        }
+
+        if let Err(e) = swap_in_staging_dir(game_dir, &staging_dir) {
+            let _ = fs::remove_dir_all(&staging_dir);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// インストール済みのゲームファイルをマニフェストと照合する
+    ///
+    /// 欠損・サイズ不一致・ハッシュ不一致のファイルをGameフォルダからの相対パスで返す。
+    pub fn verify(&self, depot_downloader: &DepotDownloader, profile_manager: &ProfileManager) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+        let profile = profile_manager.get_profile(&self.profile_name)?;
+        let game_dir = profile_manager.get_profile_dir(&self.profile_name).join("Game");
+        if !game_dir.exists() {
+            return Err(format!("Game is not installed in profile '{}'", self.profile_name).into());
+        }
+
+        // 指定がなければインストール済みのマニフェストと照合する
+        let manifest_id = self
+            .manifest_id
+            .clone()
+            .or_else(|| profile.game_info.as_ref().and_then(|info| info.manifest_id.clone()));
+        let manifest_files = self.branch_downloader(depot_downloader)?.fetch_manifest_files(
+            &self.branch,
+            manifest_id.as_deref(),
+            self.username.as_deref(),
+            self.password.as_deref(),
+        )?;
+
+        let mut corrupted_files = Vec::new();
+        for entry in &manifest_files {
+            let path = game_dir.join(&entry.name);
+            let intact = match fs::metadata(&path) {
+                Ok(metadata) if metadata.is_file() && metadata.len() == entry.size => file_sha1(&path)? == entry.sha1,
+                _ => false,
+            };
+            if !intact {
+                corrupted_files.push(PathBuf::from(&entry.name));
+            }
+        }
+
+        log_println!(
+            "Verified {} files in profile '{}': {} corrupted or missing",
+            manifest_files.len(),
+            self.profile_name,
+            corrupted_files.len()
+        );
+        Ok(corrupted_files)
+    }
+
+    /// `verify` で見つかったファイルだけを再ダウンロードして修復する
+    pub fn repair(
+        &self,
+        depot_downloader: &DepotDownloader,
+        profile_manager: &ProfileManager,
+        corrupted_files: Vec<PathBuf>,
+    ) -> Result<(), Box<dyn Error>> {
+        if corrupted_files.is_empty() {
+            log_println!("Nothing to repair in profile '{}'", self.profile_name);
+            return Ok(());
+        }
+
+        let profile = profile_manager.get_profile(&self.profile_name)?;
+        let game_dir = profile_manager.get_profile_dir(&self.profile_name).join("Game");
+
+        // 他のプロファイルと共有しているファイルを書き換えないようリンクを解除
+        game_dedup::unshare_game_files(&game_dir)?;
+
+        let manifest_id = self
+            .manifest_id
+            .clone()
+            .or_else(|| profile.game_info.as_ref().and_then(|info| info.manifest_id.clone()));
+        let files: Vec<String> = corrupted_files
+            .iter()
+            .map(|path| path.to_string_lossy().replace('\\', "/"))
+            .collect();
+
+        log_println!("Repairing {} files in profile '{}'", files.len(), self.profile_name);
+        self.branch_downloader(depot_downloader)?.download_resonite_files(
+            &game_dir.to_string_lossy(),
+            &self.branch,
+            manifest_id.as_deref(),
+            self.username.as_deref(),
+            self.password.as_deref(),
+            &files,
+        )?;
+
+        log_println!("Repair successful!");
+        Ok(())
+    }
+
+    /// DepotDownloaderのマニフェスト情報からダウンロードサイズ（バイト）を見積もる
+    pub fn estimate_size(&self, depot_downloader: &DepotDownloader) -> Result<u64, Box<dyn Error>> {
+        let manifest_files = self.branch_downloader(depot_downloader)?.fetch_manifest_files(
+            &self.branch,
+            self.manifest_id.as_deref(),
+            self.username.as_deref(),
+            self.password.as_deref(),
+        )?;
+        Ok(manifest_files.iter().map(|entry| entry.size).sum())
+    }
+
+    /// 必要なサイズを見積もり、プロファイルのGameフォルダのドライブの空き容量と比較する
+    pub fn estimate_install_space(&self, depot_downloader: &DepotDownloader, profile_manager: &ProfileManager) -> Result<InstallSizeEstimate, Box<dyn Error>> {
+        let required_bytes = self.estimate_size(depot_downloader)?;
+        let game_dir = profile_manager.get_profile_dir(&self.profile_name).join("Game");
+        let available_bytes = match utils::available_disk_space(&game_dir) {
+            Ok(bytes) => Some(bytes),
+            Err(e) => {
+                eprintln!("Failed to get free space of {}: {}", game_dir.display(), e);
+                None
+            }
+        };
+        Ok(InstallSizeEstimate::new(required_bytes, available_bytes))
     }
 
     /// 実行可能ファイルの存在確認とパスの取得
@@ -51,51 +368,38 @@ impl ResoniteInstall {
 
     /// プロファイルにResoniteをインストールする（バックグラウンド）
     pub fn install(&self, depot_downloader: &DepotDownloader, profile_manager: &ProfileManager) -> Result<(), Box<dyn Error>> {
-        println!(
+        log_println!(
             "Installing Resonite {} branch to profile '{}'",
             self.branch, self.profile_name
         );
 
         // プロファイルの存在確認
-        let mut profile = profile_manager.get_profile(&self.profile_name)?;
+        let _profile = profile_manager.get_profile(&self.profile_name)?;
         let profile_dir = profile_manager.get_profile_dir(&self.profile_name);
         let game_dir = profile_dir.join("Game");
 
+        // 別のものが入っているフォルダに混在させない
+        self.ensure_install_target_safe(profile_manager)?;
+
         // ゲームディレクトリを作成
         if !game_dir.exists() {
             fs::create_dir_all(&game_dir)?;
         }
 
-        // DepotDownloaderでResoniteをダウンロード
-        depot_downloader.download_resonite(
-            &game_dir.to_string_lossy(),
-            &self.branch,
-            self.manifest_id.as_deref(),
-            self.username.as_deref(),
-            self.password.as_deref(),
-        )?;
+        // DepotDownloaderで一時フォルダにダウンロードしてから入れ替える
+        // （ゲーム本体は新しくダウンロードされるため、他のプロファイルと共有しているファイルのリンクも解除される）
+        self.download_staged(depot_downloader, &game_dir)?;
 
-        // プロファイルのゲーム情報を更新
-        let version = profile.get_game_version(&profile_dir);
-        let game_info = GameInfo {
-            branch: self.branch.clone(),
-            manifest_id: self.manifest_id.clone(),
-            depot_id: "2519832".to_string(),
-            installed: true,
-            last_updated: Some(Utc::now().to_rfc3339()),
-            version,
-        };
-        
-        profile.update_game_info(game_info);
-        profile_manager.update_profile(&profile)?;
+        // プロファイルのゲーム情報を更新し、バージョン履歴に記録
+        record_installed_game(profile_manager, &self.profile_name, &self.branch, self.manifest_id.clone())?;
 
-        println!("Installation successful!");
+        log_println!("Installation successful!");
         Ok(())
     }
 
     /// プロファイルにResoniteをインストールする（インタラクティブ、2FA対応）
     pub fn install_interactive(&self, depot_downloader: &DepotDownloader, profile_manager: &ProfileManager) -> Result<(), Box<dyn Error>> {
-        println!(
+        log_println!(
             "Installing Resonite {} branch to profile '{}' (Interactive Mode)",
             self.branch, self.profile_name
         );
@@ -105,13 +409,19 @@ impl ResoniteInstall {
         let profile_dir = profile_manager.get_profile_dir(&self.profile_name);
         let game_dir = profile_dir.join("Game");
 
+        // 別のものが入っているフォルダに混在させない
+        self.ensure_install_target_safe(profile_manager)?;
+
         // ゲームディレクトリを作成
         if !game_dir.exists() {
             fs::create_dir_all(&game_dir)?;
         }
 
+        // 他のプロファイルと共有しているファイルは個別に更新できるようリンクを解除
+        game_dedup::unshare_game_files(&game_dir)?;
+
         // DepotDownloaderでResoniteをダウンロード（インタラクティブ）
-        depot_downloader.download_resonite_interactive(
+        self.branch_downloader(depot_downloader)?.download_resonite_interactive(
             &game_dir.to_string_lossy(),
             &self.branch,
             self.manifest_id.as_deref(),
@@ -119,8 +429,8 @@ impl ResoniteInstall {
             self.password.as_deref(),
         )?;
 
-        println!("Installation process launched in separate window!");
-        println!("Please check the command prompt window for Steam 2FA prompts.");
+        log_println!("Installation process launched in separate window!");
+        log_println!("Please check the command prompt window for Steam 2FA prompts.");
         Ok(())
     }
 
@@ -129,7 +439,7 @@ impl ResoniteInstall {
     where 
         F: Fn(bool) + Send + 'static,
     {
-        println!(
+        log_println!(
             "Installing Resonite {} branch to profile '{}' (Interactive Mode with Monitoring)",
             self.branch, self.profile_name
         );
@@ -139,11 +449,17 @@ impl ResoniteInstall {
         let profile_dir = profile_manager.get_profile_dir(&self.profile_name);
         let game_dir = profile_dir.join("Game");
 
+        // 別のものが入っているフォルダに混在させない
+        self.ensure_install_target_safe(profile_manager)?;
+
         // ゲームディレクトリを作成
         if !game_dir.exists() {
             fs::create_dir_all(&game_dir)?;
         }
 
+        // 他のプロファイルと共有しているファイルは個別に更新できるようリンクを解除
+        game_dedup::unshare_game_files(&game_dir)?;
+
         let game_dir_str = game_dir.to_string_lossy().to_string();
 
         // プロファイル更新のためのクローン
@@ -156,28 +472,12 @@ impl ResoniteInstall {
             &game_dir_str,
             Box::new(move |success| {
                 if success {
-                    println!("Installation completed for profile: {}", profile_name_for_update);
+                    log_println!("Installation completed for profile: {}", profile_name_for_update);
                     
                     // プロファイル情報を更新
-                    if let Ok(mut profile) = profile_manager_clone.get_profile(&profile_name_for_update) {
-                        let profile_dir = profile_manager_clone.get_profile_dir(&profile_name_for_update);
-                        let version = profile.get_game_version(&profile_dir);
-                        let game_info = GameInfo {
-                            branch: branch_for_update.clone(),
-                            manifest_id: manifest_id_for_update.clone(),
-                            depot_id: "2519832".to_string(),
-                            installed: true,
-                            last_updated: Some(Utc::now().to_rfc3339()),
-                            version,
-                        };
-                        
-                        profile.update_game_info(game_info);
-                        
-                        if let Err(e) = profile_manager_clone.update_profile(&profile) {
-                            eprintln!("Failed to update profile after installation: {}", e);
-                        } else {
-                            println!("Profile '{}' updated successfully with game info", profile_name_for_update);
-                        }
+                    match record_installed_game(&profile_manager_clone, &profile_name_for_update, &branch_for_update, manifest_id_for_update.clone()) {
+                        Ok(()) => log_println!("Profile '{}' updated successfully with game info", profile_name_for_update),
+                        Err(e) => eprintln!("Failed to update profile after installation: {}", e),
                     }
                 }
                 on_complete(success);
@@ -185,7 +485,7 @@ impl ResoniteInstall {
         )?;
 
         // DepotDownloaderでResoniteをダウンロード（インタラクティブ）
-        depot_downloader.download_resonite_interactive(
+        self.branch_downloader(depot_downloader)?.download_resonite_interactive(
             &game_dir_str,
             &self.branch,
             self.manifest_id.as_deref(),
@@ -193,32 +493,34 @@ impl ResoniteInstall {
             self.password.as_deref(),
         )?;
 
-        println!("Installation process launched in separate window with monitoring!");
-        println!("Please check the command prompt window for Steam 2FA prompts.");
-        println!("You will be notified when installation completes.");
+        log_println!("Installation process launched in separate window with monitoring!");
+        log_println!("Please check the command prompt window for Steam 2FA prompts.");
+        log_println!("You will be notified when installation completes.");
         Ok(())
     }
 
     /// プロファイルのResoniteを更新する（バックグラウンド）
     pub fn update(&self, depot_downloader: &DepotDownloader, profile_manager: &ProfileManager) -> Result<(), Box<dyn Error>> {
-        println!(
+        log_println!(
             "Updating Resonite {} branch in profile '{}'",
             self.branch, self.profile_name
         );
 
         // For DepotDownloader, update is the same as install
-        self.install(depot_downloader, profile_manager)
+        // 固定バージョンが指定されている場合はそのマニフェストを維持する
+        self.with_pinned_manifest(profile_manager)?.install(depot_downloader, profile_manager)
     }
 
     /// プロファイルのResoniteを更新する（インタラクティブ、2FA対応） 
     pub fn update_interactive(&self, depot_downloader: &DepotDownloader, profile_manager: &ProfileManager) -> Result<(), Box<dyn Error>> {
-        println!(
+        log_println!(
             "Updating Resonite {} branch in profile '{}' (Interactive Mode)",
             self.branch, self.profile_name
         );
 
         // For DepotDownloader, update is the same as install
-        self.install_interactive(depot_downloader, profile_manager)
+        // 固定バージョンが指定されている場合はそのマニフェストを維持する
+        self.with_pinned_manifest(profile_manager)?.install_interactive(depot_downloader, profile_manager)
     }
 
     /// プロファイルのResoniteを更新する（インタラクティブ、監視付き）
@@ -226,22 +528,23 @@ impl ResoniteInstall {
     where 
         F: Fn(bool) + Send + 'static,
     {
-        println!(
+        log_println!(
             "Updating Resonite {} branch in profile '{}' (Interactive Mode with Monitoring)",
             self.branch, self.profile_name
         );
 
         // For DepotDownloader, update is the same as install with monitoring
-        self.install_interactive_with_monitoring(depot_downloader, profile_manager, on_complete)
+        // 固定バージョンが指定されている場合はそのマニフェストを維持する
+        self.with_pinned_manifest(profile_manager)?.install_interactive_with_monitoring(depot_downloader, profile_manager, on_complete)
     }
 
     /// プロファイルにResoniteをインストールする（自動フォールバック付き）
     /// バックグラウンドインストールを試行し、失敗したらインタラクティブモードにフォールバック
     pub fn install_with_fallback<F>(&self, depot_downloader: &DepotDownloader, profile_manager: &ProfileManager, on_status: F) -> Result<(), Box<dyn Error>> 
     where 
-        F: Fn(&str, bool) + Send + 'static + Clone,
+        F: Fn(&str, InstallStatus) + Send + 'static + Clone,
     {
-        println!(
+        log_println!(
             "Installing Resonite {} branch to profile '{}' (Auto-fallback Mode)",
             self.branch, self.profile_name
         );
@@ -251,57 +554,52 @@ impl ResoniteInstall {
         let profile_dir = profile_manager.get_profile_dir(&self.profile_name);
         let game_dir = profile_dir.join("Game");
 
+        // 別のものが入っているフォルダに混在させない
+        self.ensure_install_target_safe(profile_manager)?;
+
         // ゲームディレクトリを作成
         if !game_dir.exists() {
             fs::create_dir_all(&game_dir)?;
         }
 
-        // まず通常のバックグラウンドインストールを試行
-        on_status("バックグラウンドインストールを試行中...", false);
+        // まず通常のバックグラウンドインストールを試行（一時フォルダにダウンロードしてから入れ替える）
+        on_status("バックグラウンドインストールを試行中...", InstallStatus::InProgress);
         
-        let background_result = depot_downloader.download_resonite(
-            &game_dir.to_string_lossy(),
-            &self.branch,
-            self.manifest_id.as_deref(),
-            self.username.as_deref(),
-            self.password.as_deref(),
-        );
+        let background_result = self.download_staged(depot_downloader, &game_dir);
 
         match background_result {
             Ok(_) => {
                 // バックグラウンドインストールが成功
-                println!("Background installation succeeded for profile: {}", self.profile_name);
+                log_println!("Background installation succeeded for profile: {}", self.profile_name);
                 
                 // プロファイル情報を更新
-                let mut profile = profile_manager.get_profile(&self.profile_name)?;
-                let profile_dir = profile_manager.get_profile_dir(&self.profile_name);
-                let version = profile.get_game_version(&profile_dir);
-                let game_info = GameInfo {
-                    branch: self.branch.clone(),
-                    manifest_id: self.manifest_id.clone(),
-                    depot_id: "2519832".to_string(),
-                    installed: true,
-                    last_updated: Some(Utc::now().to_rfc3339()),
-                    version,
-                };
+                record_installed_game(profile_manager, &self.profile_name, &self.branch, self.manifest_id.clone())?;
                 
-                profile.update_game_info(game_info);
-                profile_manager.update_profile(&profile)?;
-                
-                on_status("インストールが完了しました", true);
+                on_status("インストールが完了しました", InstallStatus::Succeeded);
                 Ok(())
             }
+            Err(e) if e.is::<NetworkDisconnectedError>() => {
+                // 認証の問題ではないためインタラクティブモードにはフォールバックしない
+                log_println!("Background installation interrupted: {}", e);
+                on_status(&e.to_string(), InstallStatus::Failed);
+                Err(e)
+            }
+            Err(e) if e.is::<SteamGuardRequiredError>() => {
+                // 完了扱いにはせず、呼び出し側で認証コードを入力してもらってコード付きで再実行する
+                log_println!("Background installation needs a Steam Guard code: {}", e);
+                Err(e)
+            }
             Err(e) => {
                 // バックグラウンドインストールが失敗 - インタラクティブモードにフォールバック
-                println!("Background installation failed: {}. Falling back to interactive mode.", e);
-                on_status("バックグラウンドインストールが失敗しました。Steam認証が必要な可能性があります。\nコマンドウィンドウでインタラクティブインストールを開始します...", false);
+                log_println!("Background installation failed: {}. Falling back to interactive mode.", e);
+                on_status("バックグラウンドインストールが失敗しました。Steam認証が必要な可能性があります。\nコマンドウィンドウでインタラクティブインストールを開始します...", InstallStatus::InProgress);
 
                 // インタラクティブモードで再試行
                 self.install_interactive_with_monitoring(depot_downloader, profile_manager, move |success| {
                     if success {
-                        on_status("インタラクティブインストールが完了しました", true);
+                        on_status("インタラクティブインストールが完了しました", InstallStatus::Succeeded);
                     } else {
-                        on_status("インタラクティブインストールも失敗しました", true);
+                        on_status("インタラクティブインストールも失敗しました", InstallStatus::Failed);
                     }
                 })?;
 
@@ -313,29 +611,76 @@ impl ResoniteInstall {
     /// プロファイルのResoniteを更新する（自動フォールバック付き）
     pub fn update_with_fallback<F>(&self, depot_downloader: &DepotDownloader, profile_manager: &ProfileManager, on_status: F) -> Result<(), Box<dyn Error>> 
     where 
-        F: Fn(&str, bool) + Send + 'static + Clone,
+        F: Fn(&str, InstallStatus) + Send + 'static + Clone,
     {
-        println!(
+        log_println!(
             "Updating Resonite {} branch in profile '{}' (Auto-fallback Mode)",
             self.branch, self.profile_name
         );
 
         // For DepotDownloader, update is the same as install with fallback
-        self.install_with_fallback(depot_downloader, profile_manager, on_status)
+        // 固定バージョンが指定されている場合はそのマニフェストを維持する
+        self.with_pinned_manifest(profile_manager)?.install_with_fallback(depot_downloader, profile_manager, on_status)
+    }
+
+    /// プロファイルの設定に従ってゲームを自動更新する（一括更新・起動時チェック用）
+    ///
+    /// 自動更新が無効なプロファイルはスキップし、固定バージョンのプロファイルは
+    /// 固定先と異なる場合のみ固定バージョンへ戻す。更新した場合はtrueを返す。
+    pub fn auto_update(&self, depot_downloader: &DepotDownloader, profile_manager: &ProfileManager) -> Result<bool, Box<dyn Error>> {
+        let profile = profile_manager.get_profile(&self.profile_name)?;
+
+        let needs_update = if profile.should_auto_update_game() {
+            self.check_updates(depot_downloader, profile_manager)?
+        } else if profile.pinned_manifest_id.is_some() {
+            profile.is_pinned_version_mismatch()
+        } else {
+            log_println!("Auto update is disabled for profile '{}', skipping", self.profile_name);
+            false
+        };
+        if !needs_update {
+            return Ok(false);
+        }
+
+        self.update(depot_downloader, profile_manager)?;
+        Ok(true)
+    }
+
+    /// 固定バージョンを反映したインストール情報を返す
+    fn with_pinned_manifest(&self, profile_manager: &ProfileManager) -> Result<ResoniteInstall, Box<dyn Error>> {
+        let profile = profile_manager.get_profile(&self.profile_name)?;
+
+        Ok(ResoniteInstall::new(
+            self.profile_name.clone(),
+            self.branch.clone(),
+            profile.resolve_manifest_id(self.manifest_id.clone()),
+            self.username.clone(),
+            self.password.clone(),
+        )
+        .with_wait_for_network(self.wait_for_network)
+        .with_overwrite(self.overwrite)
+        .with_auth_code(self.auth_code.clone())
+        .with_beta_password(self.beta_password.clone()))
     }
 
     /// プロファイルのアップデートがあるかチェックする
     pub fn check_updates(&self, depot_downloader: &DepotDownloader, profile_manager: &ProfileManager) -> Result<bool, Box<dyn Error>> {
-        println!(
+        log_println!(
             "Checking updates for Resonite {} branch in profile '{}'",
             self.branch, self.profile_name
         );
 
+        // 固定バージョンの場合は、固定先と異なるときだけ更新が必要
+        let profile = profile_manager.get_profile(&self.profile_name)?;
+        if profile.pinned_manifest_id.is_some() {
+            return Ok(profile.is_pinned_version_mismatch());
+        }
+
         let profile_dir = profile_manager.get_profile_dir(&self.profile_name);
         let game_dir = profile_dir.join("Game");
 
         // Use DepotDownloader to check for updates
-        depot_downloader.check_updates(
+        self.branch_downloader(depot_downloader)?.check_updates(
             &game_dir.to_string_lossy(),
             &self.branch,
             self.manifest_id.as_deref(),
@@ -346,7 +691,81 @@ impl ResoniteInstall {
 
 }
 
+/// インストール・更新の完了後に、プロファイルのゲーム情報を更新してバージョン履歴に記録する
+fn record_installed_game(
+    profile_manager: &ProfileManager,
+    profile_name: &str,
+    branch: &str,
+    manifest_id: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    let mut profile = profile_manager.get_profile(profile_name)?;
+    let profile_dir = profile_manager.get_profile_dir(profile_name);
+    let version = profile.get_game_version(&profile_dir);
+    let game_info = GameInfo {
+        branch: branch.to_string(),
+        manifest_id: manifest_id.clone(),
+        depot_id: RESONITE_DEPOT_ID.to_string(),
+        installed: true,
+        last_updated: Some(Utc::now().to_rfc3339()),
+        version: version.clone(),
+    };
+
+    profile.update_game_info(game_info);
+    profile_manager.update_profile(&profile)?;
+
+    // 最新版をインストールした場合もダウングレードに使えるよう、実際のマニフェストIDを記録する
+    let manifest_id = manifest_id.or_else(|| {
+        version_history::detect_installed_manifest_id(&profile.get_game_dir(&profile_dir), RESONITE_DEPOT_ID)
+    });
+    let record = VersionRecord {
+        installed_at: Utc::now().to_rfc3339(),
+        branch: branch.to_string(),
+        manifest_id,
+        version,
+    };
+    if let Err(e) = VersionHistoryManager::new(&profile_dir).add_record(record) {
+        eprintln!("Failed to record version history: {}", e);
+    }
+    Ok(())
+}
+
+/// Resoniteのログファイルの情報
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogFileInfo {
+    pub file_name: String,
+    pub path: PathBuf,
+    pub size: u64,
+    /// 最終更新日時（RFC 3339）
+    pub modified: String,
+}
+
+/// プロファイルのログフォルダ（DataPath/Logs, Game/Logs の順）
+fn log_dirs(profile_dir: &Path) -> Vec<PathBuf> {
+    let profile = Profile::load(profile_dir).ok();
+    let data_path = profile
+        .as_ref()
+        .and_then(|p| p.get_data_path(profile_dir))
+        .unwrap_or_else(|| profile_dir.join("DataPath"));
+    let game_dir = profile
+        .as_ref()
+        .map(|p| p.get_game_dir(profile_dir))
+        .unwrap_or_else(|| profile_dir.join("Game"));
+
+    let mut dirs = vec![data_path.join("Logs")];
+    if !dirs.contains(&game_dir.join("Logs")) {
+        dirs.push(game_dir.join("Logs"));
+    }
+    dirs
+}
+
+/// ログファイルを読み込む（UTF-8として不正なバイトは置き換える）
+fn read_log(path: &Path) -> Result<String, Box<dyn Error>> {
+    let bytes = fs::read(path)?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
 /// Resoniteのインストールマネージャ
+#[derive(Clone)]
 pub struct ResoniteInstallManager {
     base_dir: PathBuf,
 }
@@ -386,48 +805,201 @@ impl ResoniteInstallManager {
         Ok(resonite_exe)
     }
 
-    /// プロファイルでResoniteを起動する
+    /// プロファイルでResoniteを起動する（`join_session` を指定するとそのセッションに直接参加する）
     pub fn launch_with_profile(
         &self,
         profile_name: &str,
         profile_manager: &ProfileManager,
+        join_session: Option<&str>,
     ) -> Result<(), Box<dyn Error>> {
-        use std::process::Command;
+        let overrides = LaunchOverrides {
+            join_session: join_session.map(|s| s.to_string()),
+            ..Default::default()
+        };
+        self.launch_with_overrides(profile_name, profile_manager, &overrides)
+    }
 
-        // プロファイルを読み込み
+    /// プロファイルでResoniteを起動する（引数の上書きはこの起動だけに適用し、プロファイルは変更しない）
+    pub fn launch_with_overrides(
+        &self,
+        profile_name: &str,
+        profile_manager: &ProfileManager,
+        overrides: &LaunchOverrides,
+    ) -> Result<(), Box<dyn Error>> {
         let profile = profile_manager.get_profile(profile_name)?;
         let profile_dir = profile_manager.get_profile_dir(profile_name);
 
-        // ゲームがインストールされているかチェック
-        if !profile.has_game_installed() {
-            return Err(format!("Game is not installed in profile '{}'", profile_name).into());
+        // 起動引数を展開
+        let mut expanded_args = profile.resolve_launch_args(&profile_dir)?;
+        overrides.apply(&mut expanded_args)?;
+
+        let child = self.spawn_resonite(&profile, &profile_dir, &expanded_args)?;
+        log_println!("Resonite launched successfully!");
+
+        self.run_post_launch_commands(&profile, &profile_dir, child, None);
+        Ok(())
+    }
+
+    /// プロファイルでResoniteを起動し、起動完了までの時間を計測して記録する
+    ///
+    /// 起動完了はGame/Logsに出力されるResoniteのログから検知する。
+    /// 計測結果は起動時のMOD構成と一緒にプロファイルの起動履歴へ保存される。
+    pub fn launch_and_monitor(
+        &self,
+        profile_name: &str,
+        profile_manager: &ProfileManager,
+        timeout: Duration,
+    ) -> Result<LaunchRecord, Box<dyn Error>> {
+        let profile = profile_manager.get_profile(profile_name)?;
+        let profile_dir = profile_manager.get_profile_dir(profile_name);
+
+        // 起動前のMOD構成を記録
+        let mods = collect_enabled_mods(&profile_dir, profile.mod_loader_type.as_ref());
+        let launched_at = Utc::now().to_rfc3339();
+        let launched_time = SystemTime::now();
+
+        let expanded_args = profile.resolve_launch_args(&profile_dir)?;
+        let mut child = self.spawn_resonite(&profile, &profile_dir, &expanded_args)?;
+        log_println!("Resonite launched, waiting for startup to complete...");
+
+        let logs_dir = profile_dir.join("Game").join("Logs");
+        let elapsed = wait_for_startup_complete(&logs_dir, launched_time, timeout, || {
+            matches!(child.try_wait(), Ok(None))
+        });
+
+        // 起動完了前にプロセスが終了していればクラッシュとみなす
+        let crashed = elapsed.is_none() && matches!(child.try_wait(), Ok(Some(_)));
+
+        match elapsed {
+            Some(duration) => log_println!("Resonite startup completed in {:.1}s", duration.as_secs_f64()),
+            None if crashed => log_println!("Resonite exited before startup completed"),
+            None => log_println!("Could not detect Resonite startup completion"),
         }
 
-        // Resonite実行ファイルのパスを取得
-        let resonite_path = profile.get_resonite_exe(&profile_dir);
-        if !resonite_path.exists() {
-            return Err(format!("Resonite executable not found at {}", resonite_path.display()).into());
+        let record = LaunchRecord {
+            launched_at,
+            startup_seconds: elapsed.map(|d| d.as_secs_f64()),
+            completed: elapsed.is_some(),
+            crashed,
+            suggest_safe_mode: crashed && !mods.is_empty(),
+            mod_loader_type: profile.mod_loader_type,
+            mods,
+        };
+
+        LaunchStatsManager::new(&profile_dir).add_record(record.clone())?;
+
+        // 起動の成否と、起動後の終了をWebhookで通知する
+        let on_exit = match profile.webhook.clone() {
+            Some(webhook) if crashed => {
+                let exit_code = child.try_wait().ok().flatten().and_then(|status| status.code());
+                webhook::notify_in_background(
+                    &webhook,
+                    WebhookPayload::new(LaunchWebhookEvent::Crashed, profile.get_display_name()).with_exit_code(exit_code),
+                );
+                None
+            }
+            Some(webhook) => {
+                webhook::notify_in_background(
+                    &webhook,
+                    WebhookPayload::new(LaunchWebhookEvent::Launched, profile.get_display_name())
+                        .with_startup_seconds(record.startup_seconds),
+                );
+                let profile_name = profile.get_display_name().to_string();
+                let on_exit: ExitHook = Box::new(move |status| {
+                    let event = match status {
+                        Some(status) if status.success() => LaunchWebhookEvent::Exited,
+                        _ => LaunchWebhookEvent::Crashed,
+                    };
+                    let exit_code = status.and_then(|status| status.code());
+                    webhook::notify_in_background(
+                        &webhook,
+                        WebhookPayload::new(event, &profile_name).with_exit_code(exit_code),
+                    );
+                });
+                Some(on_exit)
+            }
+            None => None,
+        };
+
+        self.run_post_launch_commands(&profile, &profile_dir, child, on_exit);
+        Ok(record)
+    }
+
+    /// プロファイルの起動統計を取得する
+    pub fn get_launch_stats(
+        &self,
+        profile_name: &str,
+        profile_manager: &ProfileManager,
+    ) -> LaunchStats {
+        let profile_dir = profile_manager.get_profile_dir(profile_name);
+        LaunchStatsManager::new(&profile_dir).get_stats()
+    }
+
+    /// プロファイルのResoniteのログファイル一覧を新しい順に取得する
+    ///
+    /// `DataPath/Logs` と、Resoniteが既定で出力する `Game/Logs` の `.log` ファイルが対象。
+    pub fn list_logs(&self, profile_dir: &Path) -> Result<Vec<LogFileInfo>, Box<dyn Error>> {
+        let mut logs: Vec<(LogFileInfo, SystemTime)> = Vec::new();
+
+        for logs_dir in log_dirs(profile_dir) {
+            if !logs_dir.is_dir() {
+                continue;
+            }
+            for entry in fs::read_dir(&logs_dir)? {
+                let entry = entry?;
+                let path = entry.path();
+                let is_log = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("log"));
+                if !is_log || !path.is_file() {
+                    continue;
+                }
+
+                let file_name = entry.file_name().to_string_lossy().to_string();
+                // 同名のファイルはDataPath側を優先する
+                if logs.iter().any(|(log, _)| log.file_name == file_name) {
+                    continue;
+                }
+
+                let metadata = entry.metadata()?;
+                let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                logs.push((
+                    LogFileInfo {
+                        file_name,
+                        path,
+                        size: metadata.len(),
+                        modified: chrono::DateTime::<Utc>::from(modified).to_rfc3339(),
+                    },
+                    modified,
+                ));
+            }
         }
 
-        // 起動引数を展開
-        let expanded_args = profile.expand_args(&profile_dir);
+        logs.sort_by_key(|(_, modified)| std::cmp::Reverse(*modified));
+        Ok(logs.into_iter().map(|(log, _)| log).collect())
+    }
 
-        println!(
-            "Launching Resonite with profile '{}'",
-            profile.name
-        );
-        println!("Executable: {}", resonite_path.display());
-        println!("Arguments: {:?}", expanded_args);
+    /// ログファイルを名前で指定して読み込む
+    pub fn get_log(&self, profile_dir: &Path, file_name: &str) -> Result<String, Box<dyn Error>> {
+        // ログフォルダの外を読めないよう、ファイル名だけを受け付ける
+        if Path::new(file_name).file_name() != Some(std::ffi::OsStr::new(file_name)) {
+            return Err(format!("Invalid log file name: {}", file_name).into());
+        }
 
-        // Resoniteを起動（Gameディレクトリをカレントディレクトリに設定）
-        let game_dir = profile_dir.join("Game");
-        Command::new(resonite_path)
-            .args(&expanded_args)
-            .current_dir(&game_dir)
-            .spawn()?;
+        let log = self
+            .list_logs(profile_dir)?
+            .into_iter()
+            .find(|log| log.file_name == file_name)
+            .ok_or_else(|| format!("Log file not found: {}", file_name))?;
+        read_log(&log.path)
+    }
 
-        println!("Resonite launched successfully!");
-        Ok(())
+    /// 最新のログファイルを読み込む
+    pub fn get_latest_log(&self, profile_dir: &Path) -> Result<String, Box<dyn Error>> {
+        let log = self
+            .list_logs(profile_dir)?
+            .into_iter()
+            .next()
+            .ok_or("No Resonite log files found")?;
+        read_log(&log.path)
     }
 
     /// プロファイルでResoniteを特定のモードで起動する（引数強制上書き）
@@ -437,25 +1009,12 @@ impl ResoniteInstallManager {
         profile_manager: &ProfileManager,
         mode: &str,
     ) -> Result<(), Box<dyn Error>> {
-        use std::process::Command;
-
         // プロファイルを読み込み
         let profile = profile_manager.get_profile(profile_name)?;
         let profile_dir = profile_manager.get_profile_dir(profile_name);
 
-        // ゲームがインストールされているかチェック
-        if !profile.has_game_installed() {
-            return Err(format!("Game is not installed in profile '{}'", profile_name).into());
-        }
-
-        // Resonite実行ファイルのパスを取得
-        let resonite_path = profile.get_resonite_exe(&profile_dir);
-        if !resonite_path.exists() {
-            return Err(format!("Resonite executable not found at {}", resonite_path.display()).into());
-        }
-
         // 起動引数を展開
-        let mut expanded_args = profile.expand_args(&profile_dir);
+        let mut expanded_args = profile.resolve_launch_args(&profile_dir)?;
 
         // モードに応じて引数を強制上書き
         match mode {
@@ -479,21 +1038,423 @@ impl ResoniteInstallManager {
             }
         }
 
-        println!(
-            "Launching Resonite with profile '{}' in {} mode",
-            profile.name, mode
+        log_println!("Launch mode: {}", mode);
+        let child = self.spawn_resonite(&profile, &profile_dir, &expanded_args)?;
+        log_println!("Resonite launched successfully in {} mode!", mode);
+
+        self.run_post_launch_commands(&profile, &profile_dir, child, None);
+        Ok(())
+    }
+
+    /// 展開済みの引数でResoniteプロセスを起動する
+    fn spawn_resonite(
+        &self,
+        profile: &Profile,
+        profile_dir: &Path,
+        args: &[String],
+    ) -> Result<Child, Box<dyn Error>> {
+        // ゲームがインストールされているかチェック
+        if !profile.has_game_installed() {
+            return Err(format!("Game is not installed in profile '{}'", profile.get_display_name()).into());
+        }
+
+        // 同じプロファイルの二重起動はDataPathが競合してセーブが壊れるため拒否する
+        launch_lock::ensure_not_running(profile_dir, profile.get_display_name())?;
+
+        // Resonite実行ファイルのパスを取得
+        let resonite_path = profile.get_resonite_exe(profile_dir);
+        if !resonite_path.exists() {
+            return Err(format!("Resonite executable not found at {}", resonite_path.display()).into());
+        }
+
+        // 外付け・ネットワークドライブ等でDataPathに書き込めない場合は警告を出す（起動は続ける）
+        // （起動時の上書きも反映されるよう、実際に渡す引数から取得する）
+        let data_path = args
+            .iter()
+            .position(|arg| arg.eq_ignore_ascii_case("-DataPath"))
+            .and_then(|i| args.get(i + 1));
+        if let Some(data_path) = data_path {
+            if let Err(e) = crate::utils::check_directory_writable(Path::new(data_path)) {
+                eprintln!("Warning: DataPath is not writable: {}", e);
+            }
+        }
+
+        log_println!(
+            "Launching Resonite with profile '{}'",
+            profile.get_display_name()
         );
-        println!("Executable: {}", resonite_path.display());
-        println!("Arguments: {:?}", expanded_args);
+        log_println!("Executable: {}", resonite_path.display());
+        log_println!("Arguments: {:?}", args);
 
         // Resoniteを起動（Gameディレクトリをカレントディレクトリに設定）
-        let game_dir = profile_dir.join("Game");
-        Command::new(resonite_path)
-            .args(&expanded_args)
+        let game_dir = profile.get_game_dir(profile_dir);
+        let child = Command::new(resonite_path)
+            .args(args)
             .current_dir(&game_dir)
             .spawn()?;
 
-        println!("Resonite launched successfully in {} mode!", mode);
-        Ok(())
+        if let Err(e) = launch_lock::acquire(profile_dir, child.id()) {
+            eprintln!("Warning: Failed to create launch lock: {}", e);
+        }
+
+        Ok(child)
+    }
+
+    /// Resonite起動後にプロファイルの起動後コマンドを順に実行する
+    ///
+    /// `stop_on_exit` が指定されたコマンドは、Resonite終了時に停止する。
+    /// `on_exit` はResonite終了時に終了ステータスを渡して呼び出す。
+    fn run_post_launch_commands(&self, profile: &Profile, profile_dir: &Path, mut resonite: Child, on_exit: Option<ExitHook>) {
+        let companions = self.start_post_launch_commands(profile, profile_dir, &mut resonite);
+        if companions.is_empty() && on_exit.is_none() {
+            return;
+        }
+
+        // Resonite終了時に連動プロセスを停止
+        let lock_dir = profile_dir.to_path_buf();
+        std::thread::spawn(move || {
+            let status = resonite.wait().ok();
+            let _ = launch_lock::release(&lock_dir);
+            for (command, mut child) in companions {
+                if let Ok(None) = child.try_wait() {
+                    match child.kill() {
+                        Ok(()) => log_println!("Stopped post-launch command: {}", command),
+                        Err(e) => eprintln!("Failed to stop post-launch command {}: {}", command, e),
+                    }
+                    let _ = child.wait();
+                }
+            }
+            if let Some(on_exit) = on_exit {
+                on_exit(status);
+            }
+        });
+    }
+
+    /// 起動後コマンドを開始し、Resonite終了時に停止するプロセスを返す
+    fn start_post_launch_commands(&self, profile: &Profile, profile_dir: &Path, resonite: &mut Child) -> Vec<(String, Child)> {
+        if profile.post_launch_commands.is_empty() {
+            return Vec::new();
+        }
+
+        // Resoniteのプロセスが起動していることを確認
+        match resonite.try_wait() {
+            Ok(None) => {}
+            Ok(Some(status)) => {
+                eprintln!("Resonite exited early ({}), skipping post-launch commands", status);
+                return Vec::new();
+            }
+            Err(e) => {
+                eprintln!("Failed to check Resonite process, skipping post-launch commands: {}", e);
+                return Vec::new();
+            }
+        }
+
+        let mut companions = Vec::new();
+        for post_launch in &profile.post_launch_commands {
+            let command = profile.expand_path_variables(&post_launch.command, profile_dir);
+            let args: Vec<String> = post_launch.args.iter()
+                .map(|arg| profile.expand_path_variables(arg, profile_dir))
+                .collect();
+
+            match Command::new(&command).args(&args).current_dir(profile_dir).spawn() {
+                Ok(child) => {
+                    log_println!("Post-launch command started: {} {:?} (pid {})", command, args, child.id());
+                    if post_launch.stop_on_exit {
+                        companions.push((command, child));
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Post-launch command failed: {} {:?}: {}", command, args, e);
+                }
+            }
+        }
+        companions
+    }
+}
+
+/// Resonite終了時に呼び出す処理（終了ステータスを取得できなかった場合はNone）
+type ExitHook = Box<dyn FnOnce(Option<ExitStatus>) + Send>;
+
+/// Gameフォルダと同じ階層に置く、ダウンロード用の一時フォルダ
+fn staging_dir_for(game_dir: &Path) -> PathBuf {
+    game_dir.with_file_name(format!("{}.staging", file_name_of(game_dir)))
+}
+
+/// 入れ替え中に元のGameフォルダを退避しておくフォルダ
+fn backup_dir_for(game_dir: &Path) -> PathBuf {
+    game_dir.with_file_name(format!("{}.old", file_name_of(game_dir)))
+}
+
+fn file_name_of(path: &Path) -> String {
+    path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_else(|| "Game".to_string())
+}
+
+/// 前回の入れ替えが途中で止まっていた場合に元の状態へ戻す
+///
+/// Gameフォルダが無く退避フォルダだけが残っている場合は退避フォルダを戻し、
+/// 両方ある場合は入れ替え済みとみなして退避フォルダを削除する。
+fn recover_interrupted_swap(game_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let backup_dir = backup_dir_for(game_dir);
+    if !backup_dir.exists() {
+        return Ok(());
+    }
+    if game_dir.exists() {
+        fs::remove_dir_all(&backup_dir)?;
+    } else {
+        fs::rename(&backup_dir, game_dir)?;
+    }
+    Ok(())
+}
+
+/// Gameフォルダと同じ階層に、空の一時フォルダを用意する
+///
+/// 元のGameフォルダは複製せず、MODローダーやMODは入れ替え時にrenameで移す。
+fn prepare_staging_dir(game_dir: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    recover_interrupted_swap(game_dir)?;
+
+    let staging_dir = staging_dir_for(game_dir);
+    if staging_dir.exists() {
+        fs::remove_dir_all(&staging_dir)?;
+    }
+    fs::create_dir_all(&staging_dir)?;
+    Ok(staging_dir)
+}
+
+/// ダウンロードが完了した一時フォルダとGameフォルダをrenameで入れ替える
+///
+/// 一時フォルダに無いファイル（MODローダーやMOD、設定など）は先に元のGameフォルダから移しておく。
+/// 入れ替えに失敗した場合は移したファイルを戻し、元のGameフォルダを残したままエラーを返す。
+fn swap_in_staging_dir(game_dir: &Path, staging_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let backup_dir = backup_dir_for(game_dir);
+    let had_game_dir = game_dir.exists();
+    let mut moved = Vec::new();
+
+    if had_game_dir {
+        // Resoniteの起動中などでファイルやフォルダを移動できない場合はここで失敗する
+        if let Err(e) = carry_over_files(game_dir, staging_dir, &mut moved) {
+            restore_carried_over_files(&moved);
+            return Err(format!("Failed to move MODs and settings to the downloaded game (is Resonite running?): {}", e).into());
+        }
+        if let Err(e) = fs::rename(game_dir, &backup_dir) {
+            restore_carried_over_files(&moved);
+            return Err(format!("Failed to move the current game folder aside (is Resonite running?): {}", e).into());
+        }
+    }
+
+    if let Err(e) = fs::rename(staging_dir, game_dir) {
+        if had_game_dir {
+            fs::rename(&backup_dir, game_dir)?;
+            restore_carried_over_files(&moved);
+        }
+        return Err(format!("Failed to move the downloaded game into place: {}", e).into());
+    }
+
+    if had_game_dir {
+        if let Err(e) = fs::remove_dir_all(&backup_dir) {
+            // 次回のインストール時にも削除を試みる
+            eprintln!("Warning: Failed to remove previous game folder {}: {}", backup_dir.display(), e);
+        }
+    }
+    Ok(())
+}
+
+/// 一時フォルダに無いファイルやフォルダを、元のGameフォルダからrenameで移す
+///
+/// 移した元のパスと移動先のパスを `moved` に記録する。
+/// DepotDownloaderの記録と共有の記録は新しいGameフォルダのものを使うため移さない。
+fn carry_over_files(source: &Path, target: &Path, moved: &mut Vec<(PathBuf, PathBuf)>) -> std::io::Result<()> {
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if name == ".DepotDownloader" || name == game_dedup::LINK_RECORD_FILE {
+            continue;
+        }
+
+        let source_path = entry.path();
+        let target_path = target.join(&name);
+        if !target_path.exists() {
+            fs::rename(&source_path, &target_path)?;
+            moved.push((source_path, target_path));
+        } else if entry.file_type()?.is_dir() && target_path.is_dir() {
+            carry_over_files(&source_path, &target_path, moved)?;
+        }
+    }
+    Ok(())
+}
+
+/// `carry_over_files` で移したファイルを元のGameフォルダに戻す
+fn restore_carried_over_files(moved: &[(PathBuf, PathBuf)]) {
+    for (original, carried) in moved.iter().rev() {
+        if let Err(e) = fs::rename(carried, original) {
+            eprintln!("Warning: Failed to move {} back to {}: {}", carried.display(), original.display(), e);
+        }
+    }
+}
+
+/// Gameフォルダの状態を検査する（存在しない・空の場合は衝突なし）
+fn inspect_game_dir(game_dir: &Path, game_info: Option<&GameInfo>, branch: &str) -> Vec<InstallConflict> {
+    let mut entries: Vec<String> = match fs::read_dir(game_dir) {
+        Ok(read_dir) => read_dir
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect(),
+        Err(_) => return Vec::new(),
+    };
+    if entries.is_empty() {
+        return Vec::new();
+    }
+    entries.sort();
+
+    let has_resonite = entries
+        .iter()
+        .any(|name| RESONITE_INSTALL_MARKERS.iter().any(|marker| name.eq_ignore_ascii_case(marker)));
+    if !has_resonite {
+        entries.truncate(MAX_REPORTED_FOREIGN_ENTRIES);
+        return vec![InstallConflict::ForeignFiles { entries }];
+    }
+
+    match game_info {
+        Some(info) if info.installed && info.branch != branch => vec![InstallConflict::DifferentBranch {
+            installed_branch: info.branch.clone(),
+        }],
+        _ => Vec::new(),
+    }
+}
+
+/// ファイルのSHA1ハッシュを小文字16進で計算する
+fn file_sha1(path: &Path) -> Result<String, Box<dyn Error>> {
+    use sha1::{Digest, Sha1};
+
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha1::new();
+    std::io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn game_info(branch: &str) -> GameInfo {
+        GameInfo {
+            branch: branch.to_string(),
+            manifest_id: None,
+            depot_id: "2519832".to_string(),
+            installed: true,
+            last_updated: None,
+            version: None,
+        }
+    }
+
+    #[test]
+    fn test_inspect_game_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let game_dir = temp_dir.path().join("Game");
+
+        // 存在しない・空のフォルダは安全
+        assert!(inspect_game_dir(&game_dir, None, "release").is_empty());
+        fs::create_dir_all(&game_dir).unwrap();
+        assert!(inspect_game_dir(&game_dir, None, "release").is_empty());
+
+        // Resonite以外のファイルだけがある
+        fs::write(game_dir.join("notes.txt"), b"x").unwrap();
+        assert_eq!(
+            inspect_game_dir(&game_dir, None, "release"),
+            vec![InstallConflict::ForeignFiles { entries: vec!["notes.txt".to_string()] }]
+        );
+
+        // 同じブランチのResoniteの上書き（更新）は安全、別ブランチは衝突
+        fs::write(game_dir.join("Resonite.exe"), b"x").unwrap();
+        assert!(inspect_game_dir(&game_dir, Some(&game_info("release")), "release").is_empty());
+        assert_eq!(
+            inspect_game_dir(&game_dir, Some(&game_info("prerelease")), "release"),
+            vec![InstallConflict::DifferentBranch { installed_branch: "prerelease".to_string() }]
+        );
+    }
+
+    #[test]
+    fn test_staged_download_swap() {
+        let temp_dir = TempDir::new().unwrap();
+        let game_dir = temp_dir.path().join("Game");
+        fs::create_dir_all(game_dir.join("rml_mods")).unwrap();
+        fs::write(game_dir.join("Resonite.exe"), b"old").unwrap();
+        fs::write(game_dir.join("rml_mods").join("ModA.dll"), b"mod").unwrap();
+        fs::write(game_dir.join(game_dedup::LINK_RECORD_FILE), b"[]").unwrap();
+
+        // 一時フォルダは空の状態から始まる
+        let staging_dir = prepare_staging_dir(&game_dir).unwrap();
+        assert_eq!(staging_dir, temp_dir.path().join("Game.staging"));
+        assert_eq!(fs::read_dir(&staging_dir).unwrap().count(), 0);
+
+        // ダウンロード中も元のGameはそのまま
+        fs::write(staging_dir.join("Resonite.exe"), b"new").unwrap();
+        assert_eq!(fs::read(game_dir.join("Resonite.exe")).unwrap(), b"old");
+
+        // MODはダウンロードしたGameに移される（共有の記録は除く）
+        swap_in_staging_dir(&game_dir, &staging_dir).unwrap();
+        assert_eq!(fs::read(game_dir.join("Resonite.exe")).unwrap(), b"new");
+        assert!(game_dir.join("rml_mods").join("ModA.dll").exists());
+        assert!(!game_dir.join(game_dedup::LINK_RECORD_FILE).exists());
+        assert!(!staging_dir.exists());
+        assert!(!temp_dir.path().join("Game.old").exists());
+    }
+
+    #[test]
+    fn test_recover_interrupted_swap() {
+        let temp_dir = TempDir::new().unwrap();
+        let game_dir = temp_dir.path().join("Game");
+        let backup_dir = temp_dir.path().join("Game.old");
+
+        // 退避後に止まった場合は元のGameを戻す
+        fs::create_dir_all(&backup_dir).unwrap();
+        fs::write(backup_dir.join("Resonite.exe"), b"old").unwrap();
+        recover_interrupted_swap(&game_dir).unwrap();
+        assert_eq!(fs::read(game_dir.join("Resonite.exe")).unwrap(), b"old");
+        assert!(!backup_dir.exists());
+
+        // 入れ替え後に退避フォルダの削除だけ残った場合は削除する
+        fs::create_dir_all(&backup_dir).unwrap();
+        recover_interrupted_swap(&game_dir).unwrap();
+        assert!(game_dir.exists());
+        assert!(!backup_dir.exists());
+    }
+
+    #[test]
+    fn test_install_size_estimate() {
+        let estimate = InstallSizeEstimate::new(50 * 1024 * 1024 * 1024, Some(10 * 1024 * 1024 * 1024));
+        assert!(!estimate.sufficient);
+
+        assert!(InstallSizeEstimate::new(1024, Some(1024)).sufficient);
+        // 空き容量が取得できない場合は警告しない
+        assert!(InstallSizeEstimate::new(1024, None).sufficient);
+    }
+
+    #[test]
+    fn test_list_and_read_logs() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile_dir = temp_dir.path();
+        let manager = ResoniteInstallManager::new(temp_dir.path());
+
+        assert!(manager.list_logs(profile_dir).unwrap().is_empty());
+        assert!(manager.get_latest_log(profile_dir).is_err());
+
+        let data_logs = profile_dir.join("DataPath").join("Logs");
+        let game_logs = profile_dir.join("Game").join("Logs");
+        fs::create_dir_all(&data_logs).unwrap();
+        fs::create_dir_all(&game_logs).unwrap();
+        fs::write(game_logs.join("old.log"), b"old").unwrap();
+        fs::write(game_logs.join("notes.txt"), b"x").unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        fs::write(data_logs.join("new.log"), b"new").unwrap();
+
+        let logs = manager.list_logs(profile_dir).unwrap();
+        let names: Vec<&str> = logs.iter().map(|log| log.file_name.as_str()).collect();
+        assert_eq!(names, vec!["new.log", "old.log"]);
+
+        assert_eq!(manager.get_latest_log(profile_dir).unwrap(), "new");
+        assert_eq!(manager.get_log(profile_dir, "old.log").unwrap(), "old");
+        assert!(manager.get_log(profile_dir, "../Game/Logs/old.log").is_err());
+        assert!(manager.get_log(profile_dir, "missing.log").is_err());
     }
 }
@@ -0,0 +1,143 @@
+use crate::mod_manager::FileInstallChoice;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+/// 配置ルールを定義するファイル名
+pub const INSTALL_RULES_FILE: &str = "install_rules.json";
+
+/// 付随ライブラリを探すリポジトリ内の既定のフォルダ
+pub const DEFAULT_LIBRARY_DIRS: &[&str] = &["Libs", "libs", "Libraries", "lib"];
+
+/// ルールで指定できる配置先（`FileDestination::path`）
+const VALID_DESTINATIONS: &[&str] = &["rml_mods", "Mods", "Libraries", "RuntimeData", "skip"];
+
+/// MODごとのファイル配置ルール
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InstallRule {
+    /// 対象のMOD名（リポジトリ名。大文字小文字や記号の違いは無視する）
+    pub mod_name: String,
+    /// 付随ライブラリを探すリポジトリ内のフォルダ（未指定の場合は既定のフォルダ）
+    #[serde(default)]
+    pub library_dirs: Vec<String>,
+    /// ファイル名ごとの配置先
+    #[serde(default)]
+    pub destinations: HashMap<String, String>,
+}
+
+/// `install_rules.json` の内容
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct InstallRules {
+    #[serde(default)]
+    pub rules: Vec<InstallRule>,
+}
+
+fn normalize_mod_name(name: &str) -> String {
+    name.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_lowercase()
+}
+
+impl InstallRules {
+    /// 配置ルールを読み込む（ファイルが無い場合は空のルール）
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        if !path.exists() {
+            return Ok(InstallRules::default());
+        }
+
+        let content = fs::read_to_string(path)?;
+        let rules: InstallRules = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+
+        for rule in &rules.rules {
+            for (file_name, destination) in &rule.destinations {
+                if !VALID_DESTINATIONS.contains(&destination.as_str()) {
+                    return Err(format!(
+                        "Invalid destination '{}' for {} in rule '{}'",
+                        destination, file_name, rule.mod_name
+                    ).into());
+                }
+            }
+        }
+
+        Ok(rules)
+    }
+
+    /// MOD名に一致するルール
+    pub fn find(&self, mod_name: &str) -> Option<&InstallRule> {
+        let normalized = normalize_mod_name(mod_name);
+        self.rules.iter().find(|rule| normalize_mod_name(&rule.mod_name) == normalized)
+    }
+
+    /// 付随ライブラリを探すリポジトリ内のフォルダ
+    pub fn library_dirs(&self, mod_name: &str) -> Vec<String> {
+        match self.find(mod_name) {
+            Some(rule) if !rule.library_dirs.is_empty() => rule.library_dirs.clone(),
+            _ => DEFAULT_LIBRARY_DIRS.iter().map(|dir| dir.to_string()).collect(),
+        }
+    }
+
+    /// ルールで配置先が指定されたファイルの選択を上書きする
+    pub fn apply(&self, mod_name: &str, choices: &mut [FileInstallChoice]) {
+        let Some(rule) = self.find(mod_name) else {
+            return;
+        };
+
+        for choice in choices.iter_mut() {
+            let destination = rule.destinations.iter()
+                .find(|(file_name, _)| file_name.eq_ignore_ascii_case(&choice.asset_name))
+                .map(|(_, destination)| destination);
+            if let Some(destination) = destination {
+                choice.destination_path = destination.clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn choice(asset_name: &str, destination_path: &str) -> FileInstallChoice {
+        FileInstallChoice {
+            asset_name: asset_name.to_string(),
+            destination_path: destination_path.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_rule_by_mod_name() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(INSTALL_RULES_FILE);
+        assert!(InstallRules::load(&path).unwrap().rules.is_empty());
+
+        fs::write(&path, r#"{
+            "rules": [{
+                "mod_name": "ViveStreamingFaceTrackingForResonite",
+                "library_dirs": ["Dependencies"],
+                "destinations": { "ViveSR.dll": "Libraries", "README.txt": "skip" }
+            }]
+        }"#).unwrap();
+        let rules = InstallRules::load(&path).unwrap();
+
+        assert_eq!(rules.library_dirs("vive-streaming-face-tracking-for-resonite"), vec!["Dependencies".to_string()]);
+        assert_eq!(rules.library_dirs("OtherMod").len(), DEFAULT_LIBRARY_DIRS.len());
+
+        let mut choices = vec![
+            choice("ViveStreamingFaceTrackingForResonite.dll", "rml_mods"),
+            choice("vivesr.dll", "rml_mods"),
+        ];
+        rules.apply("ViveStreamingFaceTrackingForResonite", &mut choices);
+        assert_eq!(choices[0].destination_path, "rml_mods");
+        assert_eq!(choices[1].destination_path, "Libraries");
+    }
+
+    #[test]
+    fn test_invalid_destination_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(INSTALL_RULES_FILE);
+        fs::write(&path, r#"{"rules":[{"mod_name":"Mod","destinations":{"a.dll":"Desktop"}}]}"#).unwrap();
+        assert!(InstallRules::load(&path).is_err());
+    }
+}
@@ -0,0 +1,447 @@
+use serde::{Deserialize, Serialize};
+
+/// 起動引数のカテゴリ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ArgCategory {
+    /// 表示・デバイス
+    Display,
+    /// パス指定
+    Paths,
+    /// セッション・ワールド
+    Session,
+    /// MOD・拡張
+    Mods,
+    /// ネットワーク・クラウド
+    Network,
+    /// パフォーマンス
+    Performance,
+    /// メンテナンス
+    Maintenance,
+}
+
+/// 既知の起動引数の定義
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArgSpec {
+    pub name: String,
+    pub description: String,
+    /// 値を伴う引数か（例: `-DataPath <パス>`）
+    pub takes_value: bool,
+    /// 値の入力例
+    pub value_hint: Option<String>,
+    pub category: ArgCategory,
+}
+
+fn arg(name: &str, description: &str, value_hint: Option<&str>, category: ArgCategory) -> ArgSpec {
+    ArgSpec {
+        name: name.to_string(),
+        description: description.to_string(),
+        takes_value: value_hint.is_some(),
+        value_hint: value_hint.map(|hint| hint.to_string()),
+        category,
+    }
+}
+
+/// 既知のResonite起動引数を取得する（GUIの補完候補・ツールチップ用）
+pub fn get_known_launch_args() -> Vec<ArgSpec> {
+    use ArgCategory::*;
+
+    vec![
+        arg("-Screen", "VRを使わずデスクトップモードで起動します", None, Display),
+        arg("-Device", "使用するVRデバイスを指定します", Some("SteamVR"), Display),
+        arg("-DataPath", "データベースなどのデータ保存先を指定します", Some("%PROFILE_DIR%\\DataPath"), Paths),
+        arg("-CachePath", "キャッシュの保存先を指定します", Some("%PROFILE_DIR%\\Cache"), Paths),
+        arg("-Join", "指定したセッションに参加します", Some("<セッションURL>"), Session),
+        arg("-Open", "指定したワールドを開きます", Some("<ワールドURL>"), Session),
+        arg("-Invisible", "オンライン状態を非表示にして起動します", None, Session),
+        arg("-Kiosk", "キオスクモード（展示用）で起動します", None, Session),
+        arg("-DontAutoOpenCloudHome", "起動時にクラウドホームを自動で開きません", None, Session),
+        arg("-SkipIntroTutorial", "初回チュートリアルをスキップします", None, Session),
+        arg("-ForceIntroTutorial", "初回チュートリアルを強制的に表示します", None, Session),
+        arg("-ResetDash", "ダッシュメニューの配置を初期状態に戻します", None, Session),
+        arg("-LoadAssembly", "追加のアセンブリ（MODローダーなど）を読み込みます", Some("%GAME_DIR%\\Libraries\\ResoniteModLoader.dll"), Mods),
+        arg("-UseLocalCloud", "ローカルのクラウドサーバーに接続します（開発用）", None, Network),
+        arg("-UseStagingCloud", "ステージング環境のクラウドに接続します（開発用）", None, Network),
+        arg("-ForceLANOnly", "LAN内の接続のみに制限します", None, Network),
+        arg("-ForceRelay", "常にリレーサーバー経由で接続します", None, Network),
+        arg("-AnnounceHomeOnLAN", "ホームワールドをLANに公開します", None, Network),
+        arg("-BackgroundWorkers", "バックグラウンド処理のワーカースレッド数を指定します", Some("4"), Performance),
+        arg("-PriorityWorkers", "優先処理のワーカースレッド数を指定します", Some("2"), Performance),
+        arg("-Watchdog", "ウォッチドッグ用のファイルを指定します（フリーズ検知）", Some("<ファイルパス>"), Maintenance),
+        arg("-RepairDatabase", "起動時にローカルデータベースを修復します", None, Maintenance),
+        arg("-DeleteUnsyncedCloudRecords", "クラウドに同期されていないレコードを削除します", None, Maintenance),
+        arg("-Verbose", "詳細なログを出力します（トラブルシュート用）", None, Maintenance),
+    ]
+}
+
+/// 起動引数で使用できるプレースホルダ変数（`{profile_dir}` など）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaceholderVariable {
+    pub name: String,
+    pub description: String,
+    /// 展開後の値
+    pub value: String,
+}
+
+/// 未定義のプレースホルダの扱い
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UndefinedPlaceholderPolicy {
+    /// 展開せずそのまま残す（`{word}` を含む従来の引数をそのまま渡す）
+    #[default]
+    Keep,
+    /// 起動前にエラーにする
+    Error,
+    /// 空文字列に展開する
+    Empty,
+}
+
+/// 文字列中の `{変数名}` をプレースホルダ変数の値に展開する
+///
+/// 変数名は英数字とアンダースコアのみ。それ以外の `{...}` はそのまま残す。
+pub fn expand_placeholders(
+    value: &str,
+    variables: &[PlaceholderVariable],
+    policy: UndefinedPlaceholderPolicy,
+) -> Result<String, String> {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        let name_len = after
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(after.len());
+        if name_len == 0 || !after[name_len..].starts_with('}') {
+            result.push('{');
+            rest = after;
+            continue;
+        }
+
+        let name = &after[..name_len];
+        match variables.iter().find(|v| v.name.eq_ignore_ascii_case(name)) {
+            Some(variable) => result.push_str(&variable.value),
+            None => match policy {
+                UndefinedPlaceholderPolicy::Keep => result.push_str(&rest[start..start + name_len + 2]),
+                UndefinedPlaceholderPolicy::Empty => {}
+                UndefinedPlaceholderPolicy::Error => {
+                    return Err(format!("Undefined placeholder {{{}}} in \"{}\"", name, value))
+                }
+            },
+        }
+        rest = &after[name_len + 1..];
+    }
+
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// セッションIDまたはセッションURLを `-Join` 引数として起動引数に追加する
+///
+/// `S-` で始まるセッションIDは `ressession:///` のURLに変換し、`lnl-nat://` などの
+/// URLはそのまま使用する。既存の `-Join` / `-Open` 指定は置き換える。
+pub fn apply_join_session(args: &mut Vec<String>, session: &str) {
+    let session = session.trim();
+    let session_url = if session.contains("://") {
+        session.to_string()
+    } else {
+        format!("ressession:///{}", session)
+    };
+
+    let mut i = 0;
+    while i < args.len() {
+        if args[i].eq_ignore_ascii_case("-Join") || args[i].eq_ignore_ascii_case("-Open") {
+            let end = (i + 2).min(args.len());
+            args.drain(i..end);
+        } else {
+            i += 1;
+        }
+    }
+
+    args.push("-Join".to_string());
+    args.push(session_url);
+}
+
+/// デバッグログモードで追加する起動引数
+pub const DEBUG_LOG_ARGS: &[&str] = &["-Verbose"];
+
+/// 詳細ログ用の起動引数を追加する（既にある引数は追加しない）
+pub fn apply_debug_logging(args: &mut Vec<String>) {
+    for arg in DEBUG_LOG_ARGS {
+        if !args.iter().any(|a| a.eq_ignore_ascii_case(arg)) {
+            args.push(arg.to_string());
+        }
+    }
+}
+
+/// プロファイルで設定できる画面モード
+pub const SCREEN_MODES: &[&str] = &["windowed", "fullscreen"];
+
+/// 画面モードが有効か確認する
+pub fn validate_screen_mode(screen_mode: &str) -> Result<(), String> {
+    if SCREEN_MODES.contains(&screen_mode) {
+        Ok(())
+    } else {
+        Err(format!("Invalid screen mode '{}'. Must be one of: {}", screen_mode, SCREEN_MODES.join(", ")))
+    }
+}
+
+/// プロファイルの解像度・画面モードを起動引数に反映する（Unityの `-screen-*` 引数を使用）
+///
+/// 未設定の項目は引数を付けない。既に同じ引数がある場合は値を置き換える。
+pub fn apply_display_settings(args: &mut Vec<String>, resolution: Option<(u32, u32)>, screen_mode: Option<&str>) -> Result<(), String> {
+    if let Some((width, height)) = resolution {
+        if width == 0 || height == 0 {
+            return Err(format!("Invalid resolution {}x{}", width, height));
+        }
+        add_launch_arg(args, &format!("-screen-width {}", width))?;
+        add_launch_arg(args, &format!("-screen-height {}", height))?;
+    }
+    if let Some(screen_mode) = screen_mode {
+        validate_screen_mode(screen_mode)?;
+        let fullscreen = if screen_mode == "fullscreen" { 1 } else { 0 };
+        add_launch_arg(args, &format!("-screen-fullscreen {}", fullscreen))?;
+    }
+    Ok(())
+}
+
+/// 一回の起動だけに適用する起動引数の上書き（プロファイルの設定は変更しない）
+#[derive(Debug, Clone, Default)]
+pub struct LaunchOverrides {
+    /// 起動時に参加するセッションIDまたはセッションURL
+    pub join_session: Option<String>,
+    /// `-DataPath` の上書き
+    pub data_path: Option<String>,
+    /// `-CachePath` の上書き
+    pub cache_path: Option<String>,
+    /// 追加する引数（`-Device SteamVR` のように値付きでも可、既存の同じ引数は値を置き換える）
+    pub extra_args: Vec<String>,
+    /// 詳細ログ付きで起動する
+    pub debug_logging: bool,
+}
+
+impl LaunchOverrides {
+    /// 展開済みの起動引数に上書きを適用する
+    pub fn apply(&self, args: &mut Vec<String>) -> Result<(), String> {
+        if let Some(data_path) = &self.data_path {
+            add_launch_arg(args, &format!("-DataPath {}", data_path))?;
+        }
+        if let Some(cache_path) = &self.cache_path {
+            add_launch_arg(args, &format!("-CachePath {}", cache_path))?;
+        }
+        for arg in &self.extra_args {
+            add_launch_arg(args, arg)?;
+        }
+        if self.debug_logging {
+            apply_debug_logging(args);
+        }
+        if let Some(session) = &self.join_session {
+            apply_join_session(args, session);
+        }
+        Ok(())
+    }
+}
+
+/// 起動引数を追加する（`-DataPath <パス>` のように値付きで指定可能）
+///
+/// 既に同じ引数がある場合、値付きの引数は値を置き換え、値なしの引数は追加しない。
+/// 既知の引数は値の有無を検証する。
+pub fn add_launch_arg(args: &mut Vec<String>, arg: &str) -> Result<(), String> {
+    let arg = arg.trim();
+    let (name, value) = match arg.split_once(char::is_whitespace) {
+        Some((name, value)) => (name, Some(value.trim())),
+        None => (arg, None),
+    };
+
+    if !name.starts_with('-') || name.len() < 2 {
+        return Err(format!("Launch argument must start with '-': {}", arg));
+    }
+    if let Some(spec) = get_known_launch_args().iter().find(|spec| spec.name.eq_ignore_ascii_case(name)) {
+        if spec.takes_value && value.is_none() {
+            return Err(format!("{} requires a value (e.g. {} {})", spec.name, spec.name, spec.value_hint.as_deref().unwrap_or("")));
+        }
+        if !spec.takes_value && value.is_some() {
+            return Err(format!("{} does not take a value", spec.name));
+        }
+    }
+
+    match (args.iter().position(|a| a.eq_ignore_ascii_case(name)), value) {
+        (Some(index), Some(value)) => {
+            if args.get(index + 1).is_some_and(|next| !next.starts_with('-')) {
+                args[index + 1] = value.to_string();
+            } else {
+                args.insert(index + 1, value.to_string());
+            }
+        }
+        (Some(_), None) => {}
+        (None, value) => {
+            args.push(name.to_string());
+            if let Some(value) = value {
+                args.push(value.to_string());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 起動引数を値ごと削除する（大文字小文字は区別しない）
+///
+/// 削除した場合はtrueを返す。
+pub fn remove_launch_arg(args: &mut Vec<String>, name: &str) -> bool {
+    let name = name.trim();
+    let mut removed = false;
+    let mut i = 0;
+    while i < args.len() {
+        if args[i].eq_ignore_ascii_case(name) {
+            let has_value = args.get(i + 1).is_some_and(|next| !next.starts_with('-'));
+            let end = if has_value { i + 2 } else { i + 1 };
+            args.drain(i..end);
+            removed = true;
+        } else {
+            i += 1;
+        }
+    }
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_launch_args_are_unique() {
+        let args = get_known_launch_args();
+        let mut names: Vec<&str> = args.iter().map(|a| a.name.as_str()).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), args.len());
+    }
+
+    #[test]
+    fn test_known_launch_args_value_hint() {
+        for spec in get_known_launch_args() {
+            assert!(spec.name.starts_with('-'));
+            assert_eq!(spec.takes_value, spec.value_hint.is_some());
+        }
+    }
+
+    #[test]
+    fn test_apply_join_session() {
+        let mut args = vec!["-Screen".to_string(), "-Open".to_string(), "resrec:///U-x/R-y".to_string()];
+        apply_join_session(&mut args, "S-1234");
+        assert_eq!(args, vec!["-Screen", "-Join", "ressession:///S-1234"]);
+
+        apply_join_session(&mut args, "lnl-nat://abc/S-5678");
+        assert_eq!(args, vec!["-Screen", "-Join", "lnl-nat://abc/S-5678"]);
+    }
+
+    #[test]
+    fn test_add_and_remove_launch_arg() {
+        let mut args = vec!["-DataPath".to_string(), "%PROFILE_DIR%\\DataPath".to_string()];
+
+        add_launch_arg(&mut args, "-Screen").unwrap();
+        add_launch_arg(&mut args, "-screen").unwrap();
+        add_launch_arg(&mut args, "-DataPath D:\\Data").unwrap();
+        add_launch_arg(&mut args, "-CustomFlag").unwrap();
+        assert_eq!(args, vec!["-DataPath", "D:\\Data", "-Screen", "-CustomFlag"]);
+
+        assert!(add_launch_arg(&mut args, "Screen").is_err());
+        assert!(add_launch_arg(&mut args, "-Join").is_err());
+        assert!(add_launch_arg(&mut args, "-Screen 1").is_err());
+
+        assert!(remove_launch_arg(&mut args, "-datapath"));
+        assert!(!remove_launch_arg(&mut args, "-Invisible"));
+        assert_eq!(args, vec!["-Screen", "-CustomFlag"]);
+    }
+
+    #[test]
+    fn test_launch_overrides_apply() {
+        let mut args = vec![
+            "-DataPath".to_string(),
+            "C:\\Profiles\\main\\DataPath".to_string(),
+            "-Screen".to_string(),
+        ];
+        let overrides = LaunchOverrides {
+            join_session: Some("S-1234".to_string()),
+            data_path: Some("D:\\Temp Data".to_string()),
+            cache_path: None,
+            extra_args: vec!["-Invisible".to_string(), "-Screen".to_string()],
+            debug_logging: true,
+        };
+
+        overrides.apply(&mut args).unwrap();
+        assert_eq!(
+            args,
+            vec!["-DataPath", "D:\\Temp Data", "-Screen", "-Invisible", "-Verbose", "-Join", "ressession:///S-1234"]
+        );
+
+        let invalid = LaunchOverrides {
+            extra_args: vec!["Invisible".to_string()],
+            ..Default::default()
+        };
+        assert!(invalid.apply(&mut args).is_err());
+    }
+
+    #[test]
+    fn test_apply_debug_logging() {
+        let mut args = vec!["-Screen".to_string(), "-verbose".to_string()];
+        apply_debug_logging(&mut args);
+        assert_eq!(args, vec!["-Screen", "-verbose"]);
+
+        let mut args = vec!["-Screen".to_string()];
+        apply_debug_logging(&mut args);
+        assert_eq!(args, vec!["-Screen", "-Verbose"]);
+    }
+
+    #[test]
+    fn test_apply_display_settings() {
+        let mut args = vec!["-Screen".to_string()];
+        apply_display_settings(&mut args, None, None).unwrap();
+        assert_eq!(args, vec!["-Screen"]);
+
+        apply_display_settings(&mut args, Some((1920, 1080)), Some("windowed")).unwrap();
+        assert_eq!(
+            args,
+            vec!["-Screen", "-screen-width", "1920", "-screen-height", "1080", "-screen-fullscreen", "0"]
+        );
+
+        // 既にある引数は値を置き換える
+        apply_display_settings(&mut args, Some((1280, 720)), Some("fullscreen")).unwrap();
+        assert_eq!(
+            args,
+            vec!["-Screen", "-screen-width", "1280", "-screen-height", "720", "-screen-fullscreen", "1"]
+        );
+
+        assert!(apply_display_settings(&mut args, Some((0, 720)), None).is_err());
+        assert!(apply_display_settings(&mut args, None, Some("borderless")).is_err());
+    }
+
+    #[test]
+    fn test_expand_placeholders() {
+        let variables = vec![PlaceholderVariable {
+            name: "profile_dir".to_string(),
+            description: String::new(),
+            value: "C:\\Profiles\\main".to_string(),
+        }];
+
+        assert_eq!(
+            expand_placeholders("{profile_dir}\\Cache", &variables, UndefinedPlaceholderPolicy::Error).unwrap(),
+            "C:\\Profiles\\main\\Cache"
+        );
+        // 変数名として不正な括弧はそのまま残す
+        assert_eq!(
+            expand_placeholders("{\"a\": 1} {}", &variables, UndefinedPlaceholderPolicy::Error).unwrap(),
+            "{\"a\": 1} {}"
+        );
+
+        assert_eq!(
+            expand_placeholders("{unknown}/{profile_dir}", &variables, UndefinedPlaceholderPolicy::Keep).unwrap(),
+            "{unknown}/C:\\Profiles\\main"
+        );
+        assert!(expand_placeholders("{unknown}/x", &variables, UndefinedPlaceholderPolicy::Error).is_err());
+        assert_eq!(
+            expand_placeholders("{unknown}/x", &variables, UndefinedPlaceholderPolicy::Empty).unwrap(),
+            "/x"
+        );
+    }
+}
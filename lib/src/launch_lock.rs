@@ -0,0 +1,147 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 起動中のResoniteのPIDを記録するロックファイル（プロファイルフォルダ直下）
+pub const LOCK_FILE_NAME: &str = ".running.lock";
+
+/// 同じプロファイルのResoniteが既に起動している
+#[derive(Debug)]
+pub struct ProfileAlreadyRunningError {
+    pub profile_name: String,
+    pub pid: u32,
+}
+
+impl fmt::Display for ProfileAlreadyRunningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "プロファイル '{}' のResoniteは既に起動しています (PID {})。DataPathが競合するため二重起動はできません",
+            self.profile_name, self.pid
+        )
+    }
+}
+
+impl Error for ProfileAlreadyRunningError {}
+
+fn lock_path(profile_dir: &Path) -> PathBuf {
+    profile_dir.join(LOCK_FILE_NAME)
+}
+
+/// ロックを保持しているプロセスのPIDを返す（ロックが無い・プロセスが終了している場合はNone）
+///
+/// 記録されたPIDのプロセスが存在しない残存ロック（終了時やクラッシュ後のもの）は削除する。
+pub fn running_pid(profile_dir: &Path) -> Option<u32> {
+    let path = lock_path(profile_dir);
+    let content = fs::read_to_string(&path).ok()?;
+
+    match content.trim().parse::<u32>() {
+        Ok(pid) if is_process_alive(pid) => Some(pid),
+        _ => {
+            let _ = fs::remove_file(&path);
+            None
+        }
+    }
+}
+
+/// プロファイルのResoniteが起動中か
+pub fn is_profile_locked(profile_dir: &Path) -> bool {
+    running_pid(profile_dir).is_some()
+}
+
+/// 起動前に、同じプロファイルのResoniteが起動していないことを確認する
+pub fn ensure_not_running(profile_dir: &Path, profile_name: &str) -> Result<(), Box<dyn Error>> {
+    match running_pid(profile_dir) {
+        Some(pid) => Err(Box::new(ProfileAlreadyRunningError {
+            profile_name: profile_name.to_string(),
+            pid,
+        })),
+        None => Ok(()),
+    }
+}
+
+/// 起動したResoniteのPIDをロックファイルに記録する
+pub fn acquire(profile_dir: &Path, pid: u32) -> Result<(), Box<dyn Error>> {
+    fs::write(lock_path(profile_dir), pid.to_string())?;
+    Ok(())
+}
+
+/// ロックを解除する（ロックファイルが無い場合は何もしない）
+pub fn release(profile_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let path = lock_path(profile_dir);
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// 指定したPIDのプロセスが存在するか
+#[cfg(target_os = "windows")]
+fn is_process_alive(pid: u32) -> bool {
+    use std::os::windows::process::CommandExt;
+
+    let output = std::process::Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid), "/NH", "/FO", "CSV"])
+        .creation_flags(0x08000000) // CREATE_NO_WINDOW
+        .output();
+
+    match output {
+        // 該当プロセスが無い場合は "INFO: ..." のみが出力される
+        Ok(output) => String::from_utf8_lossy(&output.stdout).contains(&format!("\"{}\"", pid)),
+        Err(_) => false,
+    }
+}
+
+/// 指定したPIDのプロセスが存在するか
+#[cfg(not(target_os = "windows"))]
+fn is_process_alive(pid: u32) -> bool {
+    // 0やi32に収まらない値はkillにプロセスグループ等として解釈されるため除外する
+    if pid == 0 || pid > i32::MAX as u32 {
+        return false;
+    }
+
+    std::process::Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .stderr(std::process::Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_lock_held_by_running_process() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(!is_profile_locked(temp_dir.path()));
+        assert!(ensure_not_running(temp_dir.path(), "default").is_ok());
+
+        acquire(temp_dir.path(), std::process::id()).unwrap();
+        assert_eq!(running_pid(temp_dir.path()), Some(std::process::id()));
+
+        let err = ensure_not_running(temp_dir.path(), "default").unwrap_err();
+        assert!(err.is::<ProfileAlreadyRunningError>());
+
+        release(temp_dir.path()).unwrap();
+        assert!(!is_profile_locked(temp_dir.path()));
+    }
+
+    #[test]
+    fn test_stale_lock_is_removed() {
+        let temp_dir = TempDir::new().unwrap();
+
+        // 存在しないPID
+        acquire(temp_dir.path(), 0x3FFF_FFFF).unwrap();
+        assert!(!is_profile_locked(temp_dir.path()));
+        assert!(!temp_dir.path().join(LOCK_FILE_NAME).exists());
+
+        // 壊れたロックファイル
+        fs::write(temp_dir.path().join(LOCK_FILE_NAME), "not a pid").unwrap();
+        assert!(ensure_not_running(temp_dir.path(), "default").is_ok());
+        assert!(!temp_dir.path().join(LOCK_FILE_NAME).exists());
+    }
+}
@@ -0,0 +1,347 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::bepis_loader::BepisLoader;
+use crate::mod_loader_type::ModLoaderType;
+use crate::mod_manager::ModManager;
+
+/// 起動完了とみなすResoniteログの行（小文字で比較）
+const STARTUP_COMPLETE_MARKERS: &[&str] = &[
+    "engine initialized",
+    "initialization complete",
+];
+
+/// 保持する起動記録の最大件数
+const MAX_LAUNCH_RECORDS: usize = 100;
+
+/// 起動時に有効だったMOD
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LaunchModEntry {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+/// 1回分の起動記録
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchRecord {
+    pub launched_at: String,
+    /// 起動開始から起動完了ログ検知までの秒数（検知できなかった場合はNone）
+    pub startup_seconds: Option<f64>,
+    pub completed: bool,
+    /// 起動完了前にプロセスが終了した（起動直後のクラッシュ）
+    #[serde(default)]
+    pub crashed: bool,
+    /// MODを無効にしたセーフモードでの再起動を提案すべきか
+    #[serde(default)]
+    pub suggest_safe_mode: bool,
+    pub mod_loader_type: Option<ModLoaderType>,
+    pub mods: Vec<LaunchModEntry>,
+}
+
+/// MOD構成の変化と、その前後の起動時間
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModChangeImpact {
+    pub launched_at: String,
+    pub added_mods: Vec<String>,
+    pub removed_mods: Vec<String>,
+    pub previous_startup_seconds: Option<f64>,
+    pub startup_seconds: Option<f64>,
+    pub delta_seconds: Option<f64>,
+}
+
+/// GUIで可視化するための起動統計
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchStats {
+    pub records: Vec<LaunchRecord>,
+    pub average_startup_seconds: Option<f64>,
+    pub last_startup_seconds: Option<f64>,
+    pub mod_change_impacts: Vec<ModChangeImpact>,
+}
+
+/// プロファイルごとの起動記録を管理する
+pub struct LaunchStatsManager {
+    history_file: PathBuf,
+}
+
+impl LaunchStatsManager {
+    pub fn new(profile_dir: &Path) -> Self {
+        LaunchStatsManager {
+            history_file: profile_dir.join("launch_history.json"),
+        }
+    }
+
+    /// 起動記録を読み込む
+    pub fn load_records(&self) -> Vec<LaunchRecord> {
+        if !self.history_file.exists() {
+            return Vec::new();
+        }
+
+        fs::read_to_string(&self.history_file)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// 起動記録を追加して保存する
+    pub fn add_record(&self, record: LaunchRecord) -> Result<(), Box<dyn Error>> {
+        let mut records = self.load_records();
+        records.push(record);
+
+        if records.len() > MAX_LAUNCH_RECORDS {
+            let excess = records.len() - MAX_LAUNCH_RECORDS;
+            records.drain(0..excess);
+        }
+
+        let content = serde_json::to_string_pretty(&records)?;
+        fs::write(&self.history_file, content)?;
+        Ok(())
+    }
+
+    /// 起動統計を取得する
+    pub fn get_stats(&self) -> LaunchStats {
+        build_stats(self.load_records())
+    }
+}
+
+/// 起動記録から統計を組み立てる
+pub fn build_stats(records: Vec<LaunchRecord>) -> LaunchStats {
+    let durations: Vec<f64> = records.iter().filter_map(|r| r.startup_seconds).collect();
+    let average_startup_seconds = if durations.is_empty() {
+        None
+    } else {
+        Some(durations.iter().sum::<f64>() / durations.len() as f64)
+    };
+    let last_startup_seconds = records.iter().rev().find_map(|r| r.startup_seconds);
+
+    let mut mod_change_impacts = Vec::new();
+    for pair in records.windows(2) {
+        let (previous, current) = (&pair[0], &pair[1]);
+        let previous_mods = mod_key_map(&previous.mods);
+        let current_mods = mod_key_map(&current.mods);
+
+        let added_mods: Vec<String> = current
+            .mods
+            .iter()
+            .filter(|m| previous_mods.get(&m.name) != Some(&m.version))
+            .map(format_mod_entry)
+            .collect();
+        let removed_mods: Vec<String> = previous
+            .mods
+            .iter()
+            .filter(|m| current_mods.get(&m.name) != Some(&m.version))
+            .map(format_mod_entry)
+            .collect();
+
+        if added_mods.is_empty() && removed_mods.is_empty() {
+            continue;
+        }
+
+        let delta_seconds = match (previous.startup_seconds, current.startup_seconds) {
+            (Some(before), Some(after)) => Some(after - before),
+            _ => None,
+        };
+
+        mod_change_impacts.push(ModChangeImpact {
+            launched_at: current.launched_at.clone(),
+            added_mods,
+            removed_mods,
+            previous_startup_seconds: previous.startup_seconds,
+            startup_seconds: current.startup_seconds,
+            delta_seconds,
+        });
+    }
+
+    LaunchStats {
+        records,
+        average_startup_seconds,
+        last_startup_seconds,
+        mod_change_impacts,
+    }
+}
+
+fn mod_key_map(mods: &[LaunchModEntry]) -> HashMap<String, Option<String>> {
+    mods.iter().map(|m| (m.name.clone(), m.version.clone())).collect()
+}
+
+fn format_mod_entry(entry: &LaunchModEntry) -> String {
+    match &entry.version {
+        Some(version) => format!("{} {}", entry.name, version),
+        None => entry.name.clone(),
+    }
+}
+
+/// 現在有効なMOD構成を取得する
+pub fn collect_enabled_mods(profile_dir: &Path, mod_loader_type: Option<&ModLoaderType>) -> Vec<LaunchModEntry> {
+    let mut mods: Vec<LaunchModEntry> = match mod_loader_type {
+        Some(ModLoaderType::BepisLoader) => BepisLoader::new(profile_dir.to_path_buf())
+            .get_installed_mods()
+            .into_iter()
+            .map(|m| LaunchModEntry {
+                name: m.full_name,
+                version: Some(m.version),
+            })
+            .collect(),
+        _ => ModManager::new(profile_dir.to_path_buf())
+            .get_installed_mods()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|m| m.enabled.unwrap_or(true))
+            .map(|m| LaunchModEntry {
+                name: m.name,
+                version: Some(m.installed_version),
+            })
+            .collect(),
+    };
+
+    mods.sort_by(|a, b| a.name.cmp(&b.name));
+    mods
+}
+
+/// 起動完了のログ行が出力されるまで待機し、経過時間を返す
+///
+/// `is_running` がfalseを返した場合（プロセス終了）やタイムアウト時はNoneを返す。
+pub fn wait_for_startup_complete<F>(
+    logs_dir: &Path,
+    launched_at: SystemTime,
+    timeout: Duration,
+    mut is_running: F,
+) -> Option<Duration>
+where
+    F: FnMut() -> bool,
+{
+    let start = Instant::now();
+
+    while start.elapsed() < timeout {
+        if let Some(log_file) = find_log_since(logs_dir, launched_at) {
+            if let Ok(bytes) = fs::read(&log_file) {
+                let content = String::from_utf8_lossy(&bytes).to_lowercase();
+                if STARTUP_COMPLETE_MARKERS.iter().any(|marker| content.contains(marker)) {
+                    return Some(start.elapsed());
+                }
+            }
+        }
+
+        if !is_running() {
+            return None;
+        }
+
+        std::thread::sleep(Duration::from_millis(500));
+    }
+
+    None
+}
+
+/// 起動後に作成・更新された最新のログファイルを探す
+fn find_log_since(logs_dir: &Path, since: SystemTime) -> Option<PathBuf> {
+    let entries = fs::read_dir(logs_dir).ok()?;
+
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().map(|ext| ext == "log").unwrap_or(false))
+        .filter_map(|e| {
+            let modified = e.metadata().and_then(|m| m.modified()).ok()?;
+            if modified >= since {
+                Some((modified, e.path()))
+            } else {
+                None
+            }
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn record(startup: Option<f64>, mods: &[(&str, &str)]) -> LaunchRecord {
+        LaunchRecord {
+            launched_at: "2024-01-01T00:00:00+00:00".to_string(),
+            startup_seconds: startup,
+            completed: startup.is_some(),
+            crashed: false,
+            suggest_safe_mode: false,
+            mod_loader_type: Some(ModLoaderType::ResoniteModLoader),
+            mods: mods
+                .iter()
+                .map(|(name, version)| LaunchModEntry {
+                    name: name.to_string(),
+                    version: Some(version.to_string()),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_build_stats_average_and_last() {
+        let stats = build_stats(vec![
+            record(Some(10.0), &[]),
+            record(None, &[]),
+            record(Some(20.0), &[]),
+        ]);
+
+        assert_eq!(stats.average_startup_seconds, Some(15.0));
+        assert_eq!(stats.last_startup_seconds, Some(20.0));
+        assert!(stats.mod_change_impacts.is_empty());
+    }
+
+    #[test]
+    fn test_build_stats_detects_mod_changes() {
+        let stats = build_stats(vec![
+            record(Some(10.0), &[("ModA", "1.0.0")]),
+            record(Some(14.0), &[("ModA", "1.0.0"), ("ModB", "2.0.0")]),
+            record(Some(13.0), &[("ModA", "1.1.0"), ("ModB", "2.0.0")]),
+        ]);
+
+        assert_eq!(stats.mod_change_impacts.len(), 2);
+
+        let first = &stats.mod_change_impacts[0];
+        assert_eq!(first.added_mods, vec!["ModB 2.0.0".to_string()]);
+        assert!(first.removed_mods.is_empty());
+        assert_eq!(first.delta_seconds, Some(4.0));
+
+        let second = &stats.mod_change_impacts[1];
+        assert_eq!(second.added_mods, vec!["ModA 1.1.0".to_string()]);
+        assert_eq!(second.removed_mods, vec!["ModA 1.0.0".to_string()]);
+        assert_eq!(second.delta_seconds, Some(-1.0));
+    }
+
+    #[test]
+    fn test_add_record_persists_and_limits() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = LaunchStatsManager::new(temp_dir.path());
+
+        for _ in 0..(MAX_LAUNCH_RECORDS + 5) {
+            manager.add_record(record(Some(1.0), &[])).unwrap();
+        }
+
+        assert_eq!(manager.load_records().len(), MAX_LAUNCH_RECORDS);
+    }
+
+    #[test]
+    fn test_wait_for_startup_complete_detects_marker() {
+        let temp_dir = TempDir::new().unwrap();
+        let launched_at = SystemTime::now() - Duration::from_secs(1);
+        fs::write(
+            temp_dir.path().join("test.log"),
+            "12:00:00 AM.000 ( 60 FPS)\tEngine Initialized\n",
+        )
+        .unwrap();
+
+        let result = wait_for_startup_complete(temp_dir.path(), launched_at, Duration::from_secs(5), || true);
+        assert!(result.is_some());
+    }
+
+    #[test]
+    fn test_wait_for_startup_complete_stops_when_process_exits() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let result = wait_for_startup_complete(temp_dir.path(), SystemTime::now(), Duration::from_secs(5), || false);
+        assert!(result.is_none());
+    }
+}
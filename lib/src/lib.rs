@@ -3,27 +3,55 @@
 // このライブラリは、Resoniteのインストール、更新、プロファイル管理のための
 // 機能を提供します。
 
+/// 進捗ログを出力する（`utils::set_log_to_stderr` が有効なら標準エラー出力へ）
+macro_rules! log_println {
+    ($($arg:tt)*) => {
+        if $crate::utils::log_to_stderr() {
+            eprintln!($($arg)*);
+        } else {
+            println!($($arg)*);
+        }
+    };
+}
+
 #[cfg(test)]
 pub mod test_utils;
 
 pub mod profile;
+pub mod profile_template;
 pub mod install;
+pub mod launch_stats;
+pub mod launch_args;
+pub mod launch_lock;
+pub mod preflight;
 pub mod depotdownloader;
 pub mod mod_loader;
 pub mod mod_loader_type;
 pub mod monkey_loader;
 pub mod mod_manager;
+pub mod mod_install_queue;
+pub mod install_rules;
+pub mod download_limiter;
+pub mod game_dedup;
+pub mod loader_migration;
+pub mod mod_history;
+pub mod crash_report;
+pub mod webhook;
+pub mod mod_config;
 pub mod thunderstore;
 pub mod bepis_loader;
 pub mod utils;
+pub mod version_history;
 
 // 必要に応じて公開APIをエクスポートする
 pub use profile::Profile;
 pub use install::ResoniteInstall;
+pub use launch_stats::{LaunchRecord, LaunchStats};
 pub use depotdownloader::DepotDownloader;
 pub use mod_loader::{ModLoader, ModLoaderInfo};
 pub use mod_loader_type::{ModLoaderType, ModSource};
 pub use monkey_loader::{MonkeyLoader, MonkeyLoaderInfo};
-pub use mod_manager::{ModManager, ModInfo, InstalledMod, GitHubRelease};
+pub use mod_manager::{ModManager, ModManagerConfig, ModManagerError, ModInfo, InstalledMod, InstalledModSource, GitHubRelease, RateLimitInfo};
+pub use download_limiter::DownloadLimiter;
 pub use thunderstore::{ThunderstoreClient, ThunderstorePackage, ThunderstoreVersion, ThunderstoreCategory};
 pub use bepis_loader::{BepisLoader, BepisLoaderStatus, BepisLoaderInfo, InstalledBepisMod};
@@ -0,0 +1,327 @@
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::launch_lock;
+use crate::mod_loader::ModLoader;
+use crate::mod_loader_type::ModLoaderType;
+use crate::mod_manager::{InstalledMod, ModInfo, ModManager};
+use crate::monkey_loader::MonkeyLoader;
+use crate::profile::ProfileManager;
+use crate::utils;
+
+/// MonkeyLoaderのアンインストールで消えるMODを退避するフォルダ（プロファイルフォルダからの相対パス）
+const MIGRATION_BACKUP_DIR: &str = "loader_migration_backup";
+
+/// 移行しなかった・できなかったMOD
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationIssue {
+    pub name: String,
+    pub reason: String,
+}
+
+/// MODローダーの移行結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationReport {
+    pub from: ModLoaderType,
+    pub to: ModLoaderType,
+    /// 新しいMODローダーのインストール結果
+    pub loader_message: String,
+    /// 新しいローダー向けの版に切り替えたMOD
+    pub migrated_mods: Vec<String>,
+    /// 新しいローダー向けの版が無いためそのままにしたMOD
+    pub skipped_mods: Vec<MigrationIssue>,
+    /// 切り替えに失敗したMOD
+    pub failed_mods: Vec<MigrationIssue>,
+    /// 旧ローダーのMODフォルダを退避した場所（退避した場合のみ）
+    ///
+    /// 移行しなかったMODのファイルはここに残り、インストール済みMOD一覧からは取り除かれる。
+    pub backup_dir: Option<PathBuf>,
+}
+
+/// プロファイルのMODローダーをResoniteModLoaderとMonkeyLoaderの間で移行する
+pub struct LoaderMigrator {
+    profile_manager: ProfileManager,
+    create_mod_manager: fn(PathBuf) -> ModManager,
+}
+
+fn loader_name(loader: ModLoaderType) -> &'static str {
+    match loader {
+        ModLoaderType::ResoniteModLoader => "ResoniteModLoader",
+        ModLoaderType::MonkeyLoader => "MonkeyLoader",
+        ModLoaderType::BepisLoader => "BepisLoader",
+    }
+}
+
+/// インストール済みMODに対応する、移行先ローダー向けのマニフェストのMOD
+fn find_target_mod<'a>(installed_mod: &InstalledMod, target_mods: &'a [ModInfo]) -> Option<&'a ModInfo> {
+    target_mods
+        .iter()
+        .find(|m| utils::same_source_location(&m.source_location, &installed_mod.source_location))
+        .or_else(|| target_mods.iter().find(|m| m.name.eq_ignore_ascii_case(&installed_mod.name)))
+}
+
+/// ローダーのMODフォルダ（rml_mods・MonkeyLoader/Mods）にあるMODか
+///
+/// 同梱ライブラリとして `Libraries` 等に配置したファイルは移行の対象にしない。
+fn is_loader_mod(installed_mod: &InstalledMod, game_dir: &Path) -> bool {
+    installed_mod.dll_path.starts_with(game_dir.join("rml_mods"))
+        || installed_mod.dll_path.starts_with(game_dir.join("MonkeyLoader").join("Mods"))
+}
+
+impl LoaderMigrator {
+    pub fn new(profile_manager: ProfileManager) -> Self {
+        LoaderMigrator {
+            profile_manager,
+            create_mod_manager: ModManager::new,
+        }
+    }
+
+    /// MODの再取得に使うModManagerの作成方法を指定する（キャッシュの共有など）
+    pub fn with_mod_manager_factory(mut self, create_mod_manager: fn(PathBuf) -> ModManager) -> Self {
+        self.create_mod_manager = create_mod_manager;
+        self
+    }
+
+    /// プロファイルのMODローダーを `target` に入れ替え、MODを新しいローダー向けの版に切り替える
+    ///
+    /// 移行先のローダー向けのマニフェストに載っているMODは対応版を再取得し、
+    /// 載っていないMODはそのまま残してレポートに記録する。起動引数も新しいローダーに合わせて調整する。
+    pub async fn migrate_loader(&self, profile_name: &str, target: ModLoaderType) -> Result<MigrationReport, Box<dyn Error + Send + Sync>> {
+        if target == ModLoaderType::BepisLoader {
+            return Err("Loader migration supports only ResoniteModLoader and MonkeyLoader".into());
+        }
+
+        let mut profile = self.profile_manager.get_profile(profile_name).map_err(|e| e.to_string())?;
+        let profile_dir = self.profile_manager.get_profile_dir(profile_name);
+        let game_dir = profile.get_game_dir(&profile_dir);
+        if !game_dir.exists() {
+            return Err(format!("Game is not installed in profile '{}'", profile_name).into());
+        }
+        launch_lock::ensure_not_running(&profile_dir, profile_name).map_err(|e| e.to_string())?;
+
+        let from = profile
+            .mod_loader_type
+            .or_else(|| self.detect_loader(&game_dir))
+            .ok_or("No mod loader installed")?;
+        if from == target {
+            return Err(format!("Profile '{}' already uses {}", profile_name, loader_name(target)).into());
+        }
+        if from == ModLoaderType::BepisLoader {
+            return Err("Loader migration supports only ResoniteModLoader and MonkeyLoader".into());
+        }
+
+        // ローダーを入れ替える前に移行先のマニフェストを取得しておく（失敗したら何も変更しない）
+        let mod_manager = (self.create_mod_manager)(profile_dir.clone());
+        let target_mods = mod_manager.fetch_loader_manifest(target).await?;
+        let loader_mods: Vec<InstalledMod> = mod_manager
+            .get_installed_mods()?
+            .into_iter()
+            .filter(|m| is_loader_mod(m, &game_dir))
+            .collect();
+
+        // MonkeyLoaderのアンインストールはMODフォルダごと削除するため退避する
+        let backup_dir = if from == ModLoaderType::MonkeyLoader {
+            self.backup_monkey_loader_mods(&game_dir, &profile_dir)?
+        } else {
+            None
+        };
+
+        let loader_message = self.swap_loader(&game_dir, from, target).await?;
+
+        if from == ModLoaderType::ResoniteModLoader {
+            ModLoader::new(game_dir.clone()).remove_launch_args(&mut profile.args);
+        }
+        match target {
+            ModLoaderType::ResoniteModLoader => ModLoader::new(game_dir.clone()).add_launch_args(&mut profile.args),
+            _ => MonkeyLoader::new(game_dir.clone()).remove_disable_args(&mut profile.args),
+        }
+        profile.mod_loader_type = Some(target);
+        self.profile_manager.update_profile(&profile).map_err(|e| e.to_string())?;
+
+        let mut report = MigrationReport {
+            from,
+            to: target,
+            loader_message,
+            migrated_mods: Vec::new(),
+            skipped_mods: Vec::new(),
+            failed_mods: Vec::new(),
+            backup_dir,
+        };
+
+        // 退避したMODフォルダにあったMODは、移行しなかった場合もファイルが退避先に移っている
+        let monkey_mods_dir = game_dir.join("MonkeyLoader").join("Mods");
+        let was_backed_up = |installed_mod: &InstalledMod| {
+            report.backup_dir.is_some() && installed_mod.dll_path.starts_with(&monkey_mods_dir)
+        };
+        let mut moved_to_backup = Vec::new();
+
+        for installed_mod in loader_mods {
+            let Some(mod_info) = find_target_mod(&installed_mod, &target_mods) else {
+                let mut reason = format!("{} 向けの版がマニフェストにありません", loader_name(target));
+                if was_backed_up(&installed_mod) {
+                    reason.push_str("（ファイルは退避先に移動しました）");
+                    moved_to_backup.push(installed_mod.name.clone());
+                }
+                report.skipped_mods.push(MigrationIssue {
+                    name: installed_mod.name.clone(),
+                    reason,
+                });
+                continue;
+            };
+
+            match mod_manager.install_mod_from_cache(mod_info, None, Some(loader_name(target))).await {
+                Ok(new_mod) => {
+                    // 旧ローダー向けのファイルが別の場所に残っていれば削除する
+                    if new_mod.dll_path != installed_mod.dll_path {
+                        let removed = if new_mod.name != installed_mod.name {
+                            mod_manager.uninstall_mod(&installed_mod.name)
+                        } else if installed_mod.dll_path.exists() {
+                            fs::remove_file(&installed_mod.dll_path).map_err(|e| e.into())
+                        } else {
+                            Ok(())
+                        };
+                        if let Err(e) = removed {
+                            eprintln!("Failed to remove old file of {}: {}", installed_mod.name, e);
+                        }
+                    }
+                    report.migrated_mods.push(new_mod.name);
+                }
+                Err(e) => {
+                    let mut reason = e.to_string();
+                    if was_backed_up(&installed_mod) {
+                        reason.push_str("（ファイルは退避先に移動しました）");
+                        moved_to_backup.push(installed_mod.name.clone());
+                    }
+                    report.failed_mods.push(MigrationIssue {
+                        name: installed_mod.name.clone(),
+                        reason,
+                    });
+                }
+            }
+        }
+
+        // 退避先に移動したMODのエントリが古いパスのまま残らないようにする
+        if !moved_to_backup.is_empty() {
+            mod_manager.forget_installed_mods(&moved_to_backup)?;
+        }
+
+        log_println!(
+            "Migrated profile '{}' from {} to {}: {} migrated, {} skipped, {} failed",
+            profile_name,
+            loader_name(from),
+            loader_name(target),
+            report.migrated_mods.len(),
+            report.skipped_mods.len(),
+            report.failed_mods.len()
+        );
+        Ok(report)
+    }
+
+    /// プロファイルに記録されていない場合に、インストールされているローダーを検出する
+    fn detect_loader(&self, game_dir: &Path) -> Option<ModLoaderType> {
+        if ModLoader::new(game_dir.to_path_buf()).get_status().is_ok_and(|s| s.installed) {
+            Some(ModLoaderType::ResoniteModLoader)
+        } else if MonkeyLoader::new(game_dir.to_path_buf()).get_status().is_ok_and(|s| s.installed) {
+            Some(ModLoaderType::MonkeyLoader)
+        } else {
+            None
+        }
+    }
+
+    /// `MonkeyLoader/Mods` をプロファイルフォルダの退避先に移動する（MODが無ければNone）
+    fn backup_monkey_loader_mods(&self, game_dir: &Path, profile_dir: &Path) -> Result<Option<PathBuf>, Box<dyn Error + Send + Sync>> {
+        let mods_dir = game_dir.join("MonkeyLoader").join("Mods");
+        if !mods_dir.exists() || fs::read_dir(&mods_dir)?.next().is_none() {
+            return Ok(None);
+        }
+
+        let backup_dir = profile_dir
+            .join(MIGRATION_BACKUP_DIR)
+            .join(format!("MonkeyLoader_Mods_{}", chrono::Local::now().format("%Y%m%d_%H%M%S")));
+        if let Some(parent) = backup_dir.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::rename(&mods_dir, &backup_dir)?;
+        Ok(Some(backup_dir))
+    }
+
+    /// 旧ローダーをアンインストールして新しいローダーをインストールする
+    async fn swap_loader(&self, game_dir: &Path, from: ModLoaderType, target: ModLoaderType) -> Result<String, Box<dyn Error + Send + Sync>> {
+        match from {
+            ModLoaderType::ResoniteModLoader => {
+                ModLoader::new(game_dir.to_path_buf()).uninstall()?;
+            }
+            ModLoaderType::MonkeyLoader => {
+                MonkeyLoader::new(game_dir.to_path_buf()).uninstall()?;
+            }
+            ModLoaderType::BepisLoader => {}
+        }
+
+        match target {
+            ModLoaderType::ResoniteModLoader => {
+                let mod_loader = ModLoader::new(game_dir.to_path_buf());
+                let message = mod_loader.install().await?;
+                // Harmony依存のMODが動くよう0Harmony.dllも配置する
+                Ok(match mod_loader.ensure_harmony().await {
+                    Ok(()) => format!("{} (Harmony: OK)", message),
+                    Err(e) => format!("{} (Harmony: 見つかりません - {})", message, e),
+                })
+            }
+            _ => Ok(MonkeyLoader::new(game_dir.to_path_buf()).install().await?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn installed(name: &str, source_location: &str, dll_path: PathBuf) -> InstalledMod {
+        InstalledMod {
+            name: name.to_string(),
+            description: String::new(),
+            source_location: source_location.to_string(),
+            installed_version: "1.0.0".to_string(),
+            installed_date: String::new(),
+            dll_path,
+            mod_loader_type: None,
+            file_format: Some("dll".to_string()),
+            enabled: Some(true),
+            hot_reload_capable: None,
+            hot_reload_override: None,
+            load_priority: None,
+        }
+    }
+
+    fn manifest_mod(name: &str, source_location: &str) -> ModInfo {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "description": "",
+            "source_location": source_location,
+            "author": "author",
+            "releases": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_find_target_mod_and_loader_mods() {
+        let game_dir = PathBuf::from("profiles/main/Game");
+        let target_mods = vec![
+            manifest_mod("ResoniteIkCulling", "https://github.com/Example/ResoniteIkCulling"),
+            manifest_mod("Other", "https://github.com/Example/Other"),
+        ];
+
+        let by_source = installed("IkCulling", "https://github.com/example/ResoniteIkCulling/", game_dir.join("rml_mods").join("IkCulling.dll"));
+        assert_eq!(find_target_mod(&by_source, &target_mods).unwrap().name, "ResoniteIkCulling");
+        assert!(is_loader_mod(&by_source, &game_dir));
+
+        let missing = installed("RmlOnly", "https://github.com/Example/RmlOnly", game_dir.join("rml_mods").join("RmlOnly.dll"));
+        assert!(find_target_mod(&missing, &target_mods).is_none());
+
+        let library = installed("SomeLib", "https://github.com/Example/Other", game_dir.join("Resonite_Data").join("Managed").join("SomeLib.dll"));
+        assert!(!is_loader_mod(&library, &game_dir));
+    }
+}
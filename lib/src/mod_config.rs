@@ -0,0 +1,474 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 設定項目の入力タイプ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConfigFieldType {
+    Boolean,
+    Integer,
+    Number,
+    String,
+    /// 選択肢から選ぶ（enum）
+    Select,
+}
+
+/// GUIのフォームに表示する設定項目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigField {
+    pub key: String,
+    pub label: String,
+    pub description: Option<String>,
+    pub field_type: ConfigFieldType,
+    pub default_value: Option<Value>,
+    pub current_value: Option<Value>,
+    #[serde(default)]
+    pub options: Vec<Value>,
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+}
+
+/// MOD設定のスキーマ
+///
+/// `has_schema` がfalseの場合、GUIは `raw_json` を生JSONとして編集する。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigSchema {
+    pub mod_name: String,
+    pub config_path: Option<PathBuf>,
+    pub has_schema: bool,
+    pub fields: Vec<ConfigField>,
+    pub raw_json: Option<String>,
+}
+
+/// MOD設定ファイルを探すディレクトリ（RML、MonkeyLoaderの順）
+pub fn config_dirs(game_dir: &Path) -> Vec<PathBuf> {
+    vec![
+        game_dir.join("rml_config"),
+        game_dir.join("MonkeyLoader").join("Configs"),
+    ]
+}
+
+/// MOD名から設定ファイルとスキーマファイルを読み込み、ConfigSchemaを組み立てる
+///
+/// スキーマは設定ファイルと同じディレクトリの `<MOD名>.schema.json`（JSON Schemaのサブセット）から読み込む。
+pub fn load_config_schema(game_dir: &Path, mod_name: &str) -> Result<ConfigSchema, Box<dyn Error + Send + Sync>> {
+    let mut config_path = None;
+    let mut schema_path = None;
+
+    for dir in config_dirs(game_dir) {
+        let candidate = dir.join(format!("{}.json", mod_name));
+        let schema_candidate = dir.join(format!("{}.schema.json", mod_name));
+        if candidate.exists() || schema_candidate.exists() {
+            config_path = Some(candidate).filter(|p| p.exists());
+            schema_path = Some(schema_candidate).filter(|p| p.exists());
+            break;
+        }
+    }
+
+    let raw_json = match &config_path {
+        Some(path) => Some(fs::read_to_string(path)?),
+        None => None,
+    };
+    let current_values = match &raw_json {
+        Some(content) => extract_values(&serde_json::from_str::<Value>(content)?),
+        None => Map::new(),
+    };
+
+    let fields = match &schema_path {
+        Some(path) => {
+            let schema: Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+            parse_schema_fields(&schema, &current_values)
+        }
+        None => Vec::new(),
+    };
+
+    Ok(ConfigSchema {
+        mod_name: mod_name.to_string(),
+        config_path,
+        has_schema: !fields.is_empty(),
+        fields,
+        raw_json,
+    })
+}
+
+/// 設定ファイルの値を取り出す（RMLの `values` オブジェクトにも対応）
+fn extract_values(config: &Value) -> Map<String, Value> {
+    match config.get("values").and_then(|v| v.as_object()) {
+        Some(values) => values.clone(),
+        None => config.as_object().cloned().unwrap_or_default(),
+    }
+}
+
+/// JSON Schemaの `properties` から設定項目を生成する
+pub fn parse_schema_fields(schema: &Value, current_values: &Map<String, Value>) -> Vec<ConfigField> {
+    let Some(properties) = schema.get("properties").and_then(|p| p.as_object()) else {
+        return Vec::new();
+    };
+
+    properties
+        .iter()
+        .filter_map(|(key, property)| {
+            let options: Vec<Value> = property
+                .get("enum")
+                .and_then(|e| e.as_array())
+                .cloned()
+                .unwrap_or_default();
+
+            let field_type = if !options.is_empty() {
+                ConfigFieldType::Select
+            } else {
+                match property.get("type").and_then(|t| t.as_str())? {
+                    "boolean" => ConfigFieldType::Boolean,
+                    "integer" => ConfigFieldType::Integer,
+                    "number" => ConfigFieldType::Number,
+                    "string" => ConfigFieldType::String,
+                    // object/arrayなどフォームで扱えない型は生JSON編集に任せる
+                    _ => return None,
+                }
+            };
+
+            Some(ConfigField {
+                key: key.clone(),
+                label: property
+                    .get("title")
+                    .and_then(|t| t.as_str())
+                    .unwrap_or(key.as_str())
+                    .to_string(),
+                description: property
+                    .get("description")
+                    .and_then(|d| d.as_str())
+                    .map(|d| d.to_string()),
+                field_type,
+                default_value: property.get("default").cloned(),
+                current_value: current_values.get(key).cloned(),
+                options,
+                minimum: property.get("minimum").and_then(|m| m.as_f64()),
+                maximum: property.get("maximum").and_then(|m| m.as_f64()),
+            })
+        })
+        .collect()
+}
+
+/// 設定ファイルとインストール済みMODの対応付け結果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModConfigMap {
+    /// MOD名ごとの設定ファイル（設定ファイルが無いMODは空）
+    pub mods: HashMap<String, Vec<PathBuf>>,
+    /// どのMODのものか判別できなかった設定ファイル
+    pub unmatched: Vec<PathBuf>,
+}
+
+/// `config_dirs` 以下の設定ファイルをサブフォルダも含めて列挙する
+pub fn list_config_files(game_dir: &Path) -> Vec<PathBuf> {
+    fn collect(dir: &Path, files: &mut Vec<PathBuf>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.is_dir() {
+                collect(&path, files);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+
+    let mut files = Vec::new();
+    for dir in config_dirs(game_dir) {
+        collect(&dir, &mut files);
+    }
+    files.sort();
+    files
+}
+
+/// MOD名・ファイル名を比較できるよう英数字だけを小文字で残す
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+/// 前方一致で対応付けるMOD名の最小文字数（短い名前の誤検出を防ぐ）
+const MIN_PREFIX_MATCH_LEN: usize = 4;
+
+/// 設定ファイルをMODに対応付ける
+///
+/// `mods` は（MOD名, DLLのファイル名）の組。設定ファイル名（拡張子と `.schema` を除く）について、
+/// 1. MOD名またはDLL名と一致する
+/// 2. `Author.ModName` 形式の最後の要素が一致する
+/// 3. MOD名またはDLL名で始まる（複数あれば最も長く一致するもの）
+///
+/// の順に判定し、どれにも当てはまらないファイルは `unmatched` に入れる。
+pub fn match_config_files(mods: &[(String, String)], config_files: &[PathBuf]) -> ModConfigMap {
+    let keys: Vec<(&str, Vec<String>)> = mods
+        .iter()
+        .map(|(name, dll_name)| {
+            let mut keys = vec![normalize_name(name), normalize_name(dll_name)];
+            keys.retain(|k| !k.is_empty());
+            keys.dedup();
+            (name.as_str(), keys)
+        })
+        .collect();
+
+    let mut result = ModConfigMap {
+        mods: mods.iter().map(|(name, _)| (name.clone(), Vec::new())).collect(),
+        unmatched: Vec::new(),
+    };
+
+    for file in config_files {
+        let stem = file.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+        let stem = stem.strip_suffix(".schema").unwrap_or(&stem);
+        let full = normalize_name(stem);
+        let last = normalize_name(stem.rsplit('.').next().unwrap_or(stem));
+
+        let matched = keys
+            .iter()
+            .find(|(_, keys)| keys.contains(&full))
+            .or_else(|| keys.iter().find(|(_, keys)| keys.contains(&last)))
+            .map(|(name, _)| *name)
+            .or_else(|| {
+                keys.iter()
+                    .flat_map(|(name, keys)| keys.iter().map(move |k| (*name, k)))
+                    .filter(|(_, k)| k.len() >= MIN_PREFIX_MATCH_LEN && full.starts_with(k.as_str()))
+                    .max_by_key(|(_, k)| k.len())
+                    .map(|(name, _)| name)
+            });
+
+        match matched {
+            Some(name) => result.mods.entry(name.to_string()).or_default().push(file.clone()),
+            None => result.unmatched.push(file.clone()),
+        }
+    }
+
+    result
+}
+
+/// RMLのMOD設定ファイルを置くディレクトリ（Gameフォルダからの相対パス）
+pub const RML_CONFIG_DIR: &str = "rml_config";
+
+/// `rml_config` 配下のMOD設定ファイル
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModConfig {
+    /// `rml_config` からの相対パス（区切りは "/"）
+    pub file_name: String,
+    pub path: PathBuf,
+    /// 対応付けたインストール済みMOD（判別できない場合はNone）
+    pub mod_name: Option<String>,
+    /// スキーマファイル（`<名前>.schema.json`）があるか
+    pub has_schema: bool,
+}
+
+/// `rml_config` 配下の設定ファイル（`.schema.json` を除く）を列挙し、MODに対応付ける
+///
+/// `mods` は `match_config_files` と同じ（MOD名, DLLのファイル名）の組。
+pub fn list_rml_configs(game_dir: &Path, mods: &[(String, String)]) -> Vec<ModConfig> {
+    let config_dir = game_dir.join(RML_CONFIG_DIR);
+    let files: Vec<PathBuf> = list_config_files(game_dir)
+        .into_iter()
+        .filter(|path| path.starts_with(&config_dir))
+        .filter(|path| {
+            let name = path.to_string_lossy().to_lowercase();
+            name.ends_with(".json") && !name.ends_with(".schema.json")
+        })
+        .collect();
+    let map = match_config_files(mods, &files);
+
+    files
+        .iter()
+        .map(|path| ModConfig {
+            file_name: path
+                .strip_prefix(&config_dir)
+                .unwrap_or(path)
+                .to_string_lossy()
+                .replace('\\', "/"),
+            path: path.clone(),
+            mod_name: map
+                .mods
+                .iter()
+                .find(|(_, paths)| paths.contains(path))
+                .map(|(name, _)| name.clone()),
+            has_schema: path.with_extension("schema.json").exists(),
+        })
+        .collect()
+}
+
+/// `rml_config` からの相対パスを検証して絶対パスに変換する
+///
+/// `rml_config` の外（`..` や絶対パス）とJSON以外のファイルは扱わない。
+fn resolve_rml_config_path(game_dir: &Path, file_name: &str) -> Result<PathBuf, Box<dyn Error + Send + Sync>> {
+    let relative = Path::new(file_name);
+    let is_inside = relative
+        .components()
+        .all(|component| matches!(component, std::path::Component::Normal(_)));
+    if file_name.trim().is_empty() || !is_inside {
+        return Err(format!("Invalid config file name: {}", file_name).into());
+    }
+    if !file_name.to_lowercase().ends_with(".json") {
+        return Err(format!("Config file must be a JSON file: {}", file_name).into());
+    }
+    Ok(game_dir.join(RML_CONFIG_DIR).join(relative))
+}
+
+/// `rml_config` 配下の設定ファイルを読み込む
+pub fn read_rml_config(game_dir: &Path, file_name: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+    let path = resolve_rml_config_path(game_dir, file_name)?;
+    if !path.exists() {
+        return Err(format!("Config file not found: {}", file_name).into());
+    }
+    Ok(fs::read_to_string(path)?)
+}
+
+/// `rml_config` 配下の設定ファイルを書き込む
+///
+/// 壊れた設定でMODが読み込めなくならないよう、JSONとして解釈できない内容は書き込まない。
+pub fn write_rml_config(game_dir: &Path, file_name: &str, content: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let path = resolve_rml_config_path(game_dir, file_name)?;
+    serde_json::from_str::<Value>(content).map_err(|e| format!("Invalid JSON: {}", e))?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_schema_fields_types() {
+        let schema = json!({
+            "properties": {
+                "Enabled": { "type": "boolean", "default": true },
+                "Count": { "type": "integer", "minimum": 0, "maximum": 10 },
+                "Mode": { "type": "string", "enum": ["Fast", "Slow"], "title": "動作モード" },
+                "Nested": { "type": "object" }
+            }
+        });
+        let mut current = Map::new();
+        current.insert("Count".to_string(), json!(3));
+
+        let fields = parse_schema_fields(&schema, &current);
+        assert_eq!(fields.len(), 3);
+
+        let enabled = fields.iter().find(|f| f.key == "Enabled").unwrap();
+        assert_eq!(enabled.field_type, ConfigFieldType::Boolean);
+        assert_eq!(enabled.default_value, Some(json!(true)));
+
+        let count = fields.iter().find(|f| f.key == "Count").unwrap();
+        assert_eq!(count.field_type, ConfigFieldType::Integer);
+        assert_eq!(count.current_value, Some(json!(3)));
+        assert_eq!(count.maximum, Some(10.0));
+
+        let mode = fields.iter().find(|f| f.key == "Mode").unwrap();
+        assert_eq!(mode.field_type, ConfigFieldType::Select);
+        assert_eq!(mode.label, "動作モード");
+        assert_eq!(mode.options.len(), 2);
+    }
+
+    #[test]
+    fn test_load_config_schema_without_schema_falls_back_to_raw_json() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().join("rml_config");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(config_dir.join("TestMod.json"), r#"{"version":"1.0.0","values":{"Enabled":true}}"#).unwrap();
+
+        let schema = load_config_schema(temp_dir.path(), "TestMod").unwrap();
+        assert!(!schema.has_schema);
+        assert!(schema.fields.is_empty());
+        assert!(schema.raw_json.unwrap().contains("Enabled"));
+    }
+
+    #[test]
+    fn test_load_config_schema_with_schema_reads_rml_values() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().join("rml_config");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(config_dir.join("TestMod.json"), r#"{"version":"1.0.0","values":{"Enabled":false}}"#).unwrap();
+        fs::write(
+            config_dir.join("TestMod.schema.json"),
+            r#"{"properties":{"Enabled":{"type":"boolean"}}}"#,
+        )
+        .unwrap();
+
+        let schema = load_config_schema(temp_dir.path(), "TestMod").unwrap();
+        assert!(schema.has_schema);
+        assert_eq!(schema.fields[0].current_value, Some(json!(false)));
+    }
+
+    #[test]
+    fn test_match_config_files() {
+        let mods = vec![
+            ("CameraTweaks".to_string(), "CameraTweaks".to_string()),
+            ("Better Inspector".to_string(), "BetterInspector".to_string()),
+            ("UI".to_string(), "UI".to_string()),
+        ];
+        let files = vec![
+            PathBuf::from("rml_config/CameraTweaks.json"),
+            PathBuf::from("rml_config/CameraTweaks.schema.json"),
+            PathBuf::from("MonkeyLoader/Configs/author.BetterInspector.json"),
+            PathBuf::from("rml_config/BetterInspector_Presets.json"),
+            PathBuf::from("rml_config/UIX.json"),
+            PathBuf::from("rml_config/Unknown.json"),
+        ];
+
+        let map = match_config_files(&mods, &files);
+        assert_eq!(map.mods["CameraTweaks"].len(), 2);
+        assert_eq!(
+            map.mods["Better Inspector"],
+            vec![
+                PathBuf::from("MonkeyLoader/Configs/author.BetterInspector.json"),
+                PathBuf::from("rml_config/BetterInspector_Presets.json"),
+            ]
+        );
+        // 短いMOD名は前方一致で対応付けない
+        assert!(map.mods["UI"].is_empty());
+        assert_eq!(
+            map.unmatched,
+            vec![PathBuf::from("rml_config/UIX.json"), PathBuf::from("rml_config/Unknown.json")]
+        );
+    }
+
+    #[test]
+    fn test_list_read_and_write_rml_configs() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_dir = temp_dir.path().join("rml_config");
+        fs::create_dir_all(config_dir.join("presets")).unwrap();
+        fs::write(config_dir.join("CameraTweaks.json"), r#"{"values":{}}"#).unwrap();
+        fs::write(config_dir.join("CameraTweaks.schema.json"), r#"{"properties":{}}"#).unwrap();
+        fs::write(config_dir.join("presets").join("Unknown.json"), "{}").unwrap();
+
+        let mods = vec![("CameraTweaks".to_string(), "CameraTweaks".to_string())];
+        let configs = list_rml_configs(temp_dir.path(), &mods);
+        assert_eq!(configs.len(), 2);
+        assert_eq!(configs[0].file_name, "CameraTweaks.json");
+        assert_eq!(configs[0].mod_name.as_deref(), Some("CameraTweaks"));
+        assert!(configs[0].has_schema);
+        assert_eq!(configs[1].file_name, "presets/Unknown.json");
+        assert!(configs[1].mod_name.is_none());
+
+        write_rml_config(temp_dir.path(), "CameraTweaks.json", r#"{"values":{"Enabled":true}}"#).unwrap();
+        assert!(read_rml_config(temp_dir.path(), "CameraTweaks.json").unwrap().contains("Enabled"));
+
+        // 壊れたJSONは書き込まない
+        assert!(write_rml_config(temp_dir.path(), "CameraTweaks.json", "{").is_err());
+        assert!(read_rml_config(temp_dir.path(), "CameraTweaks.json").unwrap().contains("Enabled"));
+    }
+
+    #[test]
+    fn test_rml_config_path_must_stay_inside_config_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(write_rml_config(temp_dir.path(), "../Resonite.json", "{}").is_err());
+        assert!(write_rml_config(temp_dir.path(), "/etc/config.json", "{}").is_err());
+        assert!(write_rml_config(temp_dir.path(), "Mod.txt", "{}").is_err());
+        assert!(read_rml_config(temp_dir.path(), "Missing.json").is_err());
+    }
+}
@@ -0,0 +1,156 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 保持するMOD検索クエリの最大件数
+const MAX_SEARCH_HISTORY: usize = 20;
+
+/// 保持する最近インストールしたMODの最大件数
+const MAX_RECENT_INSTALLS: usize = 20;
+
+/// 最近インストールしたMOD
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecentlyInstalledMod {
+    pub name: String,
+    pub source_location: String,
+    pub version: String,
+    pub profile_name: String,
+    pub installed_at: String,
+}
+
+/// MODの検索履歴とインストール履歴（新しいものが先頭）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModHistory {
+    #[serde(default)]
+    pub searches: Vec<String>,
+    #[serde(default)]
+    pub installed: Vec<RecentlyInstalledMod>,
+}
+
+/// アプリ全体のMOD検索・インストール履歴を管理する
+pub struct ModHistoryManager {
+    history_file: PathBuf,
+}
+
+impl ModHistoryManager {
+    pub fn new(data_dir: &Path) -> Self {
+        ModHistoryManager {
+            history_file: data_dir.join("mod_history.json"),
+        }
+    }
+
+    /// 履歴を読み込む
+    pub fn load(&self) -> ModHistory {
+        fs::read_to_string(&self.history_file)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// 検索クエリを履歴の先頭に追加する（同じクエリは先頭に移動）
+    pub fn add_search(&self, query: &str) -> Result<(), Box<dyn Error>> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(());
+        }
+
+        let mut history = self.load();
+        history.searches.retain(|q| !q.eq_ignore_ascii_case(query));
+        history.searches.insert(0, query.to_string());
+        history.searches.truncate(MAX_SEARCH_HISTORY);
+        self.save(&history)
+    }
+
+    /// インストールしたMODを履歴の先頭に追加する（同じMODは先頭に移動）
+    pub fn add_installed(&self, name: &str, source_location: &str, version: &str, profile_name: &str) -> Result<(), Box<dyn Error>> {
+        let mut history = self.load();
+        history.installed.retain(|m| m.source_location != source_location);
+        history.installed.insert(
+            0,
+            RecentlyInstalledMod {
+                name: name.to_string(),
+                source_location: source_location.to_string(),
+                version: version.to_string(),
+                profile_name: profile_name.to_string(),
+                installed_at: Utc::now().to_rfc3339(),
+            },
+        );
+        history.installed.truncate(MAX_RECENT_INSTALLS);
+        self.save(&history)
+    }
+
+    /// 検索履歴を消去する
+    pub fn clear_searches(&self) -> Result<(), Box<dyn Error>> {
+        let mut history = self.load();
+        history.searches.clear();
+        self.save(&history)
+    }
+
+    /// インストール履歴を消去する
+    pub fn clear_installed(&self) -> Result<(), Box<dyn Error>> {
+        let mut history = self.load();
+        history.installed.clear();
+        self.save(&history)
+    }
+
+    fn save(&self, history: &ModHistory) -> Result<(), Box<dyn Error>> {
+        if let Some(parent) = self.history_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.history_file, serde_json::to_string_pretty(history)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_search_history_dedup_and_limit() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = ModHistoryManager::new(temp_dir.path());
+
+        manager.add_search("camera").unwrap();
+        manager.add_search("  ").unwrap();
+        manager.add_search("inspector").unwrap();
+        manager.add_search("Camera").unwrap();
+        assert_eq!(manager.load().searches, vec!["Camera", "inspector"]);
+
+        for i in 0..MAX_SEARCH_HISTORY + 5 {
+            manager.add_search(&format!("query{}", i)).unwrap();
+        }
+        let searches = manager.load().searches;
+        assert_eq!(searches.len(), MAX_SEARCH_HISTORY);
+        assert_eq!(searches[0], format!("query{}", MAX_SEARCH_HISTORY + 4));
+
+        manager.clear_searches().unwrap();
+        assert!(manager.load().searches.is_empty());
+    }
+
+    #[test]
+    fn test_recently_installed_mods() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = ModHistoryManager::new(temp_dir.path());
+
+        manager.add_installed("ModA", "https://github.com/a/ModA", "1.0.0", "default").unwrap();
+        manager.add_installed("ModB", "https://github.com/b/ModB", "2.0.0", "default").unwrap();
+        manager.add_installed("ModA", "https://github.com/a/ModA", "1.1.0", "test").unwrap();
+
+        let installed = manager.load().installed;
+        assert_eq!(installed.len(), 2);
+        assert_eq!(installed[0].name, "ModA");
+        assert_eq!(installed[0].version, "1.1.0");
+        assert_eq!(installed[0].profile_name, "test");
+
+        // インストール履歴の消去は検索履歴に影響しない
+        manager.add_search("mod").unwrap();
+        manager.clear_installed().unwrap();
+        let history = manager.load();
+        assert!(history.installed.is_empty());
+        assert_eq!(history.searches, vec!["mod"]);
+    }
+}
@@ -0,0 +1,349 @@
+use serde::Serialize;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+
+use crate::mod_manager::ModInfo;
+
+/// キュー内のインストール要求の状態
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum QueueItemStatus {
+    /// 待機中
+    Pending,
+    /// 処理中
+    Running,
+    /// 完了
+    Completed,
+    /// 失敗
+    Failed { error: String },
+    /// 処理前にキャンセルされた
+    Cancelled,
+}
+
+/// キューに積まれたMODのインストール要求
+#[derive(Debug, Clone, Serialize)]
+pub struct QueueItem {
+    pub id: u64,
+    pub profile_name: String,
+    pub mod_name: String,
+    pub source_location: String,
+    pub version: Option<String>,
+    pub status: QueueItemStatus,
+    #[serde(skip)]
+    pub mod_info: ModInfo,
+}
+
+/// キュー全体の状態（GUIへの通知用）
+#[derive(Debug, Clone, Serialize)]
+pub struct QueueSnapshot {
+    pub paused: bool,
+    pub items: Vec<QueueItem>,
+}
+
+type QueueListener = Arc<dyn Fn(&QueueSnapshot) + Send + Sync>;
+
+struct QueueState {
+    next_id: u64,
+    paused: bool,
+    items: Vec<QueueItem>,
+}
+
+/// MODのインストール要求を積んで、順次（または制限された並列数で）処理するキュー
+///
+/// 状態が変わるたびにリスナーへキュー全体の状態を通知する。
+/// 同じプロファイルへの要求はinstalled_mods.jsonの書き込みが競合しないよう1件ずつ処理する。
+/// 処理中の要求は中断できないため、キャンセル・並べ替えは待機中の要求のみが対象。
+pub struct ModInstallQueue {
+    state: Mutex<QueueState>,
+    max_parallel: usize,
+    notify: Notify,
+    listener: Mutex<Option<QueueListener>>,
+}
+
+impl ModInstallQueue {
+    /// 同時に処理する要求の数を指定して作成
+    pub fn new(max_parallel: usize) -> Self {
+        ModInstallQueue {
+            state: Mutex::new(QueueState {
+                next_id: 1,
+                paused: false,
+                items: Vec::new(),
+            }),
+            max_parallel: max_parallel.max(1),
+            notify: Notify::new(),
+            listener: Mutex::new(None),
+        }
+    }
+
+    /// 状態が変わったときに呼ばれるリスナーを設定
+    pub fn set_listener<F>(&self, listener: F)
+    where
+        F: Fn(&QueueSnapshot) + Send + Sync + 'static,
+    {
+        *self.listener.lock().unwrap() = Some(Arc::new(listener));
+    }
+
+    /// インストール要求を末尾に積み、要求IDを返す
+    pub fn enqueue(&self, profile_name: &str, mod_info: ModInfo, version: Option<String>) -> u64 {
+        self.update(|state| {
+            let id = state.next_id;
+            state.next_id += 1;
+            state.items.push(QueueItem {
+                id,
+                profile_name: profile_name.to_string(),
+                mod_name: mod_info.name.clone(),
+                source_location: mod_info.source_location.clone(),
+                version,
+                status: QueueItemStatus::Pending,
+                mod_info,
+            });
+            id
+        })
+    }
+
+    /// 待機中の要求をキャンセルする（処理中・処理済みの場合はfalse）
+    pub fn cancel(&self, id: u64) -> bool {
+        self.update(|state| match state.items.iter_mut().find(|item| item.id == id) {
+            Some(item) if item.status == QueueItemStatus::Pending => {
+                item.status = QueueItemStatus::Cancelled;
+                true
+            }
+            _ => false,
+        })
+    }
+
+    /// 待機中の要求をキュー内の指定位置へ移動する（範囲外の位置は末尾として扱う）
+    pub fn move_item(&self, id: u64, new_index: usize) -> bool {
+        self.update(|state| {
+            let Some(pos) = state
+                .items
+                .iter()
+                .position(|item| item.id == id && item.status == QueueItemStatus::Pending)
+            else {
+                return false;
+            };
+            let item = state.items.remove(pos);
+            let new_index = new_index.min(state.items.len());
+            state.items.insert(new_index, item);
+            true
+        })
+    }
+
+    /// 新しい要求の処理開始を一時停止する（処理中の要求はそのまま完了まで続く）
+    pub fn pause(&self) {
+        self.update(|state| state.paused = true);
+    }
+
+    /// 一時停止を解除する
+    pub fn resume(&self) {
+        self.update(|state| state.paused = false);
+    }
+
+    /// 完了・失敗・キャンセル済みの要求をキューから取り除く
+    pub fn clear_finished(&self) {
+        self.update(|state| {
+            state
+                .items
+                .retain(|item| matches!(item.status, QueueItemStatus::Pending | QueueItemStatus::Running))
+        });
+    }
+
+    /// キュー全体の状態を取得
+    pub fn snapshot(&self) -> QueueSnapshot {
+        let state = self.state.lock().unwrap();
+        QueueSnapshot {
+            paused: state.paused,
+            items: state.items.clone(),
+        }
+    }
+
+    /// 次に処理する要求を処理中にして返す（一時停止中や並列数の上限に達している場合はNone）
+    ///
+    /// 処理中の要求と同じプロファイルへの要求は飛ばし、別のプロファイルへの要求を先に始める。
+    pub fn start_next(&self) -> Option<QueueItem> {
+        let started = {
+            let mut state = self.state.lock().unwrap();
+            if state.paused {
+                return None;
+            }
+            let running_profiles: Vec<String> = state
+                .items
+                .iter()
+                .filter(|item| item.status == QueueItemStatus::Running)
+                .map(|item| item.profile_name.clone())
+                .collect();
+            if running_profiles.len() >= self.max_parallel {
+                return None;
+            }
+
+            let item = state.items.iter_mut().find(|item| {
+                item.status == QueueItemStatus::Pending && !running_profiles.contains(&item.profile_name)
+            })?;
+            item.status = QueueItemStatus::Running;
+            item.clone()
+        };
+
+        // 処理ループ自身が呼ぶため、ここではループを起こさずリスナーにだけ通知する
+        self.notify_listener();
+        Some(started)
+    }
+
+    /// 処理結果を記録する
+    pub fn finish(&self, id: u64, result: Result<(), String>) {
+        self.update(|state| {
+            if let Some(item) = state.items.iter_mut().find(|item| item.id == id) {
+                item.status = match result {
+                    Ok(()) => QueueItemStatus::Completed,
+                    Err(error) => QueueItemStatus::Failed { error },
+                };
+            }
+        });
+    }
+
+    /// キューを処理し続ける（GUIの起動中はバックグラウンドで動かしておく）
+    ///
+    /// `install` は要求1件分のインストールを行い、失敗時はエラーメッセージを返す。
+    pub async fn run<F, Fut>(self: Arc<Self>, install: F)
+    where
+        F: Fn(QueueItem) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        let install = Arc::new(install);
+        loop {
+            // notify_waitersを取りこぼさないよう、キューの確認より前に待機を登録する
+            let notified = self.notify.notified();
+
+            while let Some(item) = self.start_next() {
+                let queue = Arc::clone(&self);
+                let install = Arc::clone(&install);
+                tokio::spawn(async move {
+                    let id = item.id;
+                    // インストール処理がパニックしても、要求が処理中のまま残らないようにする
+                    let result = match tokio::spawn(install(item)).await {
+                        Ok(result) => result,
+                        Err(e) => Err(format!("Install task failed: {}", e)),
+                    };
+                    queue.finish(id, result);
+                });
+            }
+
+            notified.await;
+        }
+    }
+
+    /// 状態を更新し、処理ループとリスナーに通知する
+    fn update<T>(&self, f: impl FnOnce(&mut QueueState) -> T) -> T {
+        let result = f(&mut self.state.lock().unwrap());
+
+        self.notify.notify_waiters();
+        self.notify_listener();
+        result
+    }
+
+    fn notify_listener(&self) {
+        let listener = self.listener.lock().unwrap().clone();
+        if let Some(listener) = listener {
+            listener(&self.snapshot());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_mod(name: &str) -> ModInfo {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "description": "",
+            "source_location": format!("https://github.com/test/{}", name),
+            "author": "test",
+            "releases": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_start_next_respects_parallel_limit_and_pause() {
+        let queue = ModInstallQueue::new(1);
+        let first = queue.enqueue("default", test_mod("ModA"), None);
+        let second = queue.enqueue("default", test_mod("ModB"), Some("1.0.0".to_string()));
+
+        assert_eq!(queue.start_next().unwrap().id, first);
+        // 並列数1なので処理中の要求が終わるまで次は始まらない
+        assert!(queue.start_next().is_none());
+
+        queue.pause();
+        queue.finish(first, Ok(()));
+        assert!(queue.start_next().is_none());
+
+        queue.resume();
+        let item = queue.start_next().unwrap();
+        assert_eq!(item.id, second);
+        assert_eq!(item.version.as_deref(), Some("1.0.0"));
+
+        queue.finish(second, Err("download failed".to_string()));
+        let snapshot = queue.snapshot();
+        assert_eq!(snapshot.items[0].status, QueueItemStatus::Completed);
+        assert_eq!(
+            snapshot.items[1].status,
+            QueueItemStatus::Failed { error: "download failed".to_string() }
+        );
+
+        queue.clear_finished();
+        assert!(queue.snapshot().items.is_empty());
+    }
+
+    #[test]
+    fn test_cancel_and_reorder_pending_items() {
+        let queue = ModInstallQueue::new(1);
+        let a = queue.enqueue("default", test_mod("ModA"), None);
+        let b = queue.enqueue("default", test_mod("ModB"), None);
+        let c = queue.enqueue("default", test_mod("ModC"), None);
+
+        assert_eq!(queue.start_next().unwrap().id, a);
+        // 処理中の要求はキャンセル・移動できない
+        assert!(!queue.cancel(a));
+        assert!(!queue.move_item(a, 2));
+
+        assert!(queue.move_item(c, 0));
+        let order: Vec<u64> = queue.snapshot().items.iter().map(|item| item.id).collect();
+        assert_eq!(order, vec![c, a, b]);
+
+        assert!(queue.cancel(c));
+        assert!(!queue.cancel(c));
+        queue.finish(a, Ok(()));
+        assert_eq!(queue.start_next().unwrap().id, b);
+    }
+
+    #[test]
+    fn test_same_profile_items_run_one_at_a_time() {
+        let queue = ModInstallQueue::new(2);
+        let a = queue.enqueue("default", test_mod("ModA"), None);
+        let b = queue.enqueue("default", test_mod("ModB"), None);
+        let c = queue.enqueue("other", test_mod("ModC"), None);
+
+        assert_eq!(queue.start_next().unwrap().id, a);
+        // 同じプロファイルのModBは飛ばして、別プロファイルのModCを先に始める
+        assert_eq!(queue.start_next().unwrap().id, c);
+        assert!(queue.start_next().is_none());
+
+        queue.finish(c, Ok(()));
+        assert!(queue.start_next().is_none());
+        queue.finish(a, Ok(()));
+        assert_eq!(queue.start_next().unwrap().id, b);
+    }
+
+    #[test]
+    fn test_listener_receives_snapshots() {
+        let queue = ModInstallQueue::new(2);
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&received);
+        queue.set_listener(move |snapshot| sink.lock().unwrap().push(snapshot.items.len()));
+
+        queue.enqueue("default", test_mod("ModA"), None);
+        queue.enqueue("default", test_mod("ModB"), None);
+        assert_eq!(*received.lock().unwrap(), vec![1, 2]);
+    }
+}
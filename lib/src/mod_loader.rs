@@ -4,6 +4,8 @@ use reqwest;
 use serde::{Deserialize, Serialize};
 use anyhow::{Result, anyhow};
 
+use crate::utils;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ModLoaderInfo {
     pub installed: bool,
@@ -24,11 +26,56 @@ pub struct GitHubAsset {
 }
 
 const GITHUB_API_URL: &str = "https://api.github.com/repos/resonite-modding-group/ResoniteModLoader/releases/latest";
+const GITHUB_RELEASES_URL: &str = "https://api.github.com/repos/resonite-modding-group/ResoniteModLoader/releases";
+/// リリースに0Harmony.dllが含まれない場合の取得元
+const FALLBACK_HARMONY_URL: &str = "https://github.com/resonite-modding-group/ResoniteModLoader/releases/download/3.0.0/0Harmony.dll";
+/// インストールしたバージョン（タグ）を記録するファイル名（Librariesフォルダ内）
+const VERSION_FILE_NAME: &str = "ResoniteModLoader.version";
+/// アセンブリを読み込ませる起動引数
+const LOAD_ASSEMBLY_ARG: &str = "-LoadAssembly";
+/// 起動引数に追加するModLoaderのパス
+const MOD_LOADER_ARG_VALUE: &str = "%GAME_DIR%/Libraries/ResoniteModLoader.dll";
 
 pub struct ModLoader {
     game_path: PathBuf,
 }
 
+/// ResoniteModLoader.dllを指すパスか（引用符・区切り文字・大文字小文字の違いは無視）
+fn is_mod_loader_assembly(value: &str) -> bool {
+    let normalized = value.trim().trim_matches('"').trim().replace('\\', "/").to_lowercase();
+    normalized.rsplit('/').next() == Some("resonitemodloader.dll")
+}
+
+/// 起動引数からModLoaderを読み込む `-LoadAssembly` 指定を探し、（開始位置, 要素数）を返す
+///
+/// `-LoadAssembly` とパスが別要素の場合と、`-LoadAssembly <パス>` / `-LoadAssembly=<パス>`
+/// のように1要素にまとまっている場合のどちらにも対応する。
+fn find_mod_loader_args(args: &[String]) -> Vec<(usize, usize)> {
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < args.len() {
+        let arg = args[i].trim();
+        let (flag, inline_value) = match arg.find(|c: char| c.is_whitespace() || c == '=') {
+            Some(pos) => (&arg[..pos], Some(&arg[pos + 1..])),
+            None => (arg, None),
+        };
+
+        if flag.eq_ignore_ascii_case(LOAD_ASSEMBLY_ARG) {
+            match inline_value {
+                Some(value) if is_mod_loader_assembly(value) => found.push((i, 1)),
+                None if args.get(i + 1).is_some_and(|value| is_mod_loader_assembly(value)) => {
+                    found.push((i, 2));
+                    i += 2;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+    found
+}
+
 impl ModLoader {
     pub fn new(game_path: PathBuf) -> Self {
         Self { game_path }
@@ -44,8 +91,12 @@ impl ModLoader {
         let installed = mod_loader_dll.exists() && harmony_dll.exists();
         
         let version = if installed {
-            // ここではファイルのバージョン情報を取得するか、既知のバージョンを返す
-            Some("3.0.0".to_string())
+            // インストール時に記録したバージョン、無ければ不明（ランチャー以外で導入された場合など）
+            let recorded = fs::read_to_string(libraries_path.join(VERSION_FILE_NAME))
+                .ok()
+                .map(|content| content.trim().to_string())
+                .filter(|version| !version.is_empty());
+            Some(recorded.unwrap_or_else(|| "Unknown".to_string()))
         } else {
             None
         };
@@ -63,8 +114,42 @@ impl ModLoader {
         })
     }
 
-    /// ResoniteModLoaderをインストール
+    /// ResoniteModLoaderの最新版をインストール
     pub async fn install(&self) -> Result<String> {
+        let release = self.get_latest_release().await?;
+        self.install_release(release).await
+    }
+
+    /// 指定したタグのResoniteModLoaderをインストール
+    pub async fn install_version(&self, tag: &str) -> Result<String> {
+        let release = self.fetch_release(&format!("{}/tags/{}", GITHUB_RELEASES_URL, tag)).await
+            .map_err(|e| anyhow!("Failed to get ResoniteModLoader {}: {}", tag, e))?;
+        self.install_release(release).await
+    }
+
+    /// インストール可能なResoniteModLoaderのバージョン（タグ）一覧を新しい順に取得
+    pub async fn list_available_versions(&self) -> Result<Vec<String>> {
+        let client = reqwest::Client::new();
+        let response = client
+            .get(GITHUB_RELEASES_URL)
+            .header("User-Agent", "ResoniteTools")
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch releases: {}", response.status()));
+        }
+
+        let releases: Vec<GitHubRelease> = response.json().await?;
+        Ok(releases
+            .into_iter()
+            .filter(|release| release.assets.iter().any(|asset| asset.name == "ResoniteModLoader.dll"))
+            .map(|release| release.tag_name)
+            .collect())
+    }
+
+    /// 取得済みのリリースからResoniteModLoaderをインストール
+    async fn install_release(&self, release: GitHubRelease) -> Result<String> {
         // 必要なディレクトリを作成
         let libraries_path = self.game_path.join("Libraries");
         let rml_libs_path = self.game_path.join("rml_libs");
@@ -74,9 +159,6 @@ impl ModLoader {
         fs::create_dir_all(&rml_libs_path)?;
         fs::create_dir_all(&rml_mods_path)?;
 
-        // 最新リリース情報を取得
-        let release = self.get_latest_release().await?;
-        
         // ResoniteModLoader.dllをダウンロード
         let mod_loader_asset = release.assets.iter()
             .find(|asset| asset.name == "ResoniteModLoader.dll")
@@ -85,11 +167,51 @@ impl ModLoader {
         self.download_file(&mod_loader_asset.browser_download_url, &libraries_path.join("ResoniteModLoader.dll")).await?;
 
         // 0Harmony.dllをダウンロードして展開
-        self.download_harmony(&rml_libs_path).await?;
+        self.download_harmony(&rml_libs_path, Some(&release)).await?;
+
+        // 更新確認用にバージョンを記録
+        fs::write(libraries_path.join(VERSION_FILE_NAME), &release.tag_name)?;
 
         Ok(format!("ResoniteModLoader {} をインストールしました", release.tag_name))
     }
 
+    /// Harmony（`rml_libs/0Harmony.dll`）が配置されているか確認
+    pub fn check_harmony(&self) -> bool {
+        self.game_path.join("rml_libs").join("0Harmony.dll").exists()
+    }
+
+    /// Harmonyが無ければRMLリリースに同梱のものを配置する
+    pub async fn ensure_harmony(&self) -> Result<()> {
+        if self.check_harmony() {
+            return Ok(());
+        }
+
+        let rml_libs_path = self.game_path.join("rml_libs");
+        fs::create_dir_all(&rml_libs_path)?;
+
+        let release = self.get_latest_release().await.ok();
+        self.download_harmony(&rml_libs_path, release.as_ref()).await?;
+
+        if !self.check_harmony() {
+            return Err(anyhow!("Failed to place 0Harmony.dll in {}", rml_libs_path.display()));
+        }
+        Ok(())
+    }
+
+    /// 最新リリースとインストール済みバージョンを比較し、更新可能なら新しいバージョンを返す
+    pub async fn check_update(&self) -> Result<Option<String>> {
+        let Some(current_version) = self.get_status()?.version else {
+            return Ok(None);
+        };
+
+        let release = self.get_latest_release().await?;
+        if utils::compare_versions(&release.tag_name, &current_version) == std::cmp::Ordering::Greater {
+            Ok(Some(release.tag_name))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// ResoniteModLoaderをアンインストール
     pub fn uninstall(&self) -> Result<String> {
         let libraries_path = self.game_path.join("Libraries");
@@ -101,6 +223,11 @@ impl ModLoader {
             fs::remove_file(&mod_loader_dll)?;
         }
 
+        let version_file = libraries_path.join(VERSION_FILE_NAME);
+        if version_file.exists() {
+            fs::remove_file(&version_file)?;
+        }
+
         // rml_libsディレクトリを削除
         if rml_libs_path.exists() {
             fs::remove_dir_all(&rml_libs_path)?;
@@ -113,48 +240,34 @@ impl ModLoader {
 
     /// 起動引数にModLoaderの読み込みを追加する必要があるかチェック
     pub fn needs_launch_arg_update(&self, current_args: &[String]) -> bool {
-        let required_arg = "-LoadAssembly";
-        let required_value = "%GAME_DIR%/Libraries/ResoniteModLoader.dll";
-        
-        // 現在の引数に既に含まれているかチェック
-        for (i, arg) in current_args.iter().enumerate() {
-            if arg == required_arg {
-                if i + 1 < current_args.len() && current_args[i + 1] == required_value {
-                    return false; // 既に正しく設定されている
-                }
-            }
-        }
-        true // 追加が必要
+        find_mod_loader_args(current_args).is_empty()
     }
 
     /// 起動引数にModLoader用の引数を追加
     pub fn add_launch_args(&self, current_args: &mut Vec<String>) {
         if self.needs_launch_arg_update(current_args) {
-            current_args.push("-LoadAssembly".to_string());
-            current_args.push("%GAME_DIR%/Libraries/ResoniteModLoader.dll".to_string());
+            current_args.push(LOAD_ASSEMBLY_ARG.to_string());
+            current_args.push(MOD_LOADER_ARG_VALUE.to_string());
         }
     }
 
-    /// 起動引数からModLoader用の引数を除去
+    /// 起動引数からModLoader用の引数を除去（書き方の違うものや重複もすべて除去する）
     pub fn remove_launch_args(&self, current_args: &mut Vec<String>) {
-        let mut i = 0;
-        while i < current_args.len() {
-            if current_args[i] == "-LoadAssembly" && 
-               i + 1 < current_args.len() && 
-               current_args[i + 1] == "%GAME_DIR%/Libraries/ResoniteModLoader.dll" {
-                current_args.remove(i);
-                current_args.remove(i); // 次の要素も削除
-            } else {
-                i += 1;
-            }
+        for (index, len) in find_mod_loader_args(current_args).into_iter().rev() {
+            current_args.drain(index..index + len);
         }
     }
 
     /// GitHubから最新リリース情報を取得
     async fn get_latest_release(&self) -> Result<GitHubRelease> {
+        self.fetch_release(GITHUB_API_URL).await
+    }
+
+    /// GitHubからリリース情報を取得
+    async fn fetch_release(&self, url: &str) -> Result<GitHubRelease> {
         let client = reqwest::Client::new();
         let response = client
-            .get(GITHUB_API_URL)
+            .get(url)
             .header("User-Agent", "ResoniteTools")
             .send()
             .await?;
@@ -185,13 +298,95 @@ impl ModLoader {
         Ok(())
     }
 
-    /// Harmonyライブラリをダウンロードして展開
-    async fn download_harmony(&self, rml_libs_path: &Path) -> Result<()> {
-        // とりあえず簡単な実装として、ResoniteModLoaderリポジトリから提供されているDLLを使用
-        // 本来はNuGetパッケージから抽出する必要がある
-        let harmony_url = "https://github.com/resonite-modding-group/ResoniteModLoader/releases/download/3.0.0/0Harmony.dll";
-        
+    /// Harmonyライブラリをダウンロードして配置
+    ///
+    /// リリースに `0Harmony.dll` が同梱されていればそれを使い、無ければ既知のリリースから取得する。
+    async fn download_harmony(&self, rml_libs_path: &Path, release: Option<&GitHubRelease>) -> Result<()> {
+        let harmony_url = release
+            .and_then(|release| release.assets.iter().find(|asset| asset.name == "0Harmony.dll"))
+            .map(|asset| asset.browser_download_url.as_str())
+            .unwrap_or(FALLBACK_HARMONY_URL);
+
         self.download_file(harmony_url, &rml_libs_path.join("0Harmony.dll")).await?;
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_remove_launch_args_variants() {
+        let loader = ModLoader::new(PathBuf::from("Game"));
+        let cases = [
+            args(&["-Screen", "-LoadAssembly", "%GAME_DIR%/Libraries/ResoniteModLoader.dll"]),
+            args(&["-Screen", "-loadassembly", "%GAME_DIR%\\Libraries\\ResoniteModLoader.dll"]),
+            args(&["-Screen", "-LoadAssembly", "\"C:\\Program Files\\Resonite\\Libraries\\ResoniteModLoader.dll\""]),
+            args(&["-Screen", "-LoadAssembly Libraries/ResoniteModLoader.dll"]),
+            args(&["-LoadAssembly=Libraries\\ResoniteModLoader.dll", "-Screen"]),
+        ];
+
+        for mut case in cases {
+            assert!(!loader.needs_launch_arg_update(&case), "{:?}", case);
+            loader.remove_launch_args(&mut case);
+            assert_eq!(case, args(&["-Screen"]));
+            assert!(loader.needs_launch_arg_update(&case));
+        }
+    }
+
+    #[test]
+    fn test_remove_launch_args_keeps_other_assemblies() {
+        let loader = ModLoader::new(PathBuf::from("Game"));
+        let mut current = args(&[
+            "-LoadAssembly",
+            "%GAME_DIR%/Libraries/ResoniteModLoader.dll",
+            "-LoadAssembly",
+            "Libraries/Other.dll",
+            "-DataPath",
+            "%PROFILE_DIR%\\DataPath",
+            "-LoadAssembly",
+            "%GAME_DIR%\\Libraries\\ResoniteModLoader.dll",
+            "-LoadAssembly",
+        ]);
+
+        loader.remove_launch_args(&mut current);
+        assert_eq!(
+            current,
+            args(&["-LoadAssembly", "Libraries/Other.dll", "-DataPath", "%PROFILE_DIR%\\DataPath", "-LoadAssembly"])
+        );
+    }
+
+    #[test]
+    fn test_add_launch_args_does_not_duplicate() {
+        let loader = ModLoader::new(PathBuf::from("Game"));
+        let mut current = args(&["-LoadAssembly", "%GAME_DIR%\\Libraries\\ResoniteModLoader.dll"]);
+        loader.add_launch_args(&mut current);
+        assert_eq!(current.len(), 2);
+
+        let mut current = Vec::new();
+        loader.add_launch_args(&mut current);
+        assert_eq!(current, args(&["-LoadAssembly", MOD_LOADER_ARG_VALUE]));
+    }
+
+    #[test]
+    fn test_status_version_unknown_without_version_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let game_dir = temp_dir.path().to_path_buf();
+        fs::create_dir_all(game_dir.join("Libraries")).unwrap();
+        fs::create_dir_all(game_dir.join("rml_libs")).unwrap();
+        fs::write(game_dir.join("Libraries").join("ResoniteModLoader.dll"), b"rml").unwrap();
+        fs::write(game_dir.join("rml_libs").join("0Harmony.dll"), b"harmony").unwrap();
+
+        // ランチャー以外で導入された場合はバージョンを推測しない
+        let loader = ModLoader::new(game_dir.clone());
+        assert_eq!(loader.get_status().unwrap().version.as_deref(), Some("Unknown"));
+
+        fs::write(game_dir.join("Libraries").join(VERSION_FILE_NAME), "4.0.0\n").unwrap();
+        assert_eq!(loader.get_status().unwrap().version.as_deref(), Some("4.0.0"));
+    }
+}
@@ -1,11 +1,20 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::error::Error;
+use std::fmt;
 use std::path::PathBuf;
 use std::fs;
+use std::sync::Arc;
 use std::time::Duration;
 use reqwest;
 use sha2::{Sha256, Digest};
+use crate::mod_config::{self, ConfigSchema, ModConfig, ModConfigMap};
+use crate::download_limiter::{rate_limit_wait, DownloadLimiter};
+use crate::install_rules::{InstallRules, INSTALL_RULES_FILE};
+use crate::mod_loader::ModLoader;
+use crate::mod_loader_type::ModLoaderType;
+use crate::monkey_loader::MonkeyLoader;
+use crate::utils;
 
 /// MODマニフェストから取得したMOD情報
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +30,24 @@ pub struct ModInfo {
     pub tags: Option<Vec<String>>,
     pub flags: Option<Vec<String>>,
     pub last_updated: Option<String>,
+    /// 対応プラットフォーム（"Windows" / "Linux" / "Android"、未指定は全プラットフォーム）
+    #[serde(default)]
+    pub platforms: Option<Vec<String>>,
+    /// GitHubリポジトリの統計情報（`fetch_repo_stats` で取得して付与）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo_stats: Option<RepoStats>,
+    /// マニフェスト上のMOD ID（例: "com.example.ModName"）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// 依存するMODのID（マニフェストの `dependencies` のキー）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
+    /// Resonite起動中の再読み込み（ホットリロード）に対応しているか（未記載はNone）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hot_reload: Option<bool>,
+    /// どのMODローダー向けのマニフェストに載っているか（未記載はResoniteModLoader）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mod_loaders: Vec<ModLoaderType>,
 }
 
 /// MODの個別リリース情報
@@ -36,6 +63,29 @@ pub struct ModRelease {
     pub file_name: Option<String>,
     pub file_size: Option<u64>,
     pub sha256: Option<String>,
+    /// リリースに含まれるアーティファクト（プラットフォーム別に複数ある場合）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub artifacts: Vec<ReleaseArtifact>,
+    /// 動作に必要なMODローダーの最小バージョン
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_loader_version: Option<String>,
+    /// `download_url` が取得できない場合に順に試すミラーURL
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mirror_urls: Vec<String>,
+}
+
+/// リリースのアーティファクト
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseArtifact {
+    pub url: String,
+    pub file_name: Option<String>,
+    pub sha256: Option<String>,
+    /// このアーティファクトの対象プラットフォーム（未指定は全プラットフォーム）
+    #[serde(default)]
+    pub platforms: Option<Vec<String>>,
+    /// `url` が取得できない場合に順に試すミラーURL
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mirror_urls: Vec<String>,
 }
 
 /// インストール済みMOD情報
@@ -53,6 +103,169 @@ pub struct InstalledMod {
     pub file_format: Option<String>, // "dll" or "nupkg"
     #[serde(default)]
     pub enabled: Option<bool>, // MODの有効/無効状態
+    /// マニフェストに記載されたホットリロード対応状況（不明はNone）
+    #[serde(default)]
+    pub hot_reload_capable: Option<bool>,
+    /// ユーザーが手動で設定したホットリロード対応状況（マニフェストより優先）
+    #[serde(default)]
+    pub hot_reload_override: Option<bool>,
+    /// 読み込み順の優先度（ファイル名の `010_` などの接頭辞、未設定はNone）
+    #[serde(default)]
+    pub load_priority: Option<u32>,
+}
+
+/// Resonite起動中にMODを更新したときの反映方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModReloadMode {
+    /// ホットリロードで反映される
+    HotReload,
+    /// Resoniteの再起動が必要
+    RestartRequired,
+}
+
+impl InstalledMod {
+    /// このMODを読み込むMODローダー（記録が無い場合はファイル形式・配置場所から推定）
+    pub fn loader_type(&self) -> ModLoaderType {
+        match self.mod_loader_type.as_deref() {
+            Some("MonkeyLoader") => return ModLoaderType::MonkeyLoader,
+            Some("BepisLoader") => return ModLoaderType::BepisLoader,
+            Some("ResoniteModLoader") => return ModLoaderType::ResoniteModLoader,
+            _ => {}
+        }
+
+        let is_nupkg = self.file_format.as_deref() == Some("nupkg")
+            || self.dll_path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("nupkg"));
+        let in_monkey_loader_dir = self.dll_path.components().any(|c| c.as_os_str() == "MonkeyLoader");
+        if is_nupkg || in_monkey_loader_dir {
+            ModLoaderType::MonkeyLoader
+        } else {
+            ModLoaderType::ResoniteModLoader
+        }
+    }
+
+    /// 起動中に更新した場合の反映方法（対応状況が不明なMODは再起動が必要とみなす）
+    pub fn reload_mode(&self) -> ModReloadMode {
+        match self.hot_reload_override.or(self.hot_reload_capable) {
+            Some(true) => ModReloadMode::HotReload,
+            _ => ModReloadMode::RestartRequired,
+        }
+    }
+}
+
+/// プロファイルのMODローダーでは読み込まれないMOD
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModLoaderMismatch {
+    pub mod_name: String,
+    pub file_format: String,
+    /// このMODを読み込めるMODローダー（切り替え先の候補）
+    pub supported_loaders: Vec<ModLoaderType>,
+}
+
+/// インストール済みMODの入手元（マニフェストとの照合結果）
+///
+/// MODローダーごとのカタログを表す `mod_loader_type::ModSource` とは別物。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InstalledModSource {
+    /// マニフェストに載っているMOD
+    Manifest,
+    /// マニフェストに無いGitHubリポジトリから入れたMOD
+    Github,
+    /// ローカルファイルから追加したMOD（`file://`）
+    Local,
+    /// 入手元を確認できないMOD
+    Unknown,
+}
+
+/// ModManagerの操作で発生するエラー
+///
+/// GUIやテストでエラーの種類ごとに対処を分けられるよう、原因ごとに分類する。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "detail")]
+pub enum ModManagerError {
+    /// 通信の失敗やGitHub APIのエラー応答
+    NetworkError(String),
+    /// マニフェストやAPI応答、保存データの解析に失敗した
+    ParseError(String),
+    /// ファイルの読み書きに失敗した
+    FileSystemError(String),
+    /// MOD・バージョン・ファイルなどが見つからない
+    NotFound(String),
+    /// MODがプロファイルのMODローダーや導入済みのバージョンに合わない
+    LoaderMismatch(String),
+    /// 削除しようとしたMODに依存しているMODがある
+    HasDependents { mod_name: String, dependents: Vec<String> },
+    /// 上記に当てはまらないエラー
+    Other(String),
+}
+
+impl fmt::Display for ModManagerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModManagerError::NetworkError(message)
+            | ModManagerError::ParseError(message)
+            | ModManagerError::FileSystemError(message)
+            | ModManagerError::NotFound(message)
+            | ModManagerError::LoaderMismatch(message)
+            | ModManagerError::Other(message) => write!(f, "{}", message),
+            ModManagerError::HasDependents { mod_name, dependents } => write!(
+                f,
+                "{}を削除すると{}個のMODが動かなくなります: {}",
+                mod_name,
+                dependents.len(),
+                dependents.join(", ")
+            ),
+        }
+    }
+}
+
+impl Error for ModManagerError {}
+
+impl From<std::io::Error> for ModManagerError {
+    fn from(e: std::io::Error) -> Self {
+        ModManagerError::FileSystemError(e.to_string())
+    }
+}
+
+impl From<serde_json::Error> for ModManagerError {
+    fn from(e: serde_json::Error) -> Self {
+        ModManagerError::ParseError(e.to_string())
+    }
+}
+
+impl From<reqwest::Error> for ModManagerError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_decode() {
+            ModManagerError::ParseError(e.to_string())
+        } else {
+            ModManagerError::NetworkError(e.to_string())
+        }
+    }
+}
+
+impl From<String> for ModManagerError {
+    fn from(message: String) -> Self {
+        ModManagerError::Other(message)
+    }
+}
+
+impl From<&str> for ModManagerError {
+    fn from(message: &str) -> Self {
+        ModManagerError::Other(message.to_string())
+    }
+}
+
+/// 他のモジュールから返されたエラーを変換する
+impl From<Box<dyn Error + Send + Sync>> for ModManagerError {
+    fn from(e: Box<dyn Error + Send + Sync>) -> Self {
+        match e.downcast::<ModManagerError>() {
+            Ok(e) => *e,
+            Err(e) => match e.downcast::<std::io::Error>() {
+                Ok(e) => ModManagerError::from(*e),
+                Err(e) => ModManagerError::Other(e.to_string()),
+            },
+        }
+    }
 }
 
 /// 未管理MOD情報（手動で追加されたMOD）
@@ -60,6 +273,9 @@ pub struct InstalledMod {
 pub struct UnmanagedMod {
     pub file_name: String,
     pub file_path: PathBuf,
+    /// スキャンしたMODフォルダからの相対パス（サブフォルダ込み）
+    #[serde(default)]
+    pub relative_path: String,
     pub file_size: u64,
     pub modified_time: String,
     pub dll_name: String,
@@ -86,6 +302,206 @@ pub struct UpgradeableMod {
     pub latest_version: String,
     pub description: String,
     pub source_location: String,
+    /// リポジトリが削除・アーカイブされておりメンテ終了の可能性がある
+    #[serde(default)]
+    pub possibly_unmaintained: bool,
+    /// このMODを読み込むMODローダー
+    #[serde(default)]
+    pub mod_loader_type: ModLoaderType,
+}
+
+/// MODローダーごとにまとめたアップデート可能なMOD
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpgradeableModGroup {
+    pub mod_loader_type: ModLoaderType,
+    pub mods: Vec<UpgradeableMod>,
+}
+
+/// アップデート可能なMODをMODローダーごとにまとめる（RML, MonkeyLoader, BepisLoaderの順、空のグループは含めない）
+pub fn group_upgradeable_mods_by_loader(mods: Vec<UpgradeableMod>) -> Vec<UpgradeableModGroup> {
+    let mut groups: Vec<UpgradeableModGroup> = [
+        ModLoaderType::ResoniteModLoader,
+        ModLoaderType::MonkeyLoader,
+        ModLoaderType::BepisLoader,
+    ]
+    .into_iter()
+    .map(|mod_loader_type| UpgradeableModGroup { mod_loader_type, mods: Vec::new() })
+    .collect();
+
+    for upgradeable_mod in mods {
+        if let Some(group) = groups.iter_mut().find(|g| g.mod_loader_type == upgradeable_mod.mod_loader_type) {
+            group.mods.push(upgradeable_mod);
+        }
+    }
+    groups.retain(|g| !g.mods.is_empty());
+    groups
+}
+
+/// インストール済みMOD一覧の整形形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutputFormat {
+    /// Markdownテーブル（GitHub issue向け）
+    MarkdownTable,
+    /// 箇条書き（Discord向け）
+    BulletList,
+}
+
+/// 一括アップグレードの各MODの状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BulkUpgradeStatus {
+    Upgrading,
+    Completed,
+    Failed,
+}
+
+/// 一括アップグレードの進捗
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkUpgradeProgress {
+    pub mod_name: String,
+    pub from_version: String,
+    pub to_version: String,
+    pub current: usize,
+    pub total: usize,
+    pub status: BulkUpgradeStatus,
+    pub error: Option<String>,
+}
+
+/// 一括操作の1件ごとの結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BatchOutcome {
+    Succeeded,
+    Failed,
+    Skipped,
+}
+
+/// MODの一括操作（一括アップグレード・MOD一覧のインポート）全体の進捗
+///
+/// GUIで「3/15 件処理中: XXX」のように1つのプログレスバーで表示し、
+/// `finished` になった時点の件数を成功・失敗・スキップのサマリとして使う。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchProgress {
+    pub current: usize,
+    pub total: usize,
+    pub current_mod_name: Option<String>,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub finished: bool,
+}
+
+impl BatchProgress {
+    pub fn new(total: usize) -> Self {
+        BatchProgress {
+            total,
+            ..Default::default()
+        }
+    }
+
+    /// 次のMODの処理を開始する
+    pub fn start(&mut self, mod_name: &str) {
+        self.current += 1;
+        self.current_mod_name = Some(mod_name.to_string());
+    }
+
+    /// 処理中のMODの結果を記録する
+    pub fn record(&mut self, outcome: BatchOutcome) {
+        match outcome {
+            BatchOutcome::Succeeded => self.succeeded += 1,
+            BatchOutcome::Failed => self.failed += 1,
+            BatchOutcome::Skipped => self.skipped += 1,
+        }
+    }
+
+    /// すべての処理を終える
+    pub fn finish(&mut self) {
+        self.current_mod_name = None;
+        self.finished = true;
+    }
+}
+
+/// ドライランで計画されたMODの変更の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlannedModAction {
+    /// 新規インストール
+    Install,
+    /// 既存のファイルを削除して別バージョンをインストール
+    Update,
+    /// 既に同じバージョンがインストール済みのため変更なし
+    Unchanged,
+}
+
+/// ドライランで計画されたMODの変更（実際のダウンロード・ファイル変更は行わない）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedModChange {
+    pub name: String,
+    pub source_location: String,
+    pub action: PlannedModAction,
+    pub from_version: Option<String>,
+    pub to_version: String,
+    /// 実行時に削除されるファイル
+    pub remove_path: Option<PathBuf>,
+}
+
+/// インストール時に保存したバージョンごとのリリースノート
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionChangelog {
+    pub version: String,
+    /// リリースノート（Markdown）
+    pub changelog: String,
+    pub installed_at: String,
+}
+
+/// 前回のアップデートチェック結果（起動間で保持する）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedUpgradeableMods {
+    pub mods: Vec<UpgradeableMod>,
+    /// チェックした日時（RFC3339）
+    pub checked_at: String,
+    /// チェック時のマニフェストとインストール済みMODのハッシュ（変化したら無効）
+    pub fingerprint: String,
+    /// 「N時間前の情報」などの鮮度表示（読み込み時に設定）
+    #[serde(default)]
+    pub age_description: Option<String>,
+}
+
+/// インストール元リポジトリの状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RepositoryState {
+    Active,
+    Archived,
+    NotFound,
+}
+
+/// メンテ終了の可能性があるMOD
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnmaintainedMod {
+    pub name: String,
+    pub source_location: String,
+    pub state: RepositoryState,
+    pub checked_at: String,
+}
+
+/// MODのGitHubリポジトリの統計情報（インストール判断の材料）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoStats {
+    pub stars: u64,
+    pub open_issues: u64,
+    /// 最終更新日時（最後にpushされた日時、RFC3339）
+    pub last_updated: Option<String>,
+    pub archived: bool,
+    /// 長期間更新されておらずメンテが停滞している可能性がある
+    pub stale: bool,
+}
+
+/// GitHub APIのレート制限の状況
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitInfo {
+    pub remaining: u32,
+    pub limit: u32,
+    /// 残り回数がリセットされる日時（RFC3339）
+    pub reset_at: String,
+    /// 残りが少なく、MOD一覧の更新などが失敗する可能性がある
+    pub low: bool,
 }
 
 /// GitHubリリース情報
@@ -116,6 +532,39 @@ pub struct GitHubAsset {
     pub size: Option<u64>,
     #[serde(default)]
     pub download_count: Option<u64>,
+    /// GitHubが計算したアセットのハッシュ（例: "sha256:..."）
+    #[serde(default)]
+    pub digest: Option<String>,
+}
+
+impl GitHubAsset {
+    /// `digest` からSHA256を取り出す（SHA256以外・未設定の場合はNone）
+    pub fn sha256(&self) -> Option<String> {
+        self.digest.as_deref()
+            .and_then(|digest| digest.strip_prefix("sha256:"))
+            .map(|hash| hash.to_string())
+    }
+}
+
+/// エクスポート用のMOD一覧エントリ
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModListEntry {
+    pub source_location: String,
+    pub version: String,
+    #[serde(default)]
+    pub mod_loader_type: Option<String>,
+}
+
+/// MOD一覧インポートの結果（MODごと）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModImportResult {
+    pub source_location: String,
+    pub version: String,
+    pub installed_mod: Option<InstalledMod>,
+    pub error: Option<String>,
+    /// 同じバージョンがインストール済みのためスキップした
+    #[serde(default)]
+    pub skipped: bool,
 }
 
 /// ファイル配置先の選択肢
@@ -132,6 +581,24 @@ pub struct MultiFileInstallRequest {
     pub available_destinations: Vec<FileDestination>,
     pub releases: Vec<GitHubRelease>,
     pub selected_version: String,
+    /// ファイル名から推測した配置先（ユーザーの選択の初期値）
+    #[serde(default)]
+    pub suggested_choices: Vec<FileInstallChoice>,
+    /// `assets` のうちリリースではなくリポジトリ内のライブラリフォルダから見つかったファイル
+    #[serde(default)]
+    pub repository_files: Vec<String>,
+}
+
+/// GitHub Contents APIのエントリ
+#[derive(Debug, Clone, Deserialize)]
+struct GitHubContentEntry {
+    name: String,
+    #[serde(rename = "type")]
+    entry_type: String,
+    #[serde(default)]
+    download_url: Option<String>,
+    #[serde(default)]
+    size: Option<u64>,
 }
 
 /// ユーザーのファイル配置選択
@@ -214,6 +681,200 @@ struct Artifact {
     url: String,
     #[serde(default)]
     sha256: Option<String>,
+    #[serde(default)]
+    filename: Option<String>,
+    #[serde(default)]
+    platforms: Option<Vec<String>>,
+}
+
+/// resonite-mod-manifest形式のマニフェストをModInfoの一覧に変換する
+fn mods_from_manifest(manifest: ModManifest, mod_loader: ModLoaderType) -> Vec<ModInfo> {
+    let mut mods = Vec::new();
+
+    for author_entry in manifest.objects.into_values() {
+        let mut author_names: Vec<String> = author_entry.author.into_keys().collect();
+        author_names.sort();
+        let author = author_names.join(", ");
+
+        for (id, entry) in author_entry.entries {
+            let mut versions: Vec<(String, ModVersion)> = entry.versions.unwrap_or_default().into_iter().collect();
+            versions.sort_by(|a, b| utils::compare_versions(&b.0, &a.0));
+
+            let releases: Vec<ModRelease> = versions
+                .into_iter()
+                .map(|(version, mod_version)| {
+                    let artifacts: Vec<ReleaseArtifact> = mod_version.artifacts.unwrap_or_default()
+                        .into_iter()
+                        .map(|artifact| {
+                            let file_name = artifact.filename.or_else(|| artifact.url.split('/').next_back().map(|s| s.to_string()));
+                            ReleaseArtifact {
+                                platforms: artifact.platforms
+                                    .or_else(|| file_name.as_deref().and_then(platforms_from_file_name)),
+                                file_name,
+                                url: artifact.url,
+                                sha256: artifact.sha256,
+                                mirror_urls: Vec::new(),
+                            }
+                        })
+                        .collect();
+                    let primary = artifacts.first().cloned();
+
+                    ModRelease {
+                        release_url: mod_version.release_url.unwrap_or_else(|| entry.source_location.clone()),
+                        version,
+                        download_url: primary.as_ref().map(|a| a.url.clone()),
+                        published_at: String::new(),
+                        prerelease: false,
+                        draft: false,
+                        changelog: mod_version.changelog,
+                        file_name: primary.as_ref().and_then(|a| a.file_name.clone()),
+                        file_size: None,
+                        sha256: primary.and_then(|a| a.sha256),
+                        // 1ファイルのみの場合は `download_url` でインストールする
+                        artifacts: if artifacts.len() > 1 { artifacts } else { Vec::new() },
+                        min_loader_version: None,
+                        mirror_urls: Vec::new(),
+                    }
+                })
+                .collect();
+
+            mods.push(ModInfo {
+                name: entry.name,
+                description: entry.description,
+                category: Some(entry.category),
+                source_location: entry.source_location,
+                author: author.clone(),
+                latest_version: releases.first().map(|r| r.version.clone()),
+                latest_download_url: releases.first().and_then(|r| r.download_url.clone()),
+                releases,
+                tags: entry.tags,
+                flags: entry.flags,
+                last_updated: None,
+                platforms: entry.platforms,
+                repo_stats: None,
+                id: Some(id),
+                dependencies: entry.dependencies.map(|d| d.into_keys().collect()).unwrap_or_default(),
+                hot_reload: None,
+                mod_loaders: vec![mod_loader],
+            });
+        }
+    }
+
+    mods.sort_by_key(|m| m.name.to_lowercase());
+    mods
+}
+
+/// 2つのマニフェストのMOD一覧をマージする
+///
+/// 同じMOD（IDまたはソースURLが一致）は `primary` 側の情報を残し、`mod_loaders` を合わせる。
+/// `primary` 側にリリース情報が無い場合は `secondary` 側のリリースを使う。
+fn merge_manifests(primary: Vec<ModInfo>, secondary: Vec<ModInfo>) -> Vec<ModInfo> {
+    let mut merged: Vec<ModInfo> = primary
+        .into_iter()
+        .map(|mut m| {
+            if m.mod_loaders.is_empty() {
+                m.mod_loaders.push(ModLoaderType::ResoniteModLoader);
+            }
+            m
+        })
+        .collect();
+
+    for other in secondary {
+        let existing = merged.iter_mut().find(|m| {
+            (m.id.is_some() && m.id == other.id) || utils::same_source_location(&m.source_location, &other.source_location)
+        });
+
+        match existing {
+            Some(existing) => {
+                for loader in other.mod_loaders {
+                    if !existing.mod_loaders.contains(&loader) {
+                        existing.mod_loaders.push(loader);
+                    }
+                }
+                if existing.releases.is_empty() {
+                    existing.latest_version = other.latest_version;
+                    existing.latest_download_url = other.latest_download_url;
+                    existing.releases = other.releases;
+                }
+                if existing.id.is_none() {
+                    existing.id = other.id;
+                }
+            }
+            None => merged.push(other),
+        }
+    }
+
+    merged
+}
+
+/// ModManagerのネットワーク設定
+#[derive(Debug, Clone)]
+pub struct ModManagerConfig {
+    /// ダウンロード失敗時の最大再試行回数
+    pub max_retries: u32,
+    /// 接続タイムアウト
+    pub connect_timeout: Duration,
+    /// リクエスト全体のタイムアウト
+    pub request_timeout: Duration,
+    /// 同時に実行するダウンロード数
+    pub max_parallel_downloads: usize,
+    /// ホスト（github.com等）ごとの同時接続数
+    pub max_connections_per_host: usize,
+    /// 最新バージョンの選択にプレリリース版を含めるか
+    pub include_prerelease: bool,
+}
+
+impl Default for ModManagerConfig {
+    fn default() -> Self {
+        ModManagerConfig {
+            max_retries: 3,
+            connect_timeout: Duration::from_secs(10),
+            request_timeout: Duration::from_secs(300),
+            max_parallel_downloads: 4,
+            max_connections_per_host: 2,
+            include_prerelease: false,
+        }
+    }
+}
+
+/// インストール済みMODに対応するマニフェストのエントリを探す
+fn manifest_entry_for<'a>(installed_mod: &InstalledMod, all_mods: &'a [ModInfo]) -> Option<&'a ModInfo> {
+    all_mods.iter().find(|m| m.name == installed_mod.name || utils::same_source_location(&m.source_location, &installed_mod.source_location))
+}
+
+/// インストール済みMODの入手元を判定する
+///
+/// 名前が同じでも入手元が違うMODは信頼できないため、`source_location` だけで照合する。
+fn classify_mod_source(installed_mod: &InstalledMod, all_mods: &[ModInfo]) -> InstalledModSource {
+    let source = utils::normalize_source_location(&installed_mod.source_location);
+    if source.starts_with("file://") {
+        InstalledModSource::Local
+    } else if all_mods.iter().any(|m| utils::same_source_location(&m.source_location, &source)) {
+        InstalledModSource::Manifest
+    } else if source.starts_with("https://github.com/") {
+        InstalledModSource::Github
+    } else {
+        InstalledModSource::Unknown
+    }
+}
+
+/// `target` に依存しているインストール済みMODの名前を返す
+fn find_dependents(target: &InstalledMod, installed_mods: &[InstalledMod], all_mods: &[ModInfo]) -> Vec<String> {
+    // 依存関係はMOD IDで書かれるが、IDの無いエントリに備えて名前でも照合する
+    let mut target_keys = vec![target.name.to_lowercase()];
+    if let Some(id) = manifest_entry_for(target, all_mods).and_then(|m| m.id.as_ref()) {
+        target_keys.push(id.to_lowercase());
+    }
+
+    installed_mods.iter()
+        .filter(|m| m.name != target.name)
+        .filter(|m| {
+            manifest_entry_for(m, all_mods).is_some_and(|info| {
+                info.dependencies.iter().any(|dep| target_keys.contains(&dep.to_lowercase()))
+            })
+        })
+        .map(|m| m.name.clone())
+        .collect()
 }
 
 /// MOD管理システム
@@ -221,63 +882,75 @@ pub struct ModManager {
     profile_dir: PathBuf,
     mods_dir: PathBuf,
     installed_mods_file: PathBuf,
+    manifest_cache: CachedResource,
+    monkey_loader_manifest_cache: CachedResource,
+    hash_lookup_cache: CachedResource,
     client: reqwest::Client,
+    max_retries: u32,
+    download_limiter: Arc<DownloadLimiter>,
+    include_prerelease: bool,
+    install_rules_file: PathBuf,
 }
 
-impl ModManager {
-    /// 新しいModManagerを作成
-    pub fn new(profile_dir: PathBuf) -> Self {
-        let mods_dir = profile_dir.join("Game").join("rml_mods");
-        let installed_mods_file = profile_dir.join("installed_mods.json");
-        
-        ModManager {
-            profile_dir,
-            mods_dir,
-            installed_mods_file,
-            client: reqwest::Client::new(),
+/// MODマニフェストの取得元
+const MOD_MANIFEST_URL: &str = "https://raw.githubusercontent.com/resonite-love/resonite-mod-cache/master/cache/mods.json";
+/// MonkeyLoader向けMODのマニフェスト（resonite-mod-manifest形式）
+const MONKEY_LOADER_MANIFEST_URL: &str = "https://raw.githubusercontent.com/resonite-modding-group/resonite-mod-manifest/main/manifest.json";
+/// ハッシュルックアップテーブルの取得元
+const HASH_LOOKUP_URL: &str = "https://raw.githubusercontent.com/resonite-love/resonite-mod-cache/master/cache/hash-lookup.json";
+/// MonkeyLoaderのMODを無効化する際の移動先フォルダ名（`Mods` 配下は再帰的に読み込まれるため、`Mods` と同じ階層に置く）
+const DISABLED_FOLDER_NAME: &str = "DisabledMods";
+/// MODごとのリリースノート履歴の保存先
+const CHANGELOG_HISTORY_FILE: &str = "mod_changelogs.json";
+/// アップデートチェック結果の保存先
+const UPGRADE_CHECK_CACHE_FILE: &str = "upgradeable_mods_cache.json";
+/// この日数以上更新されていないリポジトリを「メンテ停滞」とみなす
+const STALE_REPO_DAYS: i64 = 365;
+/// GitHub APIのレート制限の状況を取得するURL（この呼び出し自体は回数に数えられない）
+const GITHUB_RATE_LIMIT_URL: &str = "https://api.github.com/rate_limit";
+/// 残り回数がこれ以下ならレート制限が近いとみなす
+const LOW_RATE_LIMIT_REMAINING: u32 = 10;
+/// ディスクキャッシュの有効期限（10分）
+const CACHE_DURATION: Duration = Duration::from_secs(10 * 60);
+
+/// 有効期限付きでディスクにキャッシュするリモートデータ
+#[derive(Debug, Clone)]
+struct CachedResource {
+    url: &'static str,
+    data_file: PathBuf,
+    meta_file: PathBuf,
+}
+
+impl CachedResource {
+    fn new(url: &'static str, cache_dir: &std::path::Path, name: &str) -> Self {
+        CachedResource {
+            url,
+            data_file: cache_dir.join(format!("{}.json", name)),
+            meta_file: cache_dir.join(format!("{}_meta.json", name)),
         }
     }
 
-    /// キャッシュされたMOD一覧を取得
-    pub async fn fetch_mod_manifest(&self) -> Result<Vec<ModInfo>, Box<dyn Error + Send + Sync>> {
-        // ローカルキャッシュファイルのパス
-        let cache_file = self.profile_dir.join("mod_manifest_cache.json");
-        let cache_metadata_file = self.profile_dir.join("mod_manifest_cache_meta.json");
-        
-        // キャッシュの有効期限（10分）
-        let cache_duration = Duration::from_secs(10 * 60);
+    /// 有効期限内のキャッシュを読み込む
+    fn read_valid(&self) -> Option<(String, Duration)> {
+        let metadata_content = fs::read_to_string(&self.meta_file).ok()?;
+        let cache_time = serde_json::from_str::<u64>(&metadata_content).ok()?;
+        let cache_timestamp = std::time::UNIX_EPOCH + Duration::from_secs(cache_time);
+        let elapsed = std::time::SystemTime::now().duration_since(cache_timestamp).ok()?;
         
-        // キャッシュの確認
-        if let Ok(metadata_content) = fs::read_to_string(&cache_metadata_file) {
-            if let Ok(cache_time) = serde_json::from_str::<u64>(&metadata_content) {
-                let cache_timestamp = std::time::UNIX_EPOCH + Duration::from_secs(cache_time);
-                let now = std::time::SystemTime::now();
-                
-                if let Ok(elapsed) = now.duration_since(cache_timestamp) {
-                    if elapsed < cache_duration {
-                        // キャッシュが有効な場合、キャッシュから読み込み
-                        if let Ok(cache_content) = fs::read_to_string(&cache_file) {
-                            if let Ok(cached_mods) = serde_json::from_str::<Vec<ModInfo>>(&cache_content) {
-                                println!("Using cached MOD manifest (age: {}s)", elapsed.as_secs());
-                                return Ok(cached_mods);
-                            }
-                        }
-                    }
-                }
-            }
+        if elapsed >= CACHE_DURATION {
+            return None;
         }
         
-        // キャッシュが無効または存在しない場合、リモートから取得
-        println!("Fetching MOD manifest from remote source...");
-        let cache_url = "https://raw.githubusercontent.com/resonite-love/resonite-mod-cache/master/cache/mods.json";
-        
-        let response = self.client.get(cache_url).send().await?;
-        let mods_text = response.text().await?;
-        let mods: Vec<ModInfo> = serde_json::from_str(&mods_text)?;
-        
-        // キャッシュに保存
-        if let Err(e) = fs::write(&cache_file, &mods_text) {
-            eprintln!("Failed to write MOD manifest cache: {}", e);
+        fs::read_to_string(&self.data_file).ok().map(|content| (content, elapsed))
+    }
+
+    /// キャッシュを書き込む
+    fn write(&self, content: &str) {
+        if let Some(cache_dir) = self.data_file.parent() {
+            let _ = fs::create_dir_all(cache_dir);
+        }
+        if let Err(e) = fs::write(&self.data_file, content) {
+            eprintln!("Failed to write cache {}: {}", self.data_file.display(), e);
         }
         
         let now_timestamp = std::time::SystemTime::now()
@@ -285,64 +958,536 @@ impl ModManager {
             .unwrap_or_default()
             .as_secs();
         
-        if let Err(e) = fs::write(&cache_metadata_file, serde_json::to_string(&now_timestamp).unwrap_or_default()) {
-            eprintln!("Failed to write MOD manifest cache metadata: {}", e);
+        if let Err(e) = fs::write(&self.meta_file, serde_json::to_string(&now_timestamp).unwrap_or_default()) {
+            eprintln!("Failed to write cache metadata {}: {}", self.meta_file.display(), e);
         }
-        
-        Ok(mods)
     }
+}
 
-    /// GitHubリポジトリから最新リリース情報を取得
-    pub async fn get_latest_release_info(&self, repo_url: &str) -> Result<(Option<String>, Option<String>), Box<dyn Error + Send + Sync>> {
-        // GitHubリポジトリURLからAPI URLを生成
-        let api_url = self.github_repo_to_api_url(repo_url)?;
-        
-        let response = self.client
-            .get(&format!("{}/releases/latest", api_url))
-            .header("User-Agent", "resonite-tools")
-            .send()
-            .await?;
-            
-        if !response.status().is_success() {
-            return Ok((None, None));
-        }
-        
-        let response_text = response.text().await?;
-        let release: GitHubRelease = serde_json::from_str(&response_text)
-            .map_err(|e| format!("Failed to parse GitHub release JSON: {}", e))?;
-        
-        // .dllファイルを含むアセットを探す
-        let dll_asset = release.assets.iter()
-            .find(|asset| asset.name.ends_with(".dll"));
-            
-        let download_url = dll_asset.map(|asset| asset.browser_download_url.clone());
-        
-        Ok((Some(release.tag_name), download_url))
+/// 現在のOSに対応するマニフェスト上のプラットフォーム名
+pub fn current_platform() -> &'static str {
+    if cfg!(target_os = "android") {
+        "Android"
+    } else if cfg!(target_os = "linux") {
+        "Linux"
+    } else if cfg!(target_os = "windows") {
+        "Windows"
+    } else {
+        "Other"
     }
+}
 
-    /// GitHubリポジトリから全てのリリース一覧を取得
-    pub async fn get_all_releases(&self, repo_url: &str) -> Result<Vec<GitHubRelease>, Box<dyn Error + Send + Sync>> {
-        let api_url = self.github_repo_to_api_url(repo_url)?;
-        
-        let response = self.client
-            .get(&format!("{}/releases", api_url))
-            .header("User-Agent", "resonite-tools")
-            .send()
-            .await?;
-            
-        if !response.status().is_success() {
-            return Err(format!("GitHub API request failed: {}", response.status()).into());
-        }
-        
-        let response_text = response.text().await?;
-        let releases: Vec<GitHubRelease> = serde_json::from_str(&response_text)
-            .map_err(|e| format!("Failed to parse GitHub releases JSON: {}", e))?;
-        
+/// 現在の環境で動作するプラットフォーム名（優先順）
+///
+/// LinuxではResoniteをProton経由で動かすことが多いため、Windows向けも動作するものとして扱う。
+fn compatible_platforms() -> Vec<&'static str> {
+    match current_platform() {
+        "Linux" => vec!["Linux", "Windows"],
+        platform => vec![platform],
+    }
+}
+
+/// プラットフォーム指定が現在の環境に合うか（未指定は常に合う）
+fn supports_current_platform(platforms: &Option<Vec<String>>) -> bool {
+    match platforms {
+        Some(platforms) if !platforms.is_empty() => compatible_platforms()
+            .iter()
+            .any(|current| platforms.iter().any(|p| p.eq_ignore_ascii_case(current))),
+        _ => true,
+    }
+}
+
+/// MODが現在のプラットフォームに対応していない場合の警告メッセージ
+pub fn platform_warning(mod_info: &ModInfo) -> Option<String> {
+    if supports_current_platform(&mod_info.platforms) {
+        return None;
+    }
+
+    Some(format!(
+        "{} is only available for {} (current platform: {})",
+        mod_info.name,
+        mod_info.platforms.as_deref().unwrap_or_default().join(", "),
+        current_platform()
+    ))
+}
+
+/// ファイル名からアーティファクトの対象プラットフォームを推測する
+///
+/// `Foo-win-x64.dll` / `Foo_linux.zip` のようなプラットフォーム名の付いたファイルと、
+/// ネイティブライブラリの `.so` だけを対象とする。通常のMODのDLLは.NETアセンブリで
+/// どのプラットフォームでも動くため、拡張子が `.dll` というだけでは判定しない。
+fn platforms_from_file_name(file_name: &str) -> Option<Vec<String>> {
+    let file_name = file_name.to_lowercase();
+    if file_name.ends_with(".so") {
+        return Some(vec!["Linux".to_string()]);
+    }
+
+    file_name.split(['-', '_', '.'])
+        .find_map(|token| match token {
+            "win" | "windows" | "win32" | "win64" => Some("Windows"),
+            "linux" | "linux64" => Some("Linux"),
+            "android" => Some("Android"),
+            _ => None,
+        })
+        .map(|platform| vec![platform.to_string()])
+}
+
+/// 現在のプラットフォームに合うアーティファクトを選ぶ
+///
+/// 対象プラットフォームが明示されたものを優先し、次にプラットフォーム未指定のものを選ぶ。
+fn select_artifact(artifacts: &[ReleaseArtifact]) -> Option<&ReleaseArtifact> {
+    for platform in compatible_platforms() {
+        let exact = artifacts.iter().find(|a| {
+            a.platforms
+                .as_ref()
+                .map(|platforms| platforms.iter().any(|p| p.eq_ignore_ascii_case(platform)))
+                .unwrap_or(false)
+        });
+        if exact.is_some() {
+            return exact;
+        }
+    }
+
+    artifacts
+        .iter()
+        .find(|a| a.platforms.as_ref().map(|p| p.is_empty()).unwrap_or(true))
+}
+
+/// リリースのアーティファクト一覧（`artifacts` が無い古い形式は `download_url` を1件として扱う）
+pub fn release_artifacts(release: &ModRelease) -> Vec<ReleaseArtifact> {
+    if !release.artifacts.is_empty() {
+        return release.artifacts.clone();
+    }
+
+    release.download_url.iter()
+        .map(|url| ReleaseArtifact {
+            url: url.clone(),
+            file_name: release.file_name.clone(),
+            sha256: release.sha256.clone(),
+            platforms: None,
+            mirror_urls: release.mirror_urls.clone(),
+        })
+        .collect()
+}
+
+/// アーティファクトのファイル名（未指定の場合はURLの末尾）
+fn artifact_file_name(artifact: &ReleaseArtifact) -> String {
+    artifact.file_name.clone()
+        .unwrap_or_else(|| artifact.url.split('/').next_back().unwrap_or_default().to_string())
+}
+
+/// ファイル名からMODの構成ファイルの配置先を推測する
+///
+/// MOD名と一致するDLL（DLLが1つだけの場合はそれ）をMOD本体として `rml_mods` に、
+/// それ以外のDLLは同梱ライブラリとして `Libraries` に配置する。
+pub fn guess_destination(file_name: &str, mod_name: &str, dll_count: usize) -> &'static str {
+    let lower = file_name.to_lowercase();
+    if lower.ends_with(".nupkg") {
+        return "Mods";
+    }
+    if !lower.ends_with(".dll") {
+        return "skip";
+    }
+
+    let normalize = |name: &str| -> String {
+        name.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_lowercase()
+    };
+    let stem = lower.trim_end_matches(".dll");
+    if dll_count <= 1 || normalize(stem) == normalize(mod_name) {
+        "rml_mods"
+    } else {
+        "Libraries"
+    }
+}
+
+/// 各ファイルの推測した配置先を選択肢の形で返す
+fn suggest_choices<'a>(file_names: impl Iterator<Item = &'a str> + Clone, mod_name: &str) -> Vec<FileInstallChoice> {
+    let dll_count = file_names.clone().filter(|name| name.to_lowercase().ends_with(".dll")).count();
+    file_names
+        .map(|name| FileInstallChoice {
+            asset_name: name.to_string(),
+            destination_path: guess_destination(name, mod_name, dll_count).to_string(),
+        })
+        .collect()
+}
+
+/// 現在のプラットフォームで使えるアーティファクトと推測した配置先
+///
+/// 同じファイル名のプラットフォーム別アーティファクトは `select_artifact` と同じ優先順で1つに絞る。
+pub fn suggest_artifact_choices(release: &ModRelease, mod_name: &str) -> Vec<FileInstallChoice> {
+    let artifacts = compatible_artifacts(&release_artifacts(release));
+    let file_names: Vec<String> = artifacts.iter().map(artifact_file_name).collect();
+    suggest_choices(file_names.iter().map(|name| name.as_str()), mod_name)
+}
+
+/// 現在のプラットフォームで使えるアーティファクト（ファイル名ごとに1つ）
+fn compatible_artifacts(artifacts: &[ReleaseArtifact]) -> Vec<ReleaseArtifact> {
+    let mut file_names: Vec<String> = Vec::new();
+    for artifact in artifacts {
+        let name = artifact_file_name(artifact);
+        if !file_names.contains(&name) {
+            file_names.push(name);
+        }
+    }
+
+    file_names.iter()
+        .filter_map(|name| {
+            let same_name: Vec<ReleaseArtifact> = artifacts.iter()
+                .filter(|a| artifact_file_name(a) == *name)
+                .cloned()
+                .collect();
+            select_artifact(&same_name).cloned()
+        })
+        .collect()
+}
+
+/// ロードオーダーで設定できる優先度の上限（ファイル名の接頭辞は3桁）
+const MAX_LOAD_PRIORITY: u32 = 999;
+
+/// ロードオーダーの優先度の間隔（後から間に挿入できるよう10刻みにする）
+const LOAD_PRIORITY_STEP: u32 = 10;
+
+/// ファイル名からロードオーダーの接頭辞（`000_` 形式）を取り除く
+fn strip_load_order_prefix(file_name: &str) -> &str {
+    let bytes = file_name.as_bytes();
+    if bytes.len() > 4 && bytes[..3].iter().all(|b| b.is_ascii_digit()) && bytes[3] == b'_' {
+        &file_name[4..]
+    } else {
+        file_name
+    }
+}
+
+/// 優先度に応じたファイル名（Noneの場合は接頭辞無し）
+///
+/// RMLはファイル名順にMODを読み込むため、接頭辞の数値が小さいほど先に読み込まれる。
+fn load_order_file_name(file_name: &str, priority: Option<u32>) -> String {
+    let base = strip_load_order_prefix(file_name);
+    match priority {
+        Some(priority) => format!("{:03}_{}", priority, base),
+        None => base.to_string(),
+    }
+}
+
+/// ダウンロード元の候補を試す順に並べる
+///
+/// 元のURL、マニフェストのミラーURLの順に並べ、raw.githubusercontent.com のURLは
+/// jsDelivrのCDN経由のURLも候補に加える（GitHubのリリースアセットはjsDelivrで配信されない）。
+fn download_candidates(primary: Option<&str>, mirrors: &[String]) -> Vec<String> {
+    let mut candidates: Vec<String> = Vec::new();
+    for url in primary.into_iter().chain(mirrors.iter().map(|m| m.as_str())) {
+        let url = url.trim();
+        if url.is_empty() {
+            continue;
+        }
+        let jsdelivr = jsdelivr_mirror(url);
+        for candidate in std::iter::once(url.to_string()).chain(jsdelivr) {
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+    }
+    candidates
+}
+
+/// raw.githubusercontent.com のURLをjsDelivrのURLに変換する
+fn jsdelivr_mirror(url: &str) -> Option<String> {
+    let path = url.strip_prefix("https://raw.githubusercontent.com/")?;
+    let mut parts = path.splitn(4, '/');
+    let (owner, repo, git_ref, file) = (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+    Some(format!("https://cdn.jsdelivr.net/gh/{}/{}@{}/{}", owner, repo, git_ref, file))
+}
+
+/// キャッシュの手動更新結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheRefreshResult {
+    pub manifest_mods: usize,
+    pub hash_entries: usize,
+}
+
+/// キャッシュ取得の排他ロック（複数プロファイルから同時に取得しても1回で済むようにする）
+fn cache_fetch_lock() -> &'static tokio::sync::Mutex<()> {
+    static LOCK: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    LOCK.get_or_init(|| tokio::sync::Mutex::new(()))
+}
+
+/// Contents APIで取得したリポジトリのフォルダの中身（URLごと、取得時刻付き）
+///
+/// 複数ファイルの確認はインストールのたびに行われるため、GitHub APIの呼び出し回数を抑える。
+type RepositoryContentsCache = std::sync::Mutex<HashMap<String, (std::time::Instant, Vec<GitHubContentEntry>)>>;
+
+fn repository_contents_cache() -> &'static RepositoryContentsCache {
+    static CACHE: std::sync::OnceLock<RepositoryContentsCache> = std::sync::OnceLock::new();
+    CACHE.get_or_init(|| std::sync::Mutex::new(HashMap::new()))
+}
+
+impl ModManager {
+    /// 新しいModManagerを作成
+    pub fn new(profile_dir: PathBuf) -> Self {
+        Self::with_config(profile_dir, ModManagerConfig::default())
+    }
+
+    /// MODマニフェストのキャッシュを複数プロファイルで共有するModManagerを作成
+    pub fn with_shared_cache(profile_dir: PathBuf, cache_dir: PathBuf) -> Self {
+        let mut manager = Self::new(profile_dir);
+        manager.manifest_cache = CachedResource::new(MOD_MANIFEST_URL, &cache_dir, "mods");
+        manager.monkey_loader_manifest_cache = CachedResource::new(MONKEY_LOADER_MANIFEST_URL, &cache_dir, "monkey-loader-mods");
+        manager.hash_lookup_cache = CachedResource::new(HASH_LOOKUP_URL, &cache_dir, "hash-lookup");
+        manager
+    }
+
+    /// リトライ回数やタイムアウトを指定してModManagerを作成
+    pub fn with_config(profile_dir: PathBuf, config: ModManagerConfig) -> Self {
+        let mods_dir = profile_dir.join("Game").join("rml_mods");
+        let installed_mods_file = profile_dir.join("installed_mods.json");
+        let manifest_cache = CachedResource::new(MOD_MANIFEST_URL, &profile_dir, "mod_manifest_cache");
+        let monkey_loader_manifest_cache = CachedResource::new(MONKEY_LOADER_MANIFEST_URL, &profile_dir, "monkey_loader_manifest_cache");
+        let hash_lookup_cache = CachedResource::new(HASH_LOOKUP_URL, &profile_dir, "hash_lookup_cache");
+        let install_rules_file = profile_dir.join(INSTALL_RULES_FILE);
+        
+        let client = reqwest::Client::builder()
+            .connect_timeout(config.connect_timeout)
+            .timeout(config.request_timeout)
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new());
+        
+        ModManager {
+            profile_dir,
+            mods_dir,
+            installed_mods_file,
+            manifest_cache,
+            monkey_loader_manifest_cache,
+            hash_lookup_cache,
+            client,
+            max_retries: config.max_retries,
+            download_limiter: Arc::new(DownloadLimiter::new(
+                config.max_parallel_downloads,
+                config.max_connections_per_host,
+            )),
+            include_prerelease: config.include_prerelease,
+            install_rules_file,
+        }
+    }
+
+    /// 最新バージョンの選択にプレリリース版を含めるかを設定する
+    pub fn with_include_prerelease(mut self, include_prerelease: bool) -> Self {
+        self.include_prerelease = include_prerelease;
+        self
+    }
+
+    /// ダウンロードの並列度制御を他のModManagerと共有する
+    ///
+    /// レート制限はプロセス全体で効くため、複数プロファイルを同時に操作する場合は共有する。
+    pub fn with_download_limiter(mut self, download_limiter: Arc<DownloadLimiter>) -> Self {
+        self.download_limiter = download_limiter;
+        self
+    }
+
+    /// 複数ファイルMODの配置ルール（`install_rules.json`）の場所を指定する
+    ///
+    /// 既定ではプロファイルフォルダの `install_rules.json` を使う。
+    pub fn with_install_rules_file(mut self, install_rules_file: PathBuf) -> Self {
+        self.install_rules_file = install_rules_file;
+        self
+    }
+
+    /// キャッシュされたMOD一覧を取得（MonkeyLoader向けのマニフェストもマージする）
+    ///
+    /// MonkeyLoader向けのマニフェストが取得できない場合は、RML向けのMOD一覧のみを返す。
+    pub async fn fetch_mod_manifest(&self) -> Result<Vec<ModInfo>, ModManagerError> {
+        self.load_mod_manifest(false).await
+    }
+
+    /// キャッシュを無視してMOD一覧をリモートから取得し、キャッシュを更新する
+    ///
+    /// 公開された直後のMODをキャッシュの有効期限を待たずに一覧へ反映したい場合に使う。
+    pub async fn refresh_mod_manifest(&self) -> Result<Vec<ModInfo>, ModManagerError> {
+        self.load_mod_manifest(true).await
+    }
+
+    async fn load_mod_manifest(&self, force_refresh: bool) -> Result<Vec<ModInfo>, ModManagerError> {
+        let mods_text = self.fetch_cached(&self.manifest_cache, force_refresh).await?;
+        let mods: Vec<ModInfo> = serde_json::from_str(&mods_text)?;
+        
+        match self.load_monkey_loader_manifest(force_refresh).await {
+            Ok(monkey_loader_mods) => Ok(merge_manifests(mods, monkey_loader_mods)),
+            Err(e) => {
+                eprintln!("Failed to fetch MonkeyLoader manifest: {}", e);
+                Ok(merge_manifests(mods, Vec::new()))
+            }
+        }
+    }
+
+    /// MonkeyLoader向けMODのマニフェスト（resonite-mod-manifest）を取得
+    pub async fn fetch_monkey_loader_manifest(&self) -> Result<Vec<ModInfo>, ModManagerError> {
+        self.load_monkey_loader_manifest(false).await
+    }
+
+    async fn load_monkey_loader_manifest(&self, force_refresh: bool) -> Result<Vec<ModInfo>, ModManagerError> {
+        let manifest_text = self.fetch_cached(&self.monkey_loader_manifest_cache, force_refresh).await?;
+        let manifest: ModManifest = serde_json::from_str(&manifest_text)?;
+        Ok(mods_from_manifest(manifest, ModLoaderType::MonkeyLoader))
+    }
+
+    /// 指定したMODローダー向けのマニフェストに載っているMODだけを取得
+    ///
+    /// `fetch_mod_manifest` は両方に載っているMODをRML向けの情報にまとめるため、
+    /// ローダーごとのリリースが必要な場合（ローダーの移行など）はこちらを使う。
+    pub async fn fetch_loader_manifest(&self, loader: ModLoaderType) -> Result<Vec<ModInfo>, ModManagerError> {
+        match loader {
+            ModLoaderType::ResoniteModLoader => {
+                let mods_text = self.fetch_cached(&self.manifest_cache, false).await?;
+                let mods: Vec<ModInfo> = serde_json::from_str(&mods_text)?;
+                Ok(merge_manifests(mods, Vec::new()))
+            }
+            ModLoaderType::MonkeyLoader => self.fetch_monkey_loader_manifest().await,
+            ModLoaderType::BepisLoader => Err(ModManagerError::LoaderMismatch("BepisLoader MODs are not listed in the MOD manifest".to_string())),
+        }
+    }
+
+    /// マニフェストからMODを検索（名前・説明・author・tagsの部分一致、category/tagsで絞り込み）
+    ///
+    /// クエリが空の場合は絞り込み条件に合う全件を返す。
+    pub async fn search_mods(&self, query: &str, category: Option<&str>, tags: &[String]) -> Result<Vec<ModInfo>, ModManagerError> {
+        let all_mods = self.fetch_mod_manifest().await?;
+        let query = query.trim().to_lowercase();
+        
+        Ok(all_mods.into_iter()
+            .filter(|m| {
+                category.is_none_or(|c| {
+                    m.category.as_deref().is_some_and(|mc| mc.eq_ignore_ascii_case(c))
+                })
+            })
+            .filter(|m| {
+                let mod_tags = m.tags.as_deref().unwrap_or_default();
+                tags.iter().all(|tag| mod_tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+            })
+            .filter(|m| {
+                query.is_empty()
+                    || m.name.to_lowercase().contains(&query)
+                    || m.description.to_lowercase().contains(&query)
+                    || m.author.to_lowercase().contains(&query)
+                    || m.tags.as_deref().unwrap_or_default().iter().any(|t| t.to_lowercase().contains(&query))
+            })
+            .collect())
+    }
+
+    /// MODマニフェストとハッシュルックアップのキャッシュを両方とも再取得
+    pub async fn refresh_caches(&self) -> Result<CacheRefreshResult, ModManagerError> {
+        let mods_text = self.fetch_cached(&self.manifest_cache, true).await?;
+        let mut mods: Vec<ModInfo> = serde_json::from_str(&mods_text)?;
+        
+        match self.load_monkey_loader_manifest(true).await {
+            Ok(monkey_loader_mods) => mods = merge_manifests(mods, monkey_loader_mods),
+            Err(e) => eprintln!("Failed to refresh MonkeyLoader manifest: {}", e),
+        }
+        
+        let lookup_text = self.fetch_cached(&self.hash_lookup_cache, true).await?;
+        let lookup_table: HashMap<String, HashLookupEntry> = serde_json::from_str(&lookup_text)?;
+        
+        Ok(CacheRefreshResult {
+            manifest_mods: mods.len(),
+            hash_entries: lookup_table.len(),
+        })
+    }
+
+    /// キャッシュが有効ならキャッシュから、無効ならリモートから取得してキャッシュを更新
+    async fn fetch_cached(&self, cache: &CachedResource, force_refresh: bool) -> Result<String, ModManagerError> {
+        // 他のModManagerが取得中の場合は完了を待ち、そのキャッシュを使う
+        let _fetch_guard = cache_fetch_lock().lock().await;
+        
+        if !force_refresh {
+            if let Some((content, elapsed)) = cache.read_valid() {
+                log_println!("Using cached {} (age: {}s)", cache.data_file.display(), elapsed.as_secs());
+                return Ok(content);
+            }
+        }
+        
+        // キャッシュが無効または存在しない場合、リモートから取得
+        log_println!("Fetching {} from remote source...", cache.url);
+        let response = self.client.get(cache.url).send().await?;
+        if !response.status().is_success() {
+            return Err(ModManagerError::NetworkError(format!("Failed to fetch {}: {}", cache.url, response.status())));
+        }
+        let content = response.text().await?;
+        
+        // 壊れたデータをキャッシュしないようにJSONとして検証
+        serde_json::from_str::<serde_json::Value>(&content)?;
+        cache.write(&content);
+        
+        Ok(content)
+    }
+
+    /// GitHubリポジトリから最新リリース情報を取得
+    pub async fn get_latest_release_info(&self, repo_url: &str) -> Result<(Option<String>, Option<String>), ModManagerError> {
+        // GitHubリポジトリURLからAPI URLを生成
+        let api_url = self.github_repo_to_api_url(repo_url)?;
+        
+        let response = self.client
+            .get(&format!("{}/releases/latest", api_url))
+            .header("User-Agent", "resonite-tools")
+            .send()
+            .await?;
+            
+        if !response.status().is_success() {
+            return Ok((None, None));
+        }
+        
+        let response_text = response.text().await?;
+        let release: GitHubRelease = serde_json::from_str(&response_text)
+            .map_err(|e| ModManagerError::ParseError(format!("Failed to parse GitHub release JSON: {}", e)))?;
+        
+        // .dllファイルを含むアセットを探す
+        let dll_asset = release.assets.iter()
+            .find(|asset| asset.name.ends_with(".dll"));
+            
+        let download_url = dll_asset.map(|asset| asset.browser_download_url.clone());
+        
+        Ok((Some(release.tag_name), download_url))
+    }
+
+    /// GitHubリポジトリから全てのリリース一覧を取得
+    pub async fn get_all_releases(&self, repo_url: &str) -> Result<Vec<GitHubRelease>, ModManagerError> {
+        let api_url = self.github_repo_to_api_url(repo_url)?;
+        
+        let response = self.client
+            .get(&format!("{}/releases", api_url))
+            .header("User-Agent", "resonite-tools")
+            .send()
+            .await?;
+            
+        if !response.status().is_success() {
+            return Err(ModManagerError::NetworkError(format!("GitHub API request failed: {}", response.status())));
+        }
+        
+        let response_text = response.text().await?;
+        let releases: Vec<GitHubRelease> = serde_json::from_str(&response_text)
+            .map_err(|e| ModManagerError::ParseError(format!("Failed to parse GitHub releases JSON: {}", e)))?;
+        
         Ok(releases)
     }
 
+    /// アップグレード先とする最新バージョンを決定（プレリリースの扱いは設定に従う）
+    fn latest_version_for<'a>(&self, mod_info: &'a ModInfo) -> Option<&'a str> {
+        if self.include_prerelease || mod_info.releases.is_empty() {
+            return mod_info.latest_version.as_deref();
+        }
+        
+        // リリースは新しい順に並んでいる
+        mod_info.releases.iter()
+            .find(|r| !r.prerelease && !r.draft)
+            .map(|r| r.version.as_str())
+    }
+
+    /// 指定されたMODのバージョン情報を取得（`include_prerelease` がfalseなら安定版のみ、ドラフトは常に除外）
+    pub async fn get_mod_versions(&self, mod_info: &ModInfo, include_prerelease: bool) -> Result<Vec<ModRelease>, ModManagerError> {
+        let releases = self.get_all_mod_versions(mod_info).await?;
+        Ok(releases.into_iter()
+            .filter(|r| !r.draft && (include_prerelease || !r.prerelease))
+            .collect())
+    }
+
     /// 指定されたMODの全バージョン情報を取得
-    pub async fn get_mod_versions(&self, mod_info: &ModInfo) -> Result<Vec<ModRelease>, Box<dyn Error + Send + Sync>> {
+    async fn get_all_mod_versions(&self, mod_info: &ModInfo) -> Result<Vec<ModRelease>, ModManagerError> {
         // キャッシュされたリリース情報がある場合はそれを使用
         if !mod_info.releases.is_empty() {
             return Ok(mod_info.releases.clone());
@@ -353,11 +1498,29 @@ impl ModManager {
         
         let mut mod_releases = Vec::new();
         for release in github_releases {
-            // DLLファイルを含むアセットを探す
-            let dll_asset = release.assets.iter()
-                .find(|asset| asset.name.ends_with(".dll"));
+            // DLL・NuGetパッケージのアセットをすべてアーティファクトとして扱う
+            let mut artifacts: Vec<ReleaseArtifact> = release.assets.iter()
+                .filter(|asset| asset.name.ends_with(".dll") || asset.name.ends_with(".nupkg"))
+                .map(|asset| ReleaseArtifact {
+                    url: asset.browser_download_url.clone(),
+                    file_name: Some(asset.name.clone()),
+                    sha256: asset.sha256(),
+                    platforms: platforms_from_file_name(&asset.name),
+                    mirror_urls: Vec::new(),
+                })
+                .collect();
+            // MOD名と一致するDLLを本体とし、無ければ最初のDLLを使う
+            let dll_assets: Vec<&GitHubAsset> = release.assets.iter()
+                .filter(|asset| asset.name.ends_with(".dll"))
+                .collect();
+            let dll_asset = dll_assets.iter()
+                .find(|asset| guess_destination(&asset.name, &mod_info.name, dll_assets.len()) == "rml_mods")
+                .or_else(|| dll_assets.first())
+                .copied();
             
             if let Some(asset) = dll_asset {
+                // アーティファクトを1つだけ選ぶ場合（`select_artifact`）も本体が選ばれるよう先頭に置く
+                artifacts.sort_by_key(|artifact| artifact.file_name.as_deref() != Some(asset.name.as_str()));
                 mod_releases.push(ModRelease {
                     version: release.tag_name.clone(),
                     download_url: Some(asset.browser_download_url.clone()),
@@ -368,7 +1531,11 @@ impl ModManager {
                     changelog: release.body.clone(),
                     file_name: Some(asset.name.clone()),
                     file_size: asset.size,
-                    sha256: None, // キャッシュから取得される場合のみ設定
+                    sha256: asset.sha256(),
+                    // 本体1ファイルのみの場合は従来通り `download_url` でインストールする
+                    artifacts: if artifacts.len() > 1 { artifacts } else { Vec::new() },
+                    min_loader_version: None,
+                    mirror_urls: Vec::new(),
                 });
             }
         }
@@ -376,24 +1543,74 @@ impl ModManager {
         Ok(mod_releases)
     }
 
+    /// 指定バージョンの変更履歴（Markdown）を取得
+    ///
+    /// マニフェストのchangelogが空の場合はGitHubリリースのbodyにフォールバックする。
+    pub async fn get_mod_changelog(&self, mod_info: &ModInfo, version: &str) -> Result<Option<String>, ModManagerError> {
+        let releases = self.get_mod_versions(mod_info, true).await?;
+        let changelog = releases.iter()
+            .find(|r| r.version == version)
+            .and_then(|r| r.changelog.clone())
+            .filter(|c| !c.trim().is_empty());
+        
+        if changelog.is_some() {
+            return Ok(changelog);
+        }
+        
+        // フォールバック: GitHubリリースのbodyを取得（タグの "v" 接頭辞の有無は区別しない）
+        let github_releases = self.get_all_releases(&mod_info.source_location).await?;
+        let normalized_version = version.trim_start_matches('v');
+        
+        Ok(github_releases.into_iter()
+            .find(|r| r.tag_name == version || r.tag_name.trim_start_matches('v') == normalized_version)
+            .and_then(|r| r.body)
+            .filter(|body| !body.trim().is_empty()))
+    }
+
     /// MODをインストール（キャッシュ情報を活用）
-    pub async fn install_mod_from_cache(&self, mod_info: &ModInfo, version: Option<&str>, mod_loader_type: Option<&str>) -> Result<InstalledMod, Box<dyn Error + Send + Sync>> {
+    pub async fn install_mod_from_cache(&self, mod_info: &ModInfo, version: Option<&str>, mod_loader_type: Option<&str>) -> Result<InstalledMod, ModManagerError> {
         // 指定されたバージョンまたは最新バージョンのリリース情報を取得
         let release = if let Some(target_version) = version {
             mod_info.releases.iter()
                 .find(|r| r.version == target_version)
-                .ok_or(format!("Version {} not found", target_version))?
+                .ok_or_else(|| ModManagerError::NotFound(format!("Version {} not found", target_version)))?
         } else {
             mod_info.releases.first()
-                .ok_or("No releases available")?
+                .ok_or_else(|| ModManagerError::NotFound("No releases available".to_string()))?
         };
         
-        let download_url = release.download_url.as_ref()
-            .ok_or("No download URL available for this release")?;
+        // 他プラットフォーム専用のMODはインストールしない
+        if let Some(warning) = platform_warning(mod_info) {
+            return Err(warning.into());
+        }
+        
+        // 古いローダーでは動作しないMODはインストールしない
+        self.check_loader_requirement(mod_info, release, mod_loader_type)?;
+        
+        // 複数アーティファクトがある場合は現在のプラットフォームに合うものを選ぶ
+        let (candidates, file_name, expected_sha256) = if release.artifacts.is_empty() {
+            let candidates = download_candidates(release.download_url.as_deref(), &release.mirror_urls);
+            (candidates, release.file_name.as_deref(), release.sha256.as_ref())
+        } else {
+            // 合うものが無い場合は警告を出して先頭のアーティファクトを使う
+            let artifact = match select_artifact(&release.artifacts) {
+                Some(artifact) => artifact,
+                None => {
+                    eprintln!("Warning: no artifact of {} {} targets platform {}, using {}",
+                        mod_info.name, release.version, current_platform(), artifact_file_name(&release.artifacts[0]));
+                    &release.artifacts[0]
+                }
+            };
+            let candidates = download_candidates(Some(&artifact.url), &artifact.mirror_urls);
+            (candidates, artifact.file_name.as_deref(), artifact.sha256.as_ref())
+        };
+        if candidates.is_empty() {
+            return Err(ModManagerError::NotFound("No download URL available for this release".to_string()));
+        }
         
         // ファイル名を取得
-        let file_name = release.file_name.as_deref()
-            .or_else(|| download_url.split('/').last())
+        let file_name = file_name
+            .or_else(|| candidates[0].split('/').next_back())
             .ok_or("Cannot determine file name")?;
         
         // ファイル形式とインストール先を決定
@@ -415,12 +1632,9 @@ impl ModManager {
         // インストールディレクトリを作成
         fs::create_dir_all(&install_dir)?;
         
-        // ファイルをダウンロード
-        let file_response = self.client.get(download_url).send().await?;
-        let file_content = file_response.bytes().await?;
-        
+        // ファイルをダウンロード（失敗・ハッシュ不一致の場合は次のミラーを試す）
         let file_path = install_dir.join(file_name);
-        fs::write(&file_path, file_content)?;
+        self.download_from_candidates(&candidates, &file_path, expected_sha256.map(|s| s.as_str())).await?;
         
         let installed_mod = InstalledMod {
             name: mod_info.name.clone(),
@@ -432,16 +1646,99 @@ impl ModManager {
             mod_loader_type: mod_loader_type.map(|s| s.to_string()),
             file_format: Some(file_format.to_string()),
             enabled: Some(true), // 新規インストール時は有効
+            hot_reload_capable: mod_info.hot_reload,
+            hot_reload_override: None,
+            load_priority: None,
         };
         
         // インストール済みMOD一覧に追加
         self.add_to_installed_mods(&installed_mod)?;
         
+        // 後から「何が変わったか」を振り返れるようリリースノートを保存
+        if let Some(changelog) = release.changelog.as_deref().filter(|c| !c.trim().is_empty()) {
+            if let Err(e) = self.save_changelog(&mod_info.name, &release.version, changelog) {
+                eprintln!("Failed to save changelog for {}: {}", mod_info.name, e);
+            }
+        }
+        
         Ok(installed_mod)
     }
 
+    /// リリースが要求する最小ローダーバージョンをプロファイルのローダーが満たすか確認
+    fn check_loader_requirement(&self, mod_info: &ModInfo, release: &ModRelease, mod_loader_type: Option<&str>) -> Result<(), ModManagerError> {
+        let Some(required) = release.min_loader_version.as_deref() else {
+            return Ok(());
+        };
+
+        let game_dir = self.profile_dir.join("Game");
+        let (loader_name, installed_version) = match mod_loader_type {
+            Some("MonkeyLoader") => {
+                let version = MonkeyLoader::new(game_dir).get_status().ok().and_then(|status| status.version);
+                ("MonkeyLoader", version)
+            }
+            Some("ResoniteModLoader") => {
+                let version = ModLoader::new(game_dir).get_status().ok().and_then(|status| status.version);
+                ("ResoniteModLoader", version)
+            }
+            _ => {
+                // どのMODローダーで読み込むか分からない場合は判断できないためインストールを続行する
+                log_println!("No mod loader selected, skipping requirement check for {}", mod_info.name);
+                return Ok(());
+            }
+        };
+
+        match installed_version {
+            None => Err(ModManagerError::LoaderMismatch(format!(
+                "{} {} requires {} {} or later, but it is not installed",
+                mod_info.name, release.version, loader_name, required
+            ))),
+            Some(version) if version == "Unknown" => {
+                // バージョンが分からない場合は判断できないためインストールを続行する
+                log_println!("Cannot determine {} version, skipping requirement check for {}", loader_name, mod_info.name);
+                Ok(())
+            }
+            Some(version) if utils::compare_versions(&version, required) == std::cmp::Ordering::Less => {
+                Err(ModManagerError::LoaderMismatch(format!(
+                    "{} {} requires {} {} or later (installed: {}). Please update the mod loader.",
+                    mod_info.name, release.version, loader_name, required, version
+                )))
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// 保存済みのリリースノート履歴を新しい順に取得
+    pub fn get_mod_changelog_history(&self, mod_name: &str) -> Vec<VersionChangelog> {
+        let mut history = self.load_changelog_history().remove(mod_name).unwrap_or_default();
+        history.reverse();
+        history
+    }
+
+    fn load_changelog_history(&self) -> HashMap<String, Vec<VersionChangelog>> {
+        fs::read_to_string(self.profile_dir.join(CHANGELOG_HISTORY_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// リリースノートを履歴に追加（同じバージョンは上書き）
+    fn save_changelog(&self, mod_name: &str, version: &str, changelog: &str) -> Result<(), ModManagerError> {
+        let mut history = self.load_changelog_history();
+        let entries = history.entry(mod_name.to_string()).or_default();
+        entries.retain(|entry| entry.version != version);
+        entries.push(VersionChangelog {
+            version: version.to_string(),
+            changelog: changelog.to_string(),
+            installed_at: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        });
+        
+        let content = serde_json::to_string_pretty(&history)?;
+        fs::write(self.profile_dir.join(CHANGELOG_HISTORY_FILE), content)?;
+        Ok(())
+    }
+
     /// GitHubリリースの複数ファイルをチェックし、選択が必要かどうかを判定
-    pub async fn check_multi_file_install(&self, repo_url: &str, version: Option<&str>) -> Result<Option<MultiFileInstallRequest>, Box<dyn Error + Send + Sync>> {
+    pub async fn check_multi_file_install(&self, repo_url: &str, version: Option<&str>) -> Result<Option<MultiFileInstallRequest>, ModManagerError> {
         let api_url = self.github_repo_to_api_url(repo_url)?;
         
         // 全てのリリースを取得
@@ -453,27 +1750,27 @@ impl ModManager {
             .await?;
             
         if !all_releases_response.status().is_success() {
-            return Err(format!("GitHub API request failed: {}", all_releases_response.status()).into());
+            return Err(ModManagerError::NetworkError(format!("GitHub API request failed: {}", all_releases_response.status())));
         }
         
         let all_releases_text = all_releases_response.text().await?;
         let all_releases: Vec<GitHubRelease> = serde_json::from_str(&all_releases_text)
-            .map_err(|e| format!("Failed to parse GitHub releases JSON: {}. Response: {}", e, &all_releases_text[..200.min(all_releases_text.len())]))?;
+            .map_err(|e| ModManagerError::ParseError(format!("Failed to parse GitHub releases JSON: {}. Response: {}", e, &all_releases_text[..200.min(all_releases_text.len())])))?;
         
         // 指定されたバージョンまたは最新リリースを選択
         let selected_release = if let Some(version) = version {
             all_releases.iter()
                 .find(|r| r.tag_name == version)
-                .ok_or(format!("Version {} not found", version))?
+                .ok_or_else(|| ModManagerError::NotFound(format!("Version {} not found", version)))?
         } else {
             all_releases.first()
-                .ok_or("No releases found")?
+                .ok_or_else(|| ModManagerError::NotFound("No releases found".to_string()))?
         };
         
         let selected_version = selected_release.tag_name.clone();
         
         // ソースコードとアーカイブファイルを除外したファイルを抽出
-        let installable_assets: Vec<GitHubAsset> = selected_release.assets.iter()
+        let mut installable_assets: Vec<GitHubAsset> = selected_release.assets.iter()
             .filter(|asset| {
                 let name_lower = asset.name.to_lowercase();
                 // ソースコード関連ファイルを除外
@@ -490,6 +1787,19 @@ impl ModManager {
             .cloned()
             .collect();
         
+        // リリースに含まれないMOD本体以外のライブラリをリポジトリから探す
+        let mod_name = repo_url.trim_end_matches('/').split('/').next_back().unwrap_or_default();
+        let install_rules = self.load_install_rules();
+        let repository_assets: Vec<GitHubAsset> = self
+            .fetch_repository_libraries(&api_url, &selected_version, &install_rules.library_dirs(mod_name))
+            .await
+            .into_iter()
+            .filter(|asset| !installable_assets.iter().any(|a| a.name.eq_ignore_ascii_case(&asset.name)))
+            .collect();
+        let repository_files: Vec<String> = repository_assets.iter().map(|a| a.name.clone()).collect();
+        let release_asset_count = installable_assets.len();
+        installable_assets.extend(repository_assets.iter().cloned());
+        
         // 2つ以上のファイルがある場合、選択が必要
         if installable_assets.len() >= 2 {
             let available_destinations = vec![
@@ -515,11 +1825,21 @@ impl ModManager {
                 },
             ];
             
+            let mut suggested_choices = suggest_choices(installable_assets[..release_asset_count].iter().map(|a| a.name.as_str()), mod_name);
+            // リポジトリ内のライブラリは付随ライブラリとして配置する
+            suggested_choices.extend(repository_assets.iter().map(|asset| FileInstallChoice {
+                asset_name: asset.name.clone(),
+                destination_path: "Libraries".to_string(),
+            }));
+            install_rules.apply(mod_name, &mut suggested_choices);
+            
             Ok(Some(MultiFileInstallRequest {
                 assets: installable_assets,
                 available_destinations,
                 releases: all_releases,
                 selected_version,
+                suggested_choices,
+                repository_files,
             }))
         } else {
             Ok(None)
@@ -527,7 +1847,7 @@ impl ModManager {
     }
 
     /// 複数ファイルをユーザーの選択に基づいてインストール
-    pub async fn install_multiple_files(&self, repo_url: &str, version: Option<&str>, choices: Vec<FileInstallChoice>) -> Result<Vec<InstalledMod>, Box<dyn Error + Send + Sync>> {
+    pub async fn install_multiple_files(&self, repo_url: &str, version: Option<&str>, choices: Vec<FileInstallChoice>) -> Result<Vec<InstalledMod>, ModManagerError> {
         let api_url = self.github_repo_to_api_url(repo_url)?;
         
         // リリース情報を再取得
@@ -546,7 +1866,9 @@ impl ModManager {
         let response_text = response.text().await?;
         let release: GitHubRelease = serde_json::from_str(&response_text)?;
         
-        let mut installed_mods = Vec::new();
+        // ダウンロード対象を先に決定し、まとめて並列ダウンロードする
+        let mut planned = Vec::new();
+        let mut repository_assets: Option<Vec<GitHubAsset>> = None;
         
         for choice in choices {
             // スキップが選択された場合は何もしない
@@ -554,20 +1876,54 @@ impl ModManager {
                 continue;
             }
             
-            // 選択されたアセットを見つける
-            let asset = release.assets.iter()
-                .find(|a| a.name == choice.asset_name)
-                .ok_or(format!("Asset {} not found", choice.asset_name))?;
+            // 選択されたアセットを見つける（リリースに無い場合はリポジトリ内のライブラリ）
+            let asset = match release.assets.iter().find(|a| a.name == choice.asset_name) {
+                Some(asset) => asset.clone(),
+                None => {
+                    if repository_assets.is_none() {
+                        let mod_name = repo_url.trim_end_matches('/').split('/').next_back().unwrap_or_default();
+                        let library_dirs = self.load_install_rules().library_dirs(mod_name);
+                        repository_assets = Some(self.fetch_repository_libraries(&api_url, &release.tag_name, &library_dirs).await);
+                    }
+                    repository_assets.iter().flatten()
+                        .find(|a| a.name == choice.asset_name)
+                        .cloned()
+                        .ok_or_else(|| ModManagerError::NotFound(format!("Asset {} not found", choice.asset_name)))?
+                }
+            };
             
             // インストール先ディレクトリを決定
-            let install_dir = match choice.destination_path.as_str() {
-                "rml_mods" => self.mods_dir.clone(),
-                "Mods" => self.profile_dir.join("Game").join("MonkeyLoader").join("Mods"),
-                "Libraries" => self.profile_dir.join("Game").join("Resonite_Data").join("Managed"),
-                "RuntimeData" => self.profile_dir.join("Game").join("RuntimeData"),
-                _ => return Err(format!("Invalid destination: {}", choice.destination_path).into()),
-            };
+            let install_dir = self.destination_dir(&choice.destination_path)?;
+            
+            // インストールディレクトリを作成
+            fs::create_dir_all(&install_dir)?;
             
+            let file_path = install_dir.join(&asset.name);
+            planned.push((asset, choice.destination_path, file_path));
+        }
+        
+        let downloads = planned.iter()
+            .map(|(asset, _, file_path)| (asset.browser_download_url.clone(), file_path.clone()))
+            .collect();
+        let results = self.download_files(downloads).await;
+        
+        // 1つでも失敗した場合は書き込んだファイルを削除し、記録されないファイルを残さない
+        let failure = planned.iter().zip(&results)
+            .find_map(|((asset, _, _), result)| result.as_ref().err().map(|e| format!("Failed to download {}: {}", asset.name, e)));
+        if let Some(message) = failure {
+            for ((_, _, file_path), result) in planned.iter().zip(&results) {
+                if result.is_ok() {
+                    if let Err(e) = fs::remove_file(file_path) {
+                        eprintln!("Failed to remove {}: {}", file_path.display(), e);
+                    }
+                }
+            }
+            return Err(ModManagerError::NetworkError(message));
+        }
+        
+        let mut installed_mods = Vec::new();
+        
+        for (asset, destination_path, file_path) in planned {
             // ファイル形式を判定（拡張子から）
             let file_format = if let Some(ext) = std::path::Path::new(&asset.name).extension() {
                 ext.to_string_lossy().to_string()
@@ -575,18 +1931,8 @@ impl ModManager {
                 "unknown".to_string()
             };
             
-            // インストールディレクトリを作成
-            fs::create_dir_all(&install_dir)?;
-            
-            // ファイルをダウンロード
-            let file_response = self.client.get(&asset.browser_download_url).send().await?;
-            let file_content = file_response.bytes().await?;
-            
-            let file_path = install_dir.join(&asset.name);
-            fs::write(&file_path, file_content)?;
-            
             // MODローダータイプを判定
-            let mod_loader_type = if choice.destination_path == "Mods" {
+            let mod_loader_type = if destination_path == "Mods" {
                 Some("MonkeyLoader".to_string())
             } else {
                 None
@@ -602,6 +1948,9 @@ impl ModManager {
                 mod_loader_type,
                 file_format: Some(file_format.to_string()),
                 enabled: Some(true),
+                hot_reload_capable: None,
+                hot_reload_override: None,
+                load_priority: None,
             };
             
             // インストール済みMOD一覧に追加
@@ -612,9 +1961,190 @@ impl ModManager {
         Ok(installed_mods)
     }
 
-    /// GitHubリポジトリからMODをインストール（フォールバック）
-    pub async fn install_mod_from_github(&self, repo_url: &str, version: Option<&str>, mod_loader_type: Option<&str>) -> Result<InstalledMod, Box<dyn Error + Send + Sync>> {
-        let api_url = self.github_repo_to_api_url(repo_url)?;
+    /// リポジトリ内のライブラリフォルダ（`Libs/` など）にあるDLLを取得する
+    ///
+    /// フォルダが存在しない・取得できない場合は、そのフォルダを無視する。
+    async fn fetch_repository_libraries(&self, api_url: &str, git_ref: &str, library_dirs: &[String]) -> Vec<GitHubAsset> {
+        let mut assets: Vec<GitHubAsset> = Vec::new();
+        
+        for dir in library_dirs {
+            let contents_url = format!("{}/contents/{}?ref={}", api_url, dir.trim_matches('/'), git_ref);
+            let Some(entries) = self.fetch_repository_contents(&contents_url).await else {
+                continue;
+            };
+            
+            for entry in entries {
+                if entry.entry_type != "file" || !entry.name.to_lowercase().ends_with(".dll") {
+                    continue;
+                }
+                if assets.iter().any(|a| a.name.eq_ignore_ascii_case(&entry.name)) {
+                    continue;
+                }
+                if let Some(download_url) = entry.download_url {
+                    assets.push(GitHubAsset {
+                        name: entry.name,
+                        browser_download_url: download_url,
+                        content_type: None,
+                        size: entry.size,
+                        download_count: None,
+                        digest: None,
+                    });
+                }
+            }
+        }
+        
+        assets
+    }
+
+    /// プロファイルの配置ルールを読み込む（壊れている場合はエラーを表示して既定のルールを使う）
+    fn load_install_rules(&self) -> InstallRules {
+        InstallRules::load(&self.install_rules_file).unwrap_or_else(|e| {
+            eprintln!("Failed to load install rules {}, using the defaults: {}", self.install_rules_file.display(), e);
+            InstallRules::default()
+        })
+    }
+
+    /// Contents APIでリポジトリのフォルダの中身を取得する（有効期限内はキャッシュを使う）
+    ///
+    /// フォルダが無い場合は空の一覧をキャッシュし、レート制限などで取得できない場合はNoneを返す。
+    async fn fetch_repository_contents(&self, contents_url: &str) -> Option<Vec<GitHubContentEntry>> {
+        {
+            let cache = repository_contents_cache().lock().unwrap_or_else(|e| e.into_inner());
+            if let Some((fetched_at, entries)) = cache.get(contents_url) {
+                if fetched_at.elapsed() < CACHE_DURATION {
+                    return Some(entries.clone());
+                }
+            }
+        }
+
+        let entries = match self.client
+            .get(contents_url)
+            .header("User-Agent", "resonite-tools")
+            .send()
+            .await
+        {
+            // ファイルを指定した場合は配列ではなく単一のエントリが返るため、一覧として読めなければ空とする
+            Ok(response) if response.status().is_success() => response.json().await.unwrap_or_default(),
+            Ok(response) if response.status() == reqwest::StatusCode::NOT_FOUND => Vec::new(),
+            Ok(_) => return None,
+            Err(e) => {
+                eprintln!("Failed to list repository folder {}: {}", contents_url, e);
+                return None;
+            }
+        };
+
+        repository_contents_cache()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(contents_url.to_string(), (std::time::Instant::now(), entries.clone()));
+        Some(entries)
+    }
+
+    /// 複数ファイル選択の配置先（`FileDestination::path`）に対応するディレクトリ
+    fn destination_dir(&self, destination: &str) -> Result<PathBuf, ModManagerError> {
+        match destination {
+            "rml_mods" => Ok(self.mods_dir.clone()),
+            "Mods" => Ok(self.profile_dir.join("Game").join("MonkeyLoader").join("Mods")),
+            "Libraries" => Ok(self.profile_dir.join("Game").join("Resonite_Data").join("Managed")),
+            "RuntimeData" => Ok(self.profile_dir.join("Game").join("RuntimeData")),
+            _ => Err(format!("Invalid destination: {}", destination).into()),
+        }
+    }
+
+    /// マニフェストのリリースに含まれる複数のアーティファクトをインストール
+    ///
+    /// `choices` が無い場合は `suggest_artifact_choices` で推測した配置先にすべてインストールする。
+    /// 各ファイルはアーティファクトのsha256で検証し、MOD本体以外も個別のエントリとして記録する。
+    pub async fn install_mod_artifacts(
+        &self,
+        mod_info: &ModInfo,
+        version: Option<&str>,
+        choices: Option<Vec<FileInstallChoice>>,
+        mod_loader_type: Option<&str>,
+    ) -> Result<Vec<InstalledMod>, ModManagerError> {
+        let releases = self.get_all_mod_versions(mod_info).await?;
+        let release = if let Some(target_version) = version {
+            releases.iter()
+                .find(|r| r.version == target_version)
+                .ok_or_else(|| ModManagerError::NotFound(format!("Version {} not found", target_version)))?
+        } else {
+            releases.first()
+                .ok_or_else(|| ModManagerError::NotFound("No releases available".to_string()))?
+        };
+        
+        // 他プラットフォーム専用のMODはインストールしない
+        if let Some(warning) = platform_warning(mod_info) {
+            return Err(warning.into());
+        }
+        self.check_loader_requirement(mod_info, release, mod_loader_type)?;
+        
+        let artifacts = compatible_artifacts(&release_artifacts(release));
+        if artifacts.is_empty() {
+            return Err(ModManagerError::NotFound(format!("No artifact available for platform {}", current_platform())));
+        }
+        let choices = choices.unwrap_or_else(|| suggest_artifact_choices(release, &mod_info.name));
+        
+        let mut installed_mods = Vec::new();
+        
+        for choice in choices {
+            if choice.destination_path == "skip" {
+                continue;
+            }
+            
+            let artifact = artifacts.iter()
+                .find(|a| artifact_file_name(a) == choice.asset_name)
+                .ok_or_else(|| ModManagerError::NotFound(format!("Artifact {} not found", choice.asset_name)))?;
+            
+            let install_dir = self.destination_dir(&choice.destination_path)?;
+            fs::create_dir_all(&install_dir)?;
+            
+            // 失敗・ハッシュ不一致の場合は次のミラーを試す
+            let file_path = install_dir.join(&choice.asset_name);
+            let candidates = download_candidates(Some(&artifact.url), &artifact.mirror_urls);
+            self.download_from_candidates(&candidates, &file_path, artifact.sha256.as_deref()).await?;
+            
+            let file_format = std::path::Path::new(&choice.asset_name).extension()
+                .map(|ext| ext.to_string_lossy().to_string())
+                .unwrap_or_else(|| "unknown".to_string());
+            
+            // MOD本体はMOD名で、同梱ファイルはファイル名で記録する
+            let is_main = choice.destination_path == "rml_mods" || choice.destination_path == "Mods";
+            let name = if is_main && !installed_mods.iter().any(|m: &InstalledMod| m.name == mod_info.name) {
+                mod_info.name.clone()
+            } else {
+                choice.asset_name.trim_end_matches(&format!(".{}", file_format)).to_string()
+            };
+            let loader = if choice.destination_path == "Mods" {
+                Some("MonkeyLoader".to_string())
+            } else {
+                mod_loader_type.map(|s| s.to_string())
+            };
+            
+            let installed_mod = InstalledMod {
+                name,
+                description: release.changelog.clone().unwrap_or_else(|| mod_info.description.clone()),
+                source_location: mod_info.source_location.clone(),
+                installed_version: release.version.clone(),
+                installed_date: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                dll_path: file_path,
+                mod_loader_type: loader,
+                file_format: Some(file_format),
+                enabled: Some(true),
+                hot_reload_capable: if is_main { mod_info.hot_reload } else { None },
+                hot_reload_override: None,
+                load_priority: None,
+            };
+            
+            self.add_to_installed_mods(&installed_mod)?;
+            installed_mods.push(installed_mod);
+        }
+        
+        Ok(installed_mods)
+    }
+
+    /// GitHubリポジトリからMODをインストール（フォールバック）
+    pub async fn install_mod_from_github(&self, repo_url: &str, version: Option<&str>, mod_loader_type: Option<&str>) -> Result<InstalledMod, ModManagerError> {
+        let api_url = self.github_repo_to_api_url(repo_url)?;
         
         // 指定されたバージョンまたは最新リリースを取得
         let release_url = if let Some(version) = version {
@@ -630,14 +2160,14 @@ impl ModManager {
             .await?;
             
         if !response.status().is_success() {
-            return Err(format!("GitHub API request failed: {}", response.status()).into());
+            return Err(ModManagerError::NetworkError(format!("GitHub API request failed: {}", response.status())));
         }
         
         // レスポンステキストを取得してデバッグ
         let response_text = response.text().await?;
         
         let release: GitHubRelease = serde_json::from_str(&response_text)
-            .map_err(|e| format!("Failed to parse GitHub release JSON: {}. Response: {}", e, &response_text[..200.min(response_text.len())]))?;
+            .map_err(|e| ModManagerError::ParseError(format!("Failed to parse GitHub release JSON: {}. Response: {}", e, &response_text[..200.min(response_text.len())])))?;
         
         // MODローダータイプに応じてファイルを探す
         let (asset, file_format, install_dir) = if mod_loader_type == Some("MonkeyLoader") {
@@ -648,13 +2178,13 @@ impl ModManager {
             } else if let Some(dll_asset) = release.assets.iter().find(|asset| asset.name.ends_with(".dll")) {
                 (dll_asset, "dll", self.mods_dir.clone()) // MonkeyLoaderでもDLLはrml_modsフォルダに
             } else {
-                return Err("No NuGet package or DLL file found in release for MonkeyLoader".into());
+                return Err(ModManagerError::NotFound("No NuGet package or DLL file found in release for MonkeyLoader".to_string()));
             }
         } else {
             // ResoniteModLoaderの場合、DLLファイルのみ
             let dll_asset = release.assets.iter()
                 .find(|asset| asset.name.ends_with(".dll"))
-                .ok_or("No DLL file found in release")?;
+                .ok_or_else(|| ModManagerError::NotFound("No DLL file found in release".to_string()))?;
             (dll_asset, "dll", self.mods_dir.clone())
         };
         
@@ -662,11 +2192,8 @@ impl ModManager {
         fs::create_dir_all(&install_dir)?;
         
         // ファイルをダウンロード
-        let file_response = self.client.get(&asset.browser_download_url).send().await?;
-        let file_content = file_response.bytes().await?;
-        
         let file_path = install_dir.join(&asset.name);
-        fs::write(&file_path, file_content)?;
+        self.download_to_file(&asset.browser_download_url, &file_path).await?;
         
         let installed_mod = InstalledMod {
             name: asset.name.trim_end_matches(&format!(".{}", file_format)).to_string(),
@@ -678,6 +2205,72 @@ impl ModManager {
             mod_loader_type: mod_loader_type.map(|s| s.to_string()),
             file_format: Some(file_format.to_string()),
             enabled: Some(true), // 新規インストール時は有効
+            hot_reload_capable: None,
+            hot_reload_override: None,
+            load_priority: None,
+        };
+        
+        // インストール済みMOD一覧に追加
+        self.add_to_installed_mods(&installed_mod)?;
+        
+        Ok(installed_mod)
+    }
+
+    /// ローカルのMODファイルをインストール
+    ///
+    /// MODローダーは拡張子から決まる（.dllはResoniteModLoader、.nupkgはMonkeyLoader）。
+    /// `mod_loader_type` を指定した場合、拡張子と合わなければエラーを返す。
+    pub async fn install_mod_from_local_file(&self, file_path: &std::path::Path, mod_loader_type: Option<&str>) -> Result<InstalledMod, ModManagerError> {
+        if !file_path.is_file() {
+            return Err(ModManagerError::NotFound(format!("File not found: {}", file_path.display())));
+        }
+        
+        // 拡張子から配置先を決定
+        let extension = file_path.extension().and_then(|ext| ext.to_str()).map(|ext| ext.to_lowercase());
+        let (file_format, install_dir, loader) = match extension.as_deref() {
+            Some("dll") => ("dll", self.mods_dir.clone(), "ResoniteModLoader"),
+            Some("nupkg") => ("nupkg", self.profile_dir.join("Game").join("MonkeyLoader").join("Mods"), "MonkeyLoader"),
+            _ => return Err(format!("Unsupported mod file type: {}", file_path.display()).into()),
+        };
+        
+        if let Some(requested) = mod_loader_type {
+            if requested != loader {
+                return Err(ModManagerError::LoaderMismatch(format!(
+                    "{} is a {} file and cannot be installed as a {} MOD",
+                    file_path.display(), file_format, requested
+                )));
+            }
+        }
+        
+        let file_name = file_path.file_name()
+            .ok_or("Invalid file path")?;
+        
+        // インストールディレクトリを作成してコピー
+        fs::create_dir_all(&install_dir)?;
+        let dest_path = install_dir.join(file_name);
+        fs::copy(file_path, &dest_path)?;
+        
+        // ハッシュからバージョンを検出
+        let hash = self.calculate_file_sha256(&dest_path)?;
+        let detected_version = self.find_mod_by_hash(&hash).await.map(|entry| entry.version);
+        
+        let name = dest_path.file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        
+        let installed_mod = InstalledMod {
+            description: format!("ローカルファイルからインストールされたMOD: {}", name),
+            name,
+            source_location: format!("file://{}", file_path.display()),
+            installed_version: detected_version.unwrap_or_else(|| "unknown".to_string()),
+            installed_date: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            dll_path: dest_path,
+            mod_loader_type: Some(loader.to_string()),
+            file_format: Some(file_format.to_string()),
+            enabled: Some(true),
+            hot_reload_capable: None,
+            hot_reload_override: None,
+            load_priority: None,
         };
         
         // インストール済みMOD一覧に追加
@@ -687,7 +2280,7 @@ impl ModManager {
     }
 
     /// インストール済みMOD一覧を取得
-    pub fn get_installed_mods(&self) -> Result<Vec<InstalledMod>, Box<dyn Error + Send + Sync>> {
+    pub fn get_installed_mods(&self) -> Result<Vec<InstalledMod>, ModManagerError> {
         if !self.installed_mods_file.exists() {
             return Ok(Vec::new());
         }
@@ -739,6 +2332,25 @@ impl ModManager {
             }
         }
         
+        // インストール元URLを正規化（末尾の "/" や ".git" の違いで別物として扱わない）
+        for mod_info in &mut mods {
+            let normalized = utils::normalize_source_location(&mod_info.source_location);
+            if normalized != mod_info.source_location {
+                mod_info.source_location = normalized;
+                needs_migration = true;
+            }
+        }
+        
+        // 重複エントリをマージ
+        let (merged_mods, merge_logs) = Self::merge_duplicate_mods(mods);
+        mods = merged_mods;
+        if !merge_logs.is_empty() {
+            needs_migration = true;
+            for log in &merge_logs {
+                log_println!("Merged duplicate installed mod entry: {}", log);
+            }
+        }
+        
         // マイグレーションが実行された場合、ファイルを更新
         if needs_migration {
             self.save_installed_mods(&mods)?;
@@ -750,11 +2362,93 @@ impl ModManager {
             .collect())
     }
 
+    /// 同じMODの重複エントリをマージ（最新情報を優先）
+    ///
+    /// 同じファイルを指すもの、インストール元とファイル名が同じもの、名前が同じで片方のファイルが
+    /// 無くなっているものを重複とみなす。名前が同じでも両方のファイルが存在する場合は別のコピーとして残す。
+    fn merge_duplicate_mods(mods: Vec<InstalledMod>) -> (Vec<InstalledMod>, Vec<String>) {
+        let mut merged: Vec<InstalledMod> = Vec::new();
+        let mut logs = Vec::new();
+        
+        for mod_info in mods {
+            let Some((pos, reason)) = merged.iter()
+                .enumerate()
+                .find_map(|(pos, m)| Self::duplicate_reason(m, &mod_info).map(|reason| (pos, reason))) else {
+                merged.push(mod_info);
+                continue;
+            };
+            let existing = &mut merged[pos];
+            
+            let (mut preferred, other) = if Self::is_preferred_entry(&mod_info, existing) {
+                (mod_info, existing.clone())
+            } else {
+                (existing.clone(), mod_info)
+            };
+            
+            logs.push(format!(
+                "{} ({}): kept {} installed at {}, dropped {} installed at {}",
+                preferred.name, reason,
+                preferred.installed_version, preferred.installed_date,
+                other.installed_version, other.installed_date
+            ));
+            
+            // 優先エントリで欠けている情報を補完
+            if preferred.description.is_empty() {
+                preferred.description = other.description;
+            }
+            if preferred.source_location.is_empty() {
+                preferred.source_location = other.source_location;
+            }
+            
+            *existing = preferred;
+        }
+        
+        (merged, logs)
+    }
+    
+    /// 2つのエントリが同じMODの重複であればその理由を返す
+    fn duplicate_reason(a: &InstalledMod, b: &InstalledMod) -> Option<&'static str> {
+        if a.dll_path == b.dll_path {
+            Some("same file")
+        } else if Self::is_same_mod_file(a, b) {
+            Some("same source and file name")
+        } else if a.name == b.name && !(a.dll_path.exists() && b.dll_path.exists()) {
+            Some("same name, file missing")
+        } else {
+            None
+        }
+    }
+    
+    /// インストール元とDLLのファイル名が同じか（名前が同じでも別リポジトリのMODは別物として扱う）
+    fn is_same_mod_file(a: &InstalledMod, b: &InstalledMod) -> bool {
+        let same_file_name = match (a.dll_path.file_name(), b.dll_path.file_name()) {
+            (Some(a), Some(b)) => a.to_string_lossy().eq_ignore_ascii_case(&b.to_string_lossy()),
+            _ => false,
+        };
+        same_file_name && utils::same_source_location(&a.source_location, &b.source_location)
+    }
+    
+    /// 重複エントリのうちcandidateを優先すべきか判定（ファイルが存在するもの、次に新しいもの）
+    fn is_preferred_entry(candidate: &InstalledMod, current: &InstalledMod) -> bool {
+        let candidate_exists = candidate.dll_path.exists();
+        let current_exists = current.dll_path.exists();
+        if candidate_exists != current_exists {
+            return candidate_exists;
+        }
+        
+        candidate.installed_date >= current.installed_date
+    }
+
     /// MODフォルダをスキャンして全MODファイルを検出（RMLとMonkeyLoader両方）
-    pub fn scan_mod_folder(&self) -> Result<Vec<UnmanagedMod>, Box<dyn Error + Send + Sync>> {
+    pub fn scan_mod_folder(&self) -> Result<Vec<UnmanagedMod>, ModManagerError> {
         let mut unmanaged_mods = Vec::new();
         let known_mods = self.get_installed_mods().unwrap_or_default();
         
+        // 既知MODとの重複判定は絶対パスで行う
+        let known_paths: Vec<PathBuf> = known_mods.iter()
+            .map(|known_mod| Self::normalize_path(&known_mod.dll_path))
+            .collect();
+        
         // スキャンするディレクトリのリスト
         let scan_dirs = vec![
             (self.mods_dir.clone(), vec!["dll"]), // RML mods と MonkeyLoader dll mods
@@ -766,65 +2460,86 @@ impl ModManager {
                 continue;
             }
             
-            // フォルダ内の全ファイルを取得
-            for entry in fs::read_dir(&dir)? {
-                let entry = entry?;
-                let path = entry.path();
+            // サブフォルダを含む全ファイルを取得
+            let mut files = Vec::new();
+            Self::collect_files_recursive(&dir, &extensions, &mut files)?;
+            
+            for path in files {
+                let file_name = path.file_name()
+                    .and_then(|name| name.to_str())
+                    .unwrap_or_default()
+                    .to_string();
                 
-                if path.is_file() {
-                    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-                    if extensions.contains(&ext) {
-                        let file_name = path.file_name()
-                            .and_then(|name| name.to_str())
-                            .unwrap_or_default()
-                            .to_string();
-                        
-                        // 既知のMODリストに含まれているかチェック
-                        let is_managed = known_mods.iter().any(|known_mod| {
-                            known_mod.dll_path == path
-                        });
-                        
-                        if !is_managed {
-                            // ファイル情報を取得
-                            let metadata = fs::metadata(&path)?;
-                            let file_size = metadata.len();
-                            let modified_time = metadata.modified()
-                                .map(|time| {
-                                    let datetime: chrono::DateTime<chrono::Utc> = time.into();
-                                    datetime.format("%Y-%m-%d %H:%M:%S").to_string()
-                                })
-                                .unwrap_or_default();
-                            
-                            // dll_nameを拡張子に基づいて設定
-                            let dll_name = if file_name.ends_with(".dll") {
-                                file_name.trim_end_matches(".dll").to_string()
-                            } else if file_name.ends_with(".nupkg") {
-                                file_name.trim_end_matches(".nupkg").to_string()
-                            } else {
-                                file_name.clone()
-                            };
-                            
-                            unmanaged_mods.push(UnmanagedMod {
-                                file_name: file_name.clone(),
-                                file_path: path,
-                                file_size,
-                                modified_time,
-                                dll_name,
-                                matched_mod_info: None, // 後でマッチングを行う
-                                calculated_sha256: None, // 後でハッシュを計算
-                                detected_version: None, // 後でバージョンを検出
-                            });
-                        }
-                    }
+                // 既知のMODリストに含まれているかチェック
+                let is_managed = known_paths.contains(&Self::normalize_path(&path));
+                
+                if !is_managed {
+                    // ファイル情報を取得
+                    let metadata = fs::metadata(&path)?;
+                    let file_size = metadata.len();
+                    let modified_time = metadata.modified()
+                        .map(|time| {
+                            let datetime: chrono::DateTime<chrono::Utc> = time.into();
+                            datetime.format("%Y-%m-%d %H:%M:%S").to_string()
+                        })
+                        .unwrap_or_default();
+                    
+                    // dll_nameを拡張子に基づいて設定
+                    let dll_name = if file_name.ends_with(".dll") {
+                        file_name.trim_end_matches(".dll").to_string()
+                    } else if file_name.ends_with(".nupkg") {
+                        file_name.trim_end_matches(".nupkg").to_string()
+                    } else {
+                        file_name.clone()
+                    };
+                    
+                    let relative_path = path.strip_prefix(&dir)
+                        .map(|p| p.to_string_lossy().to_string())
+                        .unwrap_or_else(|_| file_name.clone());
+                    
+                    unmanaged_mods.push(UnmanagedMod {
+                        file_name: file_name.clone(),
+                        file_path: path,
+                        relative_path,
+                        file_size,
+                        modified_time,
+                        dll_name,
+                        matched_mod_info: None, // 後でマッチングを行う
+                        calculated_sha256: None, // 後でハッシュを計算
+                        detected_version: None, // 後でバージョンを検出
+                    });
                 }
             }
         }
         
         Ok(unmanaged_mods)
     }
+    
+    /// ディレクトリを再帰的に走査し、指定拡張子のファイルを収集
+    fn collect_files_recursive(dir: &std::path::Path, extensions: &[&str], files: &mut Vec<PathBuf>) -> Result<(), ModManagerError> {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            
+            if path.is_dir() {
+                Self::collect_files_recursive(&path, extensions, files)?;
+            } else if path.is_file() {
+                let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+                if extensions.contains(&ext) {
+                    files.push(path);
+                }
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// パス比較用に正規化（存在しない場合はそのまま）
+    fn normalize_path(path: &std::path::Path) -> PathBuf {
+        fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+    }
 
     /// 未管理MODとマニフェストMODのマッチングを試行
-    pub async fn match_unmanaged_mods(&self, mut unmanaged_mods: Vec<UnmanagedMod>) -> Result<Vec<UnmanagedMod>, Box<dyn Error + Send + Sync>> {
+    pub async fn match_unmanaged_mods(&self, mut unmanaged_mods: Vec<UnmanagedMod>) -> Result<Vec<UnmanagedMod>, ModManagerError> {
         let manifest_mods = self.fetch_mod_manifest().await.unwrap_or_default();
         
         for unmanaged_mod in &mut unmanaged_mods {
@@ -838,7 +2553,7 @@ impl ModManager {
             if let Some(hash_entry) = hash_match {
                 // ハッシュマッチが見つかった場合、バージョンを設定
                 unmanaged_mod.detected_version = Some(hash_entry.version.clone());
-                println!("Hash match found for {}: version {}", unmanaged_mod.dll_name, hash_entry.version);
+                log_println!("Hash match found for {}: version {}", unmanaged_mod.dll_name, hash_entry.version);
                 
                 // 対応するMOD情報を探す（MOD名ベースでマッチング）
                 let matched_mod = manifest_mods.iter().find(|manifest_mod| {
@@ -847,13 +2562,13 @@ impl ModManager {
                 
                 if let Some(matched_mod) = matched_mod {
                     unmanaged_mod.matched_mod_info = Some(matched_mod.clone());
-                    println!("Matched mod info found for {}", unmanaged_mod.dll_name);
+                    log_println!("Matched mod info found for {}", unmanaged_mod.dll_name);
                     continue;
                 } else {
-                    println!("No matched mod info found for {} (looking for '{}')", unmanaged_mod.dll_name, hash_entry.mod_name);
+                    log_println!("No matched mod info found for {} (looking for '{}')", unmanaged_mod.dll_name, hash_entry.mod_name);
                 }
             } else {
-                println!("No hash match found for {} (hash: {})", unmanaged_mod.dll_name, file_hash);
+                log_println!("No hash match found for {} (hash: {})", unmanaged_mod.dll_name, file_hash);
             }
             
             // ハッシュでマッチしない場合は従来のファイル名ベースマッチング
@@ -879,22 +2594,22 @@ impl ModManager {
     }
 
     /// 未管理MODを管理システムに追加
-    pub async fn add_unmanaged_mod_to_system(&self, unmanaged_mod: &UnmanagedMod) -> Result<InstalledMod, Box<dyn Error + Send + Sync>> {
+    pub async fn add_unmanaged_mod_to_system(&self, unmanaged_mod: &UnmanagedMod) -> Result<InstalledMod, ModManagerError> {
         // 既に検出されたバージョンを使用、なければハッシュベースで検出
         let detected_version = if let Some(version) = &unmanaged_mod.detected_version {
-            println!("Using pre-detected version for {}: {}", unmanaged_mod.dll_name, version);
+            log_println!("Using pre-detected version for {}: {}", unmanaged_mod.dll_name, version);
             Some(version.clone())
         } else if let Some(hash) = &unmanaged_mod.calculated_sha256 {
-            println!("No pre-detected version, trying hash lookup for {}", unmanaged_mod.dll_name);
+            log_println!("No pre-detected version, trying hash lookup for {}", unmanaged_mod.dll_name);
             if let Some(hash_entry) = self.find_mod_by_hash(hash).await {
-                println!("Hash lookup successful for {}: {}", unmanaged_mod.dll_name, hash_entry.version);
+                log_println!("Hash lookup successful for {}: {}", unmanaged_mod.dll_name, hash_entry.version);
                 Some(hash_entry.version)
             } else {
-                println!("Hash lookup failed for {}", unmanaged_mod.dll_name);
+                log_println!("Hash lookup failed for {}", unmanaged_mod.dll_name);
                 None
             }
         } else {
-            println!("No hash available for {}", unmanaged_mod.dll_name);
+            log_println!("No hash available for {}", unmanaged_mod.dll_name);
             None
         };
         
@@ -927,7 +2642,7 @@ impl ModManager {
                 .unwrap_or_else(|| format!("file://{}", unmanaged_mod.file_path.display())),
             installed_version: {
                 let version = detected_version.unwrap_or_else(|| "unknown".to_string());
-                println!("Final installed_version for {}: {}", unmanaged_mod.dll_name, version);
+                log_println!("Final installed_version for {}: {}", unmanaged_mod.dll_name, version);
                 version
             },
             installed_date: unmanaged_mod.modified_time.clone(),
@@ -935,6 +2650,9 @@ impl ModManager {
             mod_loader_type: Some(mod_loader_type.to_string()),
             file_format: Some(file_format.to_string()),
             enabled: Some(true), // 未管理MODは有効と仮定
+            hot_reload_capable: None,
+            hot_reload_override: None,
+            load_priority: None,
         };
 
         // インストール済みMOD一覧に追加
@@ -944,7 +2662,7 @@ impl ModManager {
     }
 
     /// 複数の未管理MODを一括で管理システムに追加
-    pub async fn add_multiple_unmanaged_mods(&self, unmanaged_mods: &[UnmanagedMod]) -> Result<Vec<InstalledMod>, Box<dyn Error + Send + Sync>> {
+    pub async fn add_multiple_unmanaged_mods(&self, unmanaged_mods: &[UnmanagedMod]) -> Result<Vec<InstalledMod>, ModManagerError> {
         let mut added_mods = Vec::new();
         
         for unmanaged_mod in unmanaged_mods {
@@ -960,24 +2678,187 @@ impl ModManager {
         Ok(added_mods)
     }
 
+    /// インストール済みMOD一覧をJSONでエクスポート
+    pub fn export_mod_list(&self) -> Result<String, ModManagerError> {
+        let entries: Vec<ModListEntry> = self.get_installed_mods()?
+            .into_iter()
+            .map(|m| ModListEntry {
+                source_location: m.source_location,
+                version: m.installed_version,
+                mod_loader_type: m.mod_loader_type,
+            })
+            .collect();
+
+        Ok(serde_json::to_string_pretty(&entries)?)
+    }
+
+    /// インストール済みMOD一覧をサポート依頼などに貼り付けやすい形式に整形
+    pub fn format_installed_mods(&self, format: OutputFormat) -> String {
+        let mut mods = self.get_installed_mods().unwrap_or_default();
+        mods.sort_by_key(|m| m.name.to_lowercase());
+        
+        if mods.is_empty() {
+            return "No mods installed".to_string();
+        }
+        
+        let mut output = String::new();
+        match format {
+            OutputFormat::MarkdownTable => {
+                output.push_str("| MOD | Version | Loader | Enabled |\n");
+                output.push_str("|---|---|---|---|\n");
+                for m in &mods {
+                    output.push_str(&format!(
+                        "| {} | {} | {} | {} |\n",
+                        m.name.replace('|', "\\|"),
+                        m.installed_version.replace('|', "\\|"),
+                        m.mod_loader_type.as_deref().unwrap_or("Unknown"),
+                        if m.enabled.unwrap_or(true) { "Yes" } else { "No" }
+                    ));
+                }
+            }
+            OutputFormat::BulletList => {
+                for m in &mods {
+                    output.push_str(&format!(
+                        "- {} {} ({}){}\n",
+                        m.name,
+                        m.installed_version,
+                        m.mod_loader_type.as_deref().unwrap_or("Unknown"),
+                        if m.enabled.unwrap_or(true) { "" } else { " [disabled]" }
+                    ));
+                }
+            }
+        }
+        
+        output
+    }
+
+    /// MOD一覧のインポートで行われる変更を計画する（ドライラン、ファイルは変更しない）
+    pub fn plan_import_mod_list(&self, json: &str) -> Result<Vec<PlannedModChange>, ModManagerError> {
+        let entries: Vec<ModListEntry> = serde_json::from_str(json)
+            .map_err(|e| ModManagerError::ParseError(format!("Failed to parse mod list: {}", e)))?;
+        let installed_mods = self.get_installed_mods()?;
+
+        Ok(entries.into_iter().map(|entry| {
+            let existing = installed_mods.iter()
+                .find(|m| utils::same_source_location(&m.source_location, &entry.source_location));
+
+            match existing {
+                Some(installed) => PlannedModChange {
+                    name: installed.name.clone(),
+                    source_location: entry.source_location,
+                    action: if installed.installed_version == entry.version {
+                        PlannedModAction::Unchanged
+                    } else {
+                        PlannedModAction::Update
+                    },
+                    from_version: Some(installed.installed_version.clone()),
+                    remove_path: None,
+                    to_version: entry.version,
+                },
+                None => PlannedModChange {
+                    name: entry.source_location.trim_end_matches('/').rsplit('/').next().unwrap_or_default().to_string(),
+                    source_location: entry.source_location,
+                    action: PlannedModAction::Install,
+                    from_version: None,
+                    to_version: entry.version,
+                    remove_path: None,
+                },
+            }
+        }).collect())
+    }
+
+    /// エクスポートしたMOD一覧を読み込み、各MODを指定バージョンで再インストール
+    pub async fn import_mod_list(&self, json: &str) -> Result<Vec<ModImportResult>, ModManagerError> {
+        self.import_mod_list_with_progress(json, |_| {}).await
+    }
+
+    /// エクスポートしたMOD一覧を読み込み、全体の進捗を通知しながら各MODを指定バージョンで再インストール
+    ///
+    /// 同じバージョンがインストール済みのMODはスキップする。
+    pub async fn import_mod_list_with_progress<F>(&self, json: &str, on_progress: F) -> Result<Vec<ModImportResult>, ModManagerError>
+    where
+        F: Fn(&BatchProgress) + Send + Sync,
+    {
+        let entries: Vec<ModListEntry> = serde_json::from_str(json)
+            .map_err(|e| ModManagerError::ParseError(format!("Failed to parse mod list: {}", e)))?;
+        let plans = self.plan_import_mod_list(json)?;
+
+        let mut results = Vec::new();
+        let mut progress = BatchProgress::new(entries.len());
+
+        for (entry, plan) in entries.into_iter().zip(plans) {
+            progress.start(&plan.name);
+            on_progress(&progress);
+
+            if plan.action == PlannedModAction::Unchanged {
+                log_println!("{} {} is already installed, skipping", plan.name, entry.version);
+                progress.record(BatchOutcome::Skipped);
+                results.push(ModImportResult {
+                    source_location: entry.source_location,
+                    version: entry.version,
+                    installed_mod: None,
+                    error: None,
+                    skipped: true,
+                });
+                continue;
+            }
+
+            let result = self.install_mod_from_github(
+                &entry.source_location,
+                Some(&entry.version),
+                entry.mod_loader_type.as_deref(),
+            ).await;
+
+            match result {
+                Ok(installed_mod) => {
+                    log_println!("Imported {} {}", installed_mod.name, entry.version);
+                    progress.record(BatchOutcome::Succeeded);
+                    results.push(ModImportResult {
+                        source_location: entry.source_location,
+                        version: entry.version,
+                        installed_mod: Some(installed_mod),
+                        error: None,
+                        skipped: false,
+                    });
+                }
+                Err(e) => {
+                    eprintln!("Failed to import {} {}: {}", entry.source_location, entry.version, e);
+                    progress.record(BatchOutcome::Failed);
+                    results.push(ModImportResult {
+                        source_location: entry.source_location,
+                        version: entry.version,
+                        installed_mod: None,
+                        error: Some(e.to_string()),
+                        skipped: false,
+                    });
+                }
+            }
+        }
+
+        progress.finish();
+        on_progress(&progress);
+
+        Ok(results)
+    }
+
     /// MODを更新（バージョン変更）
-    pub async fn update_mod(&self, mod_name: &str, target_version: &str) -> Result<InstalledMod, Box<dyn Error + Send + Sync>> {
+    pub async fn update_mod(&self, mod_name: &str, target_version: &str) -> Result<InstalledMod, ModManagerError> {
         // 既存のMOD情報を取得
         let installed_mods = self.get_installed_mods()?;
         let existing_mod = installed_mods.iter()
             .find(|m| m.name == mod_name)
-            .ok_or(format!("MOD '{}' is not installed", mod_name))?;
+            .ok_or_else(|| ModManagerError::NotFound(format!("MOD '{}' is not installed", mod_name)))?;
         
         // MODマニフェストから情報を取得
         let all_mods = self.fetch_mod_manifest().await?;
         let mod_info = all_mods.iter()
-            .find(|m| m.name == mod_name || m.source_location == existing_mod.source_location)
-            .ok_or(format!("MOD '{}' not found in manifest", mod_name))?;
+            .find(|m| m.name == mod_name || utils::same_source_location(&m.source_location, &existing_mod.source_location))
+            .ok_or_else(|| ModManagerError::NotFound(format!("MOD '{}' not found in manifest", mod_name)))?;
         
         // ターゲットバージョンのリリース情報を取得してファイル形式を確認
         let target_release = mod_info.releases.iter()
             .find(|r| r.version == target_version)
-            .ok_or(format!("Target version {} not found", target_version))?;
+            .ok_or_else(|| ModManagerError::NotFound(format!("Target version {} not found", target_version)))?;
         
         // ファイル名から形式を判定
         let file_name = target_release.file_name.as_deref()
@@ -993,153 +2874,451 @@ impl ModManager {
             None
         };
         
-        println!("Updating {} from {} to {} (old format: {}, new format: {})", 
+        log_println!("Updating {} from {} to {} (old format: {}, new format: {})", 
                 mod_name, 
                 existing_mod.installed_version,
                 target_version,
                 existing_mod.file_format.as_deref().unwrap_or("unknown"),
                 if file_name.ends_with(".nupkg") { "nupkg" } else { "dll" });
         
-        // 既存のMODをアンインストール
-        self.uninstall_mod(mod_name)?;
-        
-        // 指定されたバージョンをインストール（適切なMODローダータイプを指定）
-        self.install_mod_from_cache(mod_info, Some(target_version), target_mod_loader_type).await
+        // 指定されたバージョンに入れ替え（適切なMODローダータイプを指定）
+        self.replace_mod_version(existing_mod, mod_info, target_version, target_mod_loader_type).await
     }
 
     /// MODのダウングレード
-    pub async fn downgrade_mod(&self, mod_name: &str, target_version: &str) -> Result<InstalledMod, Box<dyn Error + Send + Sync>> {
+    pub async fn downgrade_mod(&self, mod_name: &str, target_version: &str) -> Result<InstalledMod, ModManagerError> {
         self.update_mod(mod_name, target_version).await
     }
 
     /// アップグレード可能なMODのリストを取得
-    pub async fn get_upgradeable_mods(&self) -> Result<Vec<UpgradeableMod>, Box<dyn Error + Send + Sync>> {
+    pub async fn get_upgradeable_mods(&self) -> Result<Vec<UpgradeableMod>, ModManagerError> {
         // インストール済みMODとマニフェストを取得
         let installed_mods = self.get_installed_mods()?;
         let all_mods = self.fetch_mod_manifest().await?;
+        let manifest_text = self.cached_manifest_text().unwrap_or_default();
+        
+        // 前回のリポジトリ状態チェック結果を反映
+        let unmaintained_sources: Vec<String> = self.get_unmaintained_mods()
+            .into_iter()
+            .map(|m| m.source_location)
+            .collect();
         
-        println!("DEBUG: get_upgradeable_mods called");
-        println!("DEBUG: Found {} installed mods", installed_mods.len());
-        println!("DEBUG: Found {} manifest mods", all_mods.len());
+        log_println!("DEBUG: get_upgradeable_mods called");
+        log_println!("DEBUG: Found {} installed mods", installed_mods.len());
+        log_println!("DEBUG: Found {} manifest mods", all_mods.len());
         
         let mut upgradeable_mods = Vec::new();
         
         for installed_mod in &installed_mods {
             // マニフェストから対応するMOD情報を探す
             let manifest_mod = all_mods.iter().find(|m| 
-                m.name == installed_mod.name || m.source_location == installed_mod.source_location
+                m.name == installed_mod.name || utils::same_source_location(&m.source_location, &installed_mod.source_location)
             );
             
             if let Some(mod_info) = manifest_mod {
-                if let Some(latest_version) = &mod_info.latest_version {
-                    println!("DEBUG: Checking mod '{}': current='{}', latest='{}'", 
+                if let Some(latest_version) = self.latest_version_for(mod_info) {
+                    log_println!("DEBUG: Checking mod '{}': current='{}', latest='{}'", 
                              installed_mod.name, installed_mod.installed_version, latest_version);
                     
                     // バージョン比較（より新しいバージョンがあるか確認）
-                    if latest_version != &installed_mod.installed_version {
-                        println!("DEBUG: Found upgradeable mod: {} {} -> {}", 
+                    if utils::compare_versions(latest_version, &installed_mod.installed_version) == std::cmp::Ordering::Greater {
+                        log_println!("DEBUG: Found upgradeable mod: {} {} -> {}", 
                                  installed_mod.name, installed_mod.installed_version, latest_version);
                         upgradeable_mods.push(UpgradeableMod {
                             name: installed_mod.name.clone(),
                             current_version: installed_mod.installed_version.clone(),
-                            latest_version: latest_version.clone(),
+                            latest_version: latest_version.to_string(),
                             description: mod_info.description.clone(),
                             source_location: mod_info.source_location.clone(),
+                            possibly_unmaintained: unmaintained_sources.contains(&installed_mod.source_location),
+                            mod_loader_type: installed_mod.loader_type(),
                         });
                     }
                 }
             } else {
-                println!("DEBUG: No manifest mod found for installed mod: {}", installed_mod.name);
+                log_println!("DEBUG: No manifest mod found for installed mod: {}", installed_mod.name);
             }
         }
         
-        println!("DEBUG: Returning {} upgradeable mods", upgradeable_mods.len());
+        log_println!("DEBUG: Returning {} upgradeable mods", upgradeable_mods.len());
+        
+        // 次回起動時にすぐ表示できるよう結果を保存
+        let cache = CachedUpgradeableMods {
+            mods: upgradeable_mods.clone(),
+            checked_at: chrono::Utc::now().to_rfc3339(),
+            fingerprint: Self::upgrade_check_fingerprint(&manifest_text, &installed_mods),
+            age_description: None,
+        };
+        if let Err(e) = fs::write(self.profile_dir.join(UPGRADE_CHECK_CACHE_FILE), serde_json::to_string_pretty(&cache)?) {
+            eprintln!("Failed to save upgrade check cache: {}", e);
+        }
+        
         Ok(upgradeable_mods)
     }
 
-    /// アップデート可能なMODを一括でアップグレード
-    pub async fn bulk_upgrade_mods(&self) -> Result<Vec<InstalledMod>, Box<dyn Error + Send + Sync>> {
-        // インストール済みMODとマニフェストを取得
-        let installed_mods = self.get_installed_mods()?;
-        let all_mods = self.fetch_mod_manifest().await?;
-        
-        let mut upgraded_mods = Vec::new();
-        let mut failed_upgrades = Vec::new();
+    /// アップデート可能なMODをMODローダーごとにまとめて取得
+    pub async fn get_upgradeable_mods_by_loader(&self) -> Result<Vec<UpgradeableModGroup>, ModManagerError> {
+        Ok(group_upgradeable_mods_by_loader(self.get_upgradeable_mods().await?))
+    }
+
+    /// 保存済みのアップデートチェック結果を取得（再チェック前の表示用）
+    ///
+    /// マニフェストやインストール済みMODが変化している場合は無効としてNoneを返す。
+    pub fn get_cached_upgradeable_mods(&self) -> Option<CachedUpgradeableMods> {
+        let content = fs::read_to_string(self.profile_dir.join(UPGRADE_CHECK_CACHE_FILE)).ok()?;
+        let mut cache: CachedUpgradeableMods = serde_json::from_str(&content).ok()?;
         
-        for installed_mod in &installed_mods {
-            // マニフェストから対応するMOD情報を探す
-            let manifest_mod = all_mods.iter().find(|m| 
-                m.name == installed_mod.name || m.source_location == installed_mod.source_location
-            );
-            
-            if let Some(mod_info) = manifest_mod {
-                if let Some(latest_version) = &mod_info.latest_version {
-                    // バージョン比較（簡易的にstring比較、より新しいバージョンがあるか確認）
-                    if latest_version != &installed_mod.installed_version {
-                        println!("Upgrading {} from {} to {}", 
-                                installed_mod.name, 
-                                installed_mod.installed_version, 
-                                latest_version);
-                        
-                        match self.upgrade_mod(&installed_mod.name, Some(latest_version)).await {
-                            Ok(upgraded_mod) => {
-                                upgraded_mods.push(upgraded_mod);
-                                println!("Successfully upgraded {}", installed_mod.name);
-                            }
-                            Err(e) => {
-                                let error_msg = format!("Failed to upgrade {}: {}", installed_mod.name, e);
-                                eprintln!("{}", error_msg);
-                                failed_upgrades.push(error_msg);
-                            }
-                        }
-                    } else {
-                        println!("{} is already up to date ({})", installed_mod.name, latest_version);
-                    }
-                } else {
-                    println!("No latest version available for {}", installed_mod.name);
-                }
-            } else {
-                println!("MOD {} not found in manifest, skipping", installed_mod.name);
+        // ディスク上のマニフェストで無効化を判定（ネットワークにはアクセスしない）
+        if let Some(manifest_text) = self.cached_manifest_text() {
+            let installed_mods = self.get_installed_mods().ok()?;
+            if Self::upgrade_check_fingerprint(&manifest_text, &installed_mods) != cache.fingerprint {
+                log_println!("Upgrade check cache is outdated, ignoring");
+                return None;
             }
         }
         
-        if !failed_upgrades.is_empty() {
-            println!("Failed upgrades: {}", failed_upgrades.join(", "));
+        // MODローダーを記録していない古い保存結果でもローダーごとにまとめられるよう補完する
+        if let Ok(installed_mods) = self.get_installed_mods() {
+            for upgradeable_mod in &mut cache.mods {
+                if let Some(installed_mod) = installed_mods.iter().find(|m| m.name == upgradeable_mod.name) {
+                    upgradeable_mod.mod_loader_type = installed_mod.loader_type();
+                }
+            }
         }
         
-        println!("Bulk upgrade completed: {} upgraded, {} failed", 
-                upgraded_mods.len(), 
-                failed_upgrades.len());
+        let checked_at = chrono::DateTime::parse_from_rfc3339(&cache.checked_at).ok()?;
+        let elapsed = chrono::Utc::now().signed_duration_since(checked_at);
+        cache.age_description = Some(if elapsed.num_hours() >= 1 {
+            format!("{}時間前の情報", elapsed.num_hours())
+        } else {
+            format!("{}分前の情報", elapsed.num_minutes().max(0))
+        });
         
-        Ok(upgraded_mods)
+        Some(cache)
     }
 
-    /// MODのアップグレード
-    pub async fn upgrade_mod(&self, mod_name: &str, target_version: Option<&str>) -> Result<InstalledMod, Box<dyn Error + Send + Sync>> {
-        // 既存のMOD情報を取得
-        let installed_mods = self.get_installed_mods()?;
-        let existing_mod = installed_mods.iter()
-            .find(|m| m.name == mod_name)
-            .ok_or(format!("MOD '{}' is not installed", mod_name))?;
+    /// ディスク上のマニフェスト（RML向け・MonkeyLoader向け）の内容をまとめて取得
+    fn cached_manifest_text(&self) -> Option<String> {
+        let mut manifest_text = fs::read_to_string(&self.manifest_cache.data_file).ok()?;
+        if let Ok(monkey_loader_text) = fs::read_to_string(&self.monkey_loader_manifest_cache.data_file) {
+            manifest_text.push_str(&monkey_loader_text);
+        }
+        Some(manifest_text)
+    }
+
+    /// アップデートチェック結果の有効性を判定するためのハッシュ
+    fn upgrade_check_fingerprint(manifest_text: &str, installed_mods: &[InstalledMod]) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(manifest_text.as_bytes());
+        for installed_mod in installed_mods {
+            hasher.update(installed_mod.name.as_bytes());
+            hasher.update(b"\0");
+            hasher.update(installed_mod.installed_version.as_bytes());
+            hasher.update(b"\n");
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// インストール元リポジトリの状態を確認（削除・アーカイブの検出）
+    pub async fn check_repository_state(&self, repo_url: &str) -> Result<RepositoryState, ModManagerError> {
+        let api_url = self.github_repo_to_api_url(repo_url)?;
         
-        // MODマニフェストから情報を取得
-        let all_mods = self.fetch_mod_manifest().await?;
-        let mod_info = all_mods.iter()
-            .find(|m| m.name == mod_name || m.source_location == existing_mod.source_location)
-            .ok_or(format!("MOD '{}' not found in manifest", mod_name))?;
+        let response = self.client
+            .get(&api_url)
+            .header("User-Agent", "resonite-tools")
+            .send()
+            .await?;
         
-        // ターゲットバージョンを決定（指定されない場合は最新）
-        let upgrade_version = if let Some(version) = target_version {
-            version
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(RepositoryState::NotFound);
+        }
+        if !response.status().is_success() {
+            return Err(ModManagerError::NetworkError(format!("GitHub API request failed: {}", response.status())));
+        }
+        
+        let repo: serde_json::Value = response.json().await?;
+        if repo.get("archived").and_then(|v| v.as_bool()).unwrap_or(false) {
+            Ok(RepositoryState::Archived)
+        } else {
+            Ok(RepositoryState::Active)
+        }
+    }
+    
+    /// GitHub APIからリポジトリのstar数・最終更新日・オープンissue数を取得
+    pub async fn fetch_repo_stats(&self, repo_url: &str) -> Result<RepoStats, ModManagerError> {
+        let api_url = self.github_repo_to_api_url(repo_url)?;
+        
+        let response = self.client
+            .get(&api_url)
+            .header("User-Agent", "resonite-tools")
+            .send()
+            .await?;
+        
+        if !response.status().is_success() {
+            return Err(ModManagerError::NetworkError(format!("GitHub API request failed: {}", response.status())));
+        }
+        
+        let repo: serde_json::Value = response.json().await?;
+        let last_updated = repo.get("pushed_at").and_then(|v| v.as_str()).map(|s| s.to_string());
+        let stale = last_updated.as_deref()
+            .and_then(|date| chrono::DateTime::parse_from_rfc3339(date).ok())
+            .map(|date| chrono::Utc::now().signed_duration_since(date) > chrono::Duration::days(STALE_REPO_DAYS))
+            .unwrap_or(false);
+        
+        Ok(RepoStats {
+            stars: repo.get("stargazers_count").and_then(|v| v.as_u64()).unwrap_or(0),
+            open_issues: repo.get("open_issues_count").and_then(|v| v.as_u64()).unwrap_or(0),
+            last_updated,
+            archived: repo.get("archived").and_then(|v| v.as_bool()).unwrap_or(false),
+            stale,
+        })
+    }
+    
+    /// GitHub APIのレート制限の残り回数とリセット時刻を取得
+    pub async fn get_github_rate_limit(&self) -> Result<RateLimitInfo, ModManagerError> {
+        let response = self.client
+            .get(GITHUB_RATE_LIMIT_URL)
+            .header("User-Agent", "resonite-tools")
+            .send()
+            .await?;
+        
+        if !response.status().is_success() {
+            return Err(ModManagerError::NetworkError(format!("GitHub API request failed: {}", response.status())));
+        }
+        
+        let body: serde_json::Value = response.json().await?;
+        let core = body.pointer("/resources/core").unwrap_or(&body["rate"]);
+        let field = |name: &str| core.get(name).and_then(|v| v.as_u64());
+        let (Some(remaining), Some(limit), Some(reset)) = (field("remaining"), field("limit"), field("reset")) else {
+            return Err(ModManagerError::ParseError("Unexpected GitHub rate limit response".to_string()));
+        };
+        
+        let remaining = remaining as u32;
+        let reset_at = chrono::DateTime::from_timestamp(reset as i64, 0)
+            .map(|date| date.to_rfc3339())
+            .unwrap_or_default();
+        
+        Ok(RateLimitInfo {
+            remaining,
+            limit: limit as u32,
+            reset_at,
+            low: remaining <= LOW_RATE_LIMIT_REMAINING,
+        })
+    }
+    
+    /// インストール済みMODのリポジトリを確認し、メンテ終了の可能性があるMODを検出して保存
+    pub async fn check_unmaintained_mods(&self) -> Result<Vec<UnmaintainedMod>, ModManagerError> {
+        let installed_mods = self.get_installed_mods()?;
+        let checked_at = chrono::Utc::now().to_rfc3339();
+        let mut unmaintained_mods = Vec::new();
+        let mut checked_sources: HashMap<String, RepositoryState> = HashMap::new();
+        
+        for installed_mod in &installed_mods {
+            if !installed_mod.source_location.contains("github.com") {
+                continue;
+            }
+            
+            let state = match checked_sources.get(&installed_mod.source_location) {
+                Some(state) => *state,
+                None => match self.check_repository_state(&installed_mod.source_location).await {
+                    Ok(state) => {
+                        checked_sources.insert(installed_mod.source_location.clone(), state);
+                        state
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to check repository for {}: {}", installed_mod.name, e);
+                        continue;
+                    }
+                },
+            };
+            
+            if state != RepositoryState::Active {
+                log_println!("Repository for {} is {:?}: {}", installed_mod.name, state, installed_mod.source_location);
+                unmaintained_mods.push(UnmaintainedMod {
+                    name: installed_mod.name.clone(),
+                    source_location: installed_mod.source_location.clone(),
+                    state,
+                    checked_at: checked_at.clone(),
+                });
+            }
+        }
+        
+        let content = serde_json::to_string_pretty(&unmaintained_mods)?;
+        fs::write(self.profile_dir.join("unmaintained_mods.json"), content)?;
+        
+        Ok(unmaintained_mods)
+    }
+    
+    /// 前回のチェックでメンテ終了の可能性があると判定されたMODを取得
+    pub fn get_unmaintained_mods(&self) -> Vec<UnmaintainedMod> {
+        fs::read_to_string(self.profile_dir.join("unmaintained_mods.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// 一括アップグレードで行われる変更を計画する（ドライラン、ファイルは変更しない）
+    pub async fn plan_bulk_upgrade(&self) -> Result<Vec<PlannedModChange>, ModManagerError> {
+        let targets = self.collect_upgrade_targets(None).await?;
+
+        Ok(targets.into_iter().map(|(installed_mod, latest_version)| PlannedModChange {
+            name: installed_mod.name,
+            source_location: installed_mod.source_location,
+            action: PlannedModAction::Update,
+            from_version: Some(installed_mod.installed_version),
+            to_version: latest_version,
+            remove_path: Some(installed_mod.dll_path),
+        }).collect())
+    }
+
+    /// アップグレード対象のMODと更新先バージョンを収集
+    async fn collect_upgrade_targets(&self, loader_filter: Option<ModLoaderType>) -> Result<Vec<(InstalledMod, String)>, ModManagerError> {
+        // インストール済みMODとマニフェストを取得
+        let installed_mods = self.get_installed_mods()?;
+        let all_mods = self.fetch_mod_manifest().await?;
+        
+        // アップグレード対象を収集
+        let mut targets = Vec::new();
+        for installed_mod in installed_mods {
+            if loader_filter.is_some_and(|loader| installed_mod.loader_type() != loader) {
+                continue;
+            }
+            
+            // マニフェストから対応するMOD情報を探す
+            let manifest_mod = all_mods.iter().find(|m| 
+                m.name == installed_mod.name || utils::same_source_location(&m.source_location, &installed_mod.source_location)
+            );
+            
+            if let Some(mod_info) = manifest_mod {
+                if let Some(latest_version) = self.latest_version_for(mod_info) {
+                    // バージョン比較（インストール済みの方が新しいプレリリース等の場合は対象外）
+                    if utils::compare_versions(latest_version, &installed_mod.installed_version) == std::cmp::Ordering::Greater {
+                        let latest_version = latest_version.to_string();
+                        targets.push((installed_mod, latest_version));
+                    } else {
+                        log_println!("{} is already up to date ({})", installed_mod.name, latest_version);
+                    }
+                } else {
+                    log_println!("No latest version available for {}", installed_mod.name);
+                }
+            } else {
+                log_println!("MOD {} not found in manifest, skipping", installed_mod.name);
+            }
+        }
+
+        Ok(targets)
+    }
+
+    /// アップデート可能なMODを一括でアップグレード
+    pub async fn bulk_upgrade_mods(&self) -> Result<Vec<InstalledMod>, ModManagerError> {
+        self.bulk_upgrade_mods_with_progress(|_| {}, |_| {}).await
+    }
+
+    /// アップデート可能なMODを一括でアップグレードし、1件ごとの進捗と全体の進捗を通知
+    pub async fn bulk_upgrade_mods_with_progress<F, G>(&self, on_progress: F, on_batch_progress: G) -> Result<Vec<InstalledMod>, ModManagerError>
+    where
+        F: Fn(&BulkUpgradeProgress) + Send + Sync,
+        G: Fn(&BatchProgress) + Send + Sync,
+    {
+        self.bulk_upgrade_loader_mods_with_progress(None, on_progress, on_batch_progress).await
+    }
+
+    /// 指定したMODローダーのMODだけを一括でアップグレードする（Noneの場合はすべて）
+    pub async fn bulk_upgrade_loader_mods_with_progress<F, G>(
+        &self,
+        loader_filter: Option<ModLoaderType>,
+        on_progress: F,
+        on_batch_progress: G,
+    ) -> Result<Vec<InstalledMod>, ModManagerError>
+    where
+        F: Fn(&BulkUpgradeProgress) + Send + Sync,
+        G: Fn(&BatchProgress) + Send + Sync,
+    {
+        let targets = self.collect_upgrade_targets(loader_filter).await?;
+        
+        let total = targets.len();
+        let mut upgraded_mods = Vec::new();
+        let mut failed_upgrades = Vec::new();
+        let mut batch_progress = BatchProgress::new(total);
+        
+        for (index, (installed_mod, latest_version)) in targets.into_iter().enumerate() {
+            log_println!("Upgrading {} from {} to {}", 
+                    installed_mod.name, 
+                    installed_mod.installed_version, 
+                    latest_version);
+            
+            let mut progress = BulkUpgradeProgress {
+                mod_name: installed_mod.name.clone(),
+                from_version: installed_mod.installed_version.clone(),
+                to_version: latest_version.clone(),
+                current: index + 1,
+                total,
+                status: BulkUpgradeStatus::Upgrading,
+                error: None,
+            };
+            on_progress(&progress);
+            batch_progress.start(&installed_mod.name);
+            on_batch_progress(&batch_progress);
+            
+            match self.upgrade_mod(&installed_mod.name, Some(&latest_version)).await {
+                Ok(upgraded_mod) => {
+                    upgraded_mods.push(upgraded_mod);
+                    log_println!("Successfully upgraded {}", installed_mod.name);
+                    progress.status = BulkUpgradeStatus::Completed;
+                    batch_progress.record(BatchOutcome::Succeeded);
+                }
+                Err(e) => {
+                    let error_msg = format!("Failed to upgrade {}: {}", installed_mod.name, e);
+                    eprintln!("{}", error_msg);
+                    failed_upgrades.push(error_msg);
+                    progress.status = BulkUpgradeStatus::Failed;
+                    progress.error = Some(e.to_string());
+                    batch_progress.record(BatchOutcome::Failed);
+                }
+            }
+            on_progress(&progress);
+        }
+        
+        batch_progress.finish();
+        on_batch_progress(&batch_progress);
+        
+        if !failed_upgrades.is_empty() {
+            log_println!("Failed upgrades: {}", failed_upgrades.join(", "));
+        }
+        
+        log_println!("Bulk upgrade completed: {} upgraded, {} failed", 
+                upgraded_mods.len(), 
+                failed_upgrades.len());
+        
+        Ok(upgraded_mods)
+    }
+
+    /// MODのアップグレード
+    pub async fn upgrade_mod(&self, mod_name: &str, target_version: Option<&str>) -> Result<InstalledMod, ModManagerError> {
+        // 既存のMOD情報を取得
+        let installed_mods = self.get_installed_mods()?;
+        let existing_mod = installed_mods.iter()
+            .find(|m| m.name == mod_name)
+            .ok_or_else(|| ModManagerError::NotFound(format!("MOD '{}' is not installed", mod_name)))?;
+        
+        // MODマニフェストから情報を取得
+        let all_mods = self.fetch_mod_manifest().await?;
+        let mod_info = all_mods.iter()
+            .find(|m| m.name == mod_name || utils::same_source_location(&m.source_location, &existing_mod.source_location))
+            .ok_or_else(|| ModManagerError::NotFound(format!("MOD '{}' not found in manifest", mod_name)))?;
+        
+        // ターゲットバージョンを決定（指定されない場合は最新）
+        let upgrade_version = if let Some(version) = target_version {
+            version
         } else {
-            mod_info.latest_version.as_deref()
-                .ok_or("No latest version available")?
+            self.latest_version_for(mod_info)
+                .ok_or_else(|| ModManagerError::NotFound("No latest version available".to_string()))?
         };
         
         // 新しいバージョンのリリース情報を取得してファイル形式を確認
         let target_release = mod_info.releases.iter()
             .find(|r| r.version == upgrade_version)
-            .ok_or(format!("Target version {} not found", upgrade_version))?;
+            .ok_or_else(|| ModManagerError::NotFound(format!("Target version {} not found", upgrade_version)))?;
         
         // ファイル名から形式を判定
         let file_name = target_release.file_name.as_deref()
@@ -1155,22 +3334,53 @@ impl ModManager {
             None
         };
         
-        println!("Upgrading {} from {} to {} (old format: {}, new format: {})", 
+        log_println!("Upgrading {} from {} to {} (old format: {}, new format: {})", 
                 mod_name, 
                 existing_mod.installed_version,
                 upgrade_version,
                 existing_mod.file_format.as_deref().unwrap_or("unknown"),
                 if file_name.ends_with(".nupkg") { "nupkg" } else { "dll" });
         
+        // 新しいバージョンに入れ替え（適切なMODローダータイプを指定）
+        self.replace_mod_version(existing_mod, mod_info, upgrade_version, new_mod_loader_type).await
+    }
+
+    /// インストール済みのMODを指定バージョンに入れ替える
+    ///
+    /// 手動で設定したホットリロード対応状況とロードオーダーは新しいバージョンに引き継ぐ。
+    async fn replace_mod_version(&self, existing_mod: &InstalledMod, mod_info: &ModInfo, version: &str, mod_loader_type: Option<&str>) -> Result<InstalledMod, ModManagerError> {
+        let hot_reload_override = existing_mod.hot_reload_override;
+        let load_priority = existing_mod.load_priority;
+        
         // 既存のMODをアンインストール
-        self.uninstall_mod(mod_name)?;
+        self.uninstall_mod(&existing_mod.name)?;
         
-        // 新しいバージョンをインストール（適切なMODローダータイプを指定）
-        self.install_mod_from_cache(mod_info, Some(upgrade_version), new_mod_loader_type).await
+        let mut installed_mod = self.install_mod_from_cache(mod_info, Some(version), mod_loader_type).await?;
+        if load_priority.is_some() {
+            installed_mod = self.set_mod_load_priority(&installed_mod.name, load_priority)?;
+        }
+        if hot_reload_override.is_some() {
+            return self.set_mod_hot_reload_override(&installed_mod.name, hot_reload_override);
+        }
+        Ok(installed_mod)
+    }
+
+    /// MODのホットリロード対応状況を手動で設定する（Noneでマニフェストの情報に戻す）
+    pub fn set_mod_hot_reload_override(&self, mod_name: &str, hot_reload: Option<bool>) -> Result<InstalledMod, ModManagerError> {
+        let mut installed_mods = self.get_installed_mods()?;
+        
+        let mod_info = installed_mods.iter_mut()
+            .find(|m| m.name == mod_name)
+            .ok_or_else(|| ModManagerError::NotFound(format!("MOD '{}' is not installed", mod_name)))?;
+        mod_info.hot_reload_override = hot_reload;
+        let updated = mod_info.clone();
+        
+        self.save_installed_mods(&installed_mods)?;
+        Ok(updated)
     }
 
     /// MODをアンインストール
-    pub fn uninstall_mod(&self, mod_name: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    pub fn uninstall_mod(&self, mod_name: &str) -> Result<(), ModManagerError> {
         let mut installed_mods = self.get_installed_mods()?;
         
         if let Some(pos) = installed_mods.iter().position(|m| m.name == mod_name) {
@@ -1188,24 +3398,250 @@ impl ModManager {
         Ok(())
     }
 
-    /// MODを無効化（拡張子を.disabledに変更）
-    pub fn disable_mod(&self, mod_name: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    /// ファイルには触れずに、インストール済みMOD一覧からエントリを取り除く
+    ///
+    /// ローダーの移行で旧ローダーのMODフォルダを退避した場合など、ファイルが既に別の場所にあるときに使う。
+    pub(crate) fn forget_installed_mods(&self, mod_names: &[String]) -> Result<(), ModManagerError> {
+        let installed_mods: Vec<InstalledMod> = self.get_installed_mods()?
+            .into_iter()
+            .filter(|m| !mod_names.contains(&m.name))
+            .collect();
+        self.save_installed_mods(&installed_mods)
+    }
+
+    /// インストール済みMODをマニフェストと照合し、入手元ごとに分類する
+    ///
+    /// 手動で追加したMODなど、マニフェストで確認できないMODを見分けるために使う。
+    pub async fn classify_installed_mods(&self) -> Result<Vec<(InstalledMod, InstalledModSource)>, ModManagerError> {
+        let installed_mods = self.get_installed_mods()?;
+        let all_mods = self.fetch_mod_manifest().await?;
+
+        Ok(installed_mods
+            .into_iter()
+            .map(|m| {
+                let source = classify_mod_source(&m, &all_mods);
+                (m, source)
+            })
+            .collect())
+    }
+
+    /// 指定したMODに依存しているインストール済みMODの名前を返す（マニフェストの dependencies から逆引き）
+    pub async fn find_dependent_mods(&self, mod_name: &str) -> Result<Vec<String>, ModManagerError> {
+        let installed_mods = self.get_installed_mods()?;
+        let target = match installed_mods.iter().find(|m| m.name == mod_name) {
+            Some(target) => target,
+            None => return Ok(Vec::new()),
+        };
+
+        let all_mods = self.fetch_mod_manifest().await?;
+        Ok(find_dependents(target, &installed_mods, &all_mods))
+    }
+
+    /// どのMODからも依存されなくなった依存ライブラリ（`Game/rml_libs` 内の管理外DLL）を検出
+    ///
+    /// 削除して問題ないと判断できるものだけを返すため、マニフェストで他のMODの依存先として
+    /// 登録されているMODのファイルのうち、インストール済みMODの（間接的な）依存先に含まれないものに限る。
+    /// マニフェストに無いファイルやMODローダー本体が使う `0Harmony.dll` は対象外。
+    pub async fn find_orphaned_dependencies(&self) -> Result<Vec<PathBuf>, ModManagerError> {
+        let libs_dir = self.profile_dir.join("Game").join("rml_libs");
+        if !libs_dir.exists() {
+            return Ok(Vec::new());
+        }
+        
+        let all_mods = self.fetch_mod_manifest().await?;
+        let installed_mods = self.get_installed_mods()?;
+        let keys_of = |m: &ModInfo| -> Vec<String> {
+            m.id.iter().chain(std::iter::once(&m.name)).map(|k| k.to_lowercase()).collect()
+        };
+        let file_names_of = |m: &ModInfo| -> Vec<String> {
+            m.releases.iter()
+                .flat_map(|r| release_artifacts(r).into_iter().map(|a| artifact_file_name(&a)).chain(r.file_name.clone()))
+                .map(|name| name.to_lowercase())
+                .collect()
+        };
+        
+        // インストール済みMODから依存関係をたどり、必要なライブラリのファイル名を集める
+        let mut required_keys: Vec<String> = Vec::new();
+        let mut pending: Vec<&ModInfo> = installed_mods.iter()
+            .filter_map(|m| manifest_entry_for(m, &all_mods))
+            .collect();
+        while let Some(mod_info) = pending.pop() {
+            for dep in &mod_info.dependencies {
+                let dep = dep.to_lowercase();
+                if required_keys.contains(&dep) {
+                    continue;
+                }
+                required_keys.push(dep.clone());
+                if let Some(dep_info) = all_mods.iter().find(|m| keys_of(m).contains(&dep)) {
+                    pending.push(dep_info);
+                }
+            }
+        }
+        let required_files: Vec<String> = all_mods.iter()
+            .filter(|m| keys_of(m).iter().any(|k| required_keys.contains(k)))
+            .flat_map(file_names_of)
+            .collect();
+        
+        // マニフェスト上で依存先として使われているMOD（ライブラリ）のファイル名
+        let library_files: Vec<String> = all_mods.iter()
+            .filter(|m| {
+                let keys = keys_of(m);
+                all_mods.iter().any(|other| other.dependencies.iter().any(|dep| keys.contains(&dep.to_lowercase())))
+            })
+            .flat_map(file_names_of)
+            .collect();
+        
+        let managed_paths: Vec<PathBuf> = installed_mods.iter()
+            .map(|m| Self::normalize_path(&m.dll_path))
+            .collect();
+        
+        let mut orphaned: Vec<PathBuf> = fs::read_dir(&libs_dir)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && path.extension().map(|ext| ext.eq_ignore_ascii_case("dll")).unwrap_or(false))
+            .filter(|path| !managed_paths.contains(&Self::normalize_path(path)))
+            .filter(|path| {
+                let file_name = path.file_name().unwrap_or_default().to_string_lossy().to_lowercase();
+                file_name != "0harmony.dll"
+                    && library_files.contains(&file_name)
+                    && !required_files.contains(&file_name)
+            })
+            .collect();
+        orphaned.sort();
+        Ok(orphaned)
+    }
+
+    /// 検出した孤立ライブラリを削除（ユーザーが確認したファイルのみ）
+    ///
+    /// 確認後に状況が変わっている場合に備えて再検出し、孤立ライブラリでなくなったファイルが含まれていればエラーにする。
+    pub async fn delete_orphaned_dependencies(&self, files: &[PathBuf]) -> Result<Vec<PathBuf>, ModManagerError> {
+        let orphaned: Vec<PathBuf> = self.find_orphaned_dependencies().await?
+            .iter()
+            .map(|path| Self::normalize_path(path))
+            .collect();
+        
+        if let Some(not_orphaned) = files.iter().find(|file| !orphaned.contains(&Self::normalize_path(file))) {
+            return Err(format!("{} is not an orphaned dependency", not_orphaned.display()).into());
+        }
+        
+        let mut deleted = Vec::new();
+        for file in files {
+            fs::remove_file(file)?;
+            log_println!("Deleted orphaned dependency: {}", file.display());
+            deleted.push(file.clone());
+        }
+        Ok(deleted)
+    }
+
+    /// 依存しているMODが無いか確認してからアンインストールする
+    ///
+    /// 依存しているMODがある場合、`force` でなければ `ModManagerError::HasDependents` を返して削除しない。
+    /// マニフェストを取得できない場合は依存関係を確認できないため、そのまま削除する。
+    pub async fn uninstall_mod_checked(&self, mod_name: &str, force: bool) -> Result<(), ModManagerError> {
+        if !force {
+            match self.find_dependent_mods(mod_name).await {
+                Ok(dependents) if !dependents.is_empty() => {
+                    return Err(ModManagerError::HasDependents {
+                        mod_name: mod_name.to_string(),
+                        dependents,
+                    });
+                }
+                Ok(_) => {}
+                Err(e) => log_println!("Could not check dependent mods of '{}': {}", mod_name, e),
+            }
+        }
+
+        self.uninstall_mod(mod_name)
+    }
+
+    /// 有効なMODのうち、指定したMODローダーでは読み込まれないものを返す
+    ///
+    /// MODごとのローダーは `InstalledMod::loader_type` で判定する。RML用のMODはRMLのほか、
+    /// RML互換のゲームパックが入っていればMonkeyLoaderでも読み込まれる。
+    /// MODローダーが無い（`None`）場合は判定できないため、何も返さない。
+    pub fn find_loader_mismatches(&self, profile_loader: Option<ModLoaderType>) -> Result<Vec<ModLoaderMismatch>, ModManagerError> {
+        let Some(profile_loader) = profile_loader else {
+            return Ok(Vec::new());
+        };
+        let rml_compat = self.has_rml_compat_gamepack();
+
+        Ok(self.get_installed_mods()?
+            .into_iter()
+            .filter(|m| m.enabled != Some(false))
+            .filter_map(|m| {
+                let mod_loader = m.loader_type();
+                let loaded = mod_loader == profile_loader
+                    || (profile_loader == ModLoaderType::MonkeyLoader && mod_loader == ModLoaderType::ResoniteModLoader && rml_compat);
+                if loaded {
+                    return None;
+                }
+
+                let is_nupkg = m.file_format.as_deref() == Some("nupkg")
+                    || m.dll_path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("nupkg"));
+                Some(ModLoaderMismatch {
+                    mod_name: m.name,
+                    file_format: if is_nupkg { "nupkg" } else { "dll" }.to_string(),
+                    supported_loaders: match mod_loader {
+                        ModLoaderType::ResoniteModLoader => vec![ModLoaderType::ResoniteModLoader, ModLoaderType::MonkeyLoader],
+                        other => vec![other],
+                    },
+                })
+            })
+            .collect())
+    }
+
+    /// 指定したMODローダーでは読み込まれないMODをすべて無効化し、無効化したMOD名を返す
+    pub fn disable_loader_mismatches(&self, profile_loader: Option<ModLoaderType>) -> Result<Vec<String>, ModManagerError> {
+        let mut disabled = Vec::new();
+        for mismatch in self.find_loader_mismatches(profile_loader)? {
+            self.disable_mod(&mismatch.mod_name)?;
+            disabled.push(mismatch.mod_name);
+        }
+        Ok(disabled)
+    }
+
+    /// MonkeyLoaderのRML互換ゲームパックが導入されているか
+    fn has_rml_compat_gamepack(&self) -> bool {
+        let gamepacks_dir = self.profile_dir.join("Game").join("MonkeyLoader").join("GamePacks");
+        fs::read_dir(gamepacks_dir)
+            .map(|entries| {
+                entries.filter_map(|e| e.ok()).any(|e| {
+                    e.file_name().to_string_lossy().to_lowercase().contains("resonitemodloader")
+                })
+            })
+            .unwrap_or(false)
+    }
+
+    /// MODを無効化（RMLは拡張子を.disabledに変更、MonkeyLoaderの `Mods` 内のMODは `DisabledMods` フォルダへ移動）
+    pub fn disable_mod(&self, mod_name: &str) -> Result<(), ModManagerError> {
         let mut installed_mods = self.get_installed_mods()?;
         
         if let Some(mod_info) = installed_mods.iter_mut().find(|m| m.name == mod_name) {
             let current_path = &mod_info.dll_path;
             
             // 既に無効化されているかチェック
-            if current_path.extension().and_then(|ext| ext.to_str()) == Some("disabled") {
+            if current_path.extension().and_then(|ext| ext.to_str()) == Some("disabled")
+                || current_path.starts_with(self.monkey_loader_disabled_dir())
+            {
                 return Err("MOD is already disabled".into());
             }
             
-            // 現在のパスに.disabledを追加
-            let current_path_str = current_path.to_string_lossy();
-            let disabled_path_str = format!("{}.disabled", current_path_str);
-            let disabled_path = PathBuf::from(disabled_path_str);
+            let monkey_relative_path = current_path.strip_prefix(self.monkey_loader_mods_dir()).ok()
+                .filter(|_| mod_info.mod_loader_type.as_deref() == Some("MonkeyLoader"));
+            let disabled_path = if let Some(relative_path) = monkey_relative_path {
+                // MonkeyLoaderは.disabledを認識しないことがあるため、Modsの外のDisabledModsフォルダへ移動
+                let disabled_path = self.monkey_loader_disabled_dir().join(relative_path);
+                if let Some(parent) = disabled_path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                disabled_path
+            } else {
+                // 現在のパスに.disabledを追加
+                let current_path_str = current_path.to_string_lossy();
+                PathBuf::from(format!("{}.disabled", current_path_str))
+            };
             
-            // ファイルをリネーム
+            // ファイルを移動
             if current_path.exists() {
                 fs::rename(current_path, &disabled_path)?;
             }
@@ -1217,36 +3653,35 @@ impl ModManager {
             // インストール済みMOD一覧を更新
             self.save_installed_mods(&installed_mods)?;
         } else {
-            return Err(format!("MOD '{}' not found", mod_name).into());
+            return Err(ModManagerError::NotFound(format!("MOD '{}' not found", mod_name)));
         }
         
         Ok(())
     }
 
-    /// MODを有効化（.disabled拡張子を削除）
-    pub fn enable_mod(&self, mod_name: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    /// MODを有効化（.disabled拡張子を削除、またはDisabledModsフォルダから戻す）
+    pub fn enable_mod(&self, mod_name: &str) -> Result<(), ModManagerError> {
         let mut installed_mods = self.get_installed_mods()?;
         
         if let Some(mod_info) = installed_mods.iter_mut().find(|m| m.name == mod_name) {
             let current_path = &mod_info.dll_path;
-            
-            // 無効化されているかチェック
-            if current_path.extension().and_then(|ext| ext.to_str()) != Some("disabled") {
-                return Err("MOD is already enabled".into());
-            }
-            
-            // .disabledを削除した元のパスを復元
             let current_path_str = current_path.to_string_lossy();
-            let enabled_path_str = if current_path_str.ends_with(".disabled") {
-                // .disabledを単純に削除
-                current_path_str.strip_suffix(".disabled").unwrap().to_string()
+            
+            let enabled_path = if let Some(stripped) = current_path_str.strip_suffix(".disabled") {
+                // .disabledを単純に削除（以前の方式で無効化されたMonkeyLoaderのMODも含む）
+                PathBuf::from(stripped)
+            } else if let Ok(relative_path) = current_path.strip_prefix(self.monkey_loader_disabled_dir()) {
+                // DisabledModsフォルダから元のModsフォルダへ戻す
+                let enabled_path = self.monkey_loader_mods_dir().join(relative_path);
+                if let Some(parent) = enabled_path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                enabled_path
             } else {
-                return Err("Invalid disabled file format".into());
+                return Err("MOD is already enabled".into());
             };
             
-            let enabled_path = PathBuf::from(enabled_path_str);
-            
-            // ファイルをリネーム
+            // ファイルを移動
             if current_path.exists() {
                 fs::rename(current_path, &enabled_path)?;
             }
@@ -1258,17 +3693,427 @@ impl ModManager {
             // インストール済みMOD一覧を更新
             self.save_installed_mods(&installed_mods)?;
         } else {
-            return Err(format!("MOD '{}' not found", mod_name).into());
+            return Err(ModManagerError::NotFound(format!("MOD '{}' not found", mod_name)));
+        }
+        
+        Ok(())
+    }
+    
+    /// MonkeyLoaderのMODフォルダ（配下は再帰的に読み込まれる）
+    fn monkey_loader_mods_dir(&self) -> PathBuf {
+        self.profile_dir.join("Game").join("MonkeyLoader").join("Mods")
+    }
+
+    /// 無効化したMonkeyLoaderのMODの移動先
+    fn monkey_loader_disabled_dir(&self) -> PathBuf {
+        self.profile_dir.join("Game").join("MonkeyLoader").join(DISABLED_FOLDER_NAME)
+    }
+
+    /// セーフモード用に有効なMODをすべて無効化し、無効化したMOD名を返す
+    ///
+    /// 無効化したMODは原因特定のために記録され、`enable_next_safe_mode_mod` で1つずつ戻せる。
+    pub fn disable_all_mods(&self) -> Result<Vec<String>, ModManagerError> {
+        let enabled_mods: Vec<String> = self.get_installed_mods()?
+            .into_iter()
+            .filter(|m| m.enabled.unwrap_or(true))
+            .map(|m| m.name)
+            .collect();
+        
+        let mut disabled_mods = Vec::new();
+        for mod_name in enabled_mods {
+            match self.disable_mod(&mod_name) {
+                Ok(()) => disabled_mods.push(mod_name),
+                Err(e) => eprintln!("Failed to disable mod {}: {}", mod_name, e),
+            }
+        }
+        
+        let mut pending = self.get_safe_mode_pending_mods();
+        for mod_name in &disabled_mods {
+            if !pending.contains(mod_name) {
+                pending.push(mod_name.clone());
+            }
+        }
+        self.save_safe_mode_pending_mods(&pending)?;
+        
+        log_println!("Safe mode: disabled {} mods", disabled_mods.len());
+        Ok(disabled_mods)
+    }
+    
+    /// セーフモードで無効化され、まだ有効化されていないMOD名を取得
+    pub fn get_safe_mode_pending_mods(&self) -> Vec<String> {
+        fs::read_to_string(self.profile_dir.join("safe_mode_disabled_mods.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+    
+    /// セーフモードで無効化したMODを1つ有効化する（原因特定ウィザード用）
+    ///
+    /// 有効化したMOD名を返す。残りが無い場合はNone。
+    /// 有効化に失敗したMODは記録に残したままエラーを返す（アンインストール済みのMODは記録から外す）。
+    pub fn enable_next_safe_mode_mod(&self) -> Result<Option<String>, ModManagerError> {
+        let mut pending = self.get_safe_mode_pending_mods();
+        if pending.is_empty() {
+            return Ok(None);
+        }
+        
+        while !pending.is_empty() {
+            let mod_name = pending[0].clone();
+            match self.enable_mod(&mod_name) {
+                Ok(()) => {
+                    pending.remove(0);
+                    self.save_safe_mode_pending_mods(&pending)?;
+                    return Ok(Some(mod_name));
+                }
+                Err(ModManagerError::NotFound(_)) => {
+                    log_println!("Safe mode: {} is no longer installed, skipping", mod_name);
+                    pending.remove(0);
+                }
+                Err(e) => {
+                    self.save_safe_mode_pending_mods(&pending)?;
+                    return Err(e);
+                }
+            }
+        }
+        self.save_safe_mode_pending_mods(&pending)?;
+        
+        Ok(None)
+    }
+    
+    /// セーフモードで無効化したMODをすべて有効化に戻す
+    ///
+    /// 有効化に失敗したMODは記録に残し、失敗したMODをまとめたエラーを返す。
+    pub fn restore_safe_mode_mods(&self) -> Result<Vec<String>, ModManagerError> {
+        let mut restored = Vec::new();
+        let mut remaining = Vec::new();
+        let mut failures = Vec::new();
+        for mod_name in self.get_safe_mode_pending_mods() {
+            match self.enable_mod(&mod_name) {
+                Ok(()) => restored.push(mod_name),
+                Err(ModManagerError::NotFound(_)) => {
+                    log_println!("Safe mode: {} is no longer installed, skipping", mod_name);
+                }
+                Err(e) => {
+                    failures.push(format!("{} ({})", mod_name, e));
+                    remaining.push(mod_name);
+                }
+            }
+        }
+        self.save_safe_mode_pending_mods(&remaining)?;
+        
+        if !failures.is_empty() {
+            return Err(ModManagerError::Other(format!(
+                "Failed to re-enable {} of {} mods: {}",
+                failures.len(), failures.len() + restored.len(), failures.join(", ")
+            )));
+        }
+        Ok(restored)
+    }
+    
+    fn save_safe_mode_pending_mods(&self, pending: &[String]) -> Result<(), ModManagerError> {
+        let content = serde_json::to_string_pretty(pending)?;
+        fs::write(self.profile_dir.join("safe_mode_disabled_mods.json"), content)?;
+        Ok(())
+    }
+
+    /// MODの読み込み順を設定（`order` の先頭ほど先に読み込まれる）
+    ///
+    /// ファイル名に `000_`, `010_` ... の接頭辞を付けてリネームし、`dll_path` と `load_priority` を更新する。
+    /// `order` に含まれないMODは接頭辞を外し、従来どおりファイル名順で読み込ませる。
+    pub fn set_load_order(&self, order: Vec<String>) -> Result<Vec<InstalledMod>, ModManagerError> {
+        let mut installed_mods = self.get_installed_mods()?;
+        
+        if let Some(unknown) = order.iter().find(|name| !installed_mods.iter().any(|m| &m.name == *name)) {
+            return Err(ModManagerError::NotFound(format!("MOD '{}' not found", unknown)));
+        }
+        if (order.len() as u32).saturating_sub(1).saturating_mul(LOAD_PRIORITY_STEP) > MAX_LOAD_PRIORITY {
+            return Err(format!("Too many MODs to order (max {})", MAX_LOAD_PRIORITY / LOAD_PRIORITY_STEP + 1).into());
+        }
+        
+        for installed_mod in installed_mods.iter_mut() {
+            let priority = order.iter()
+                .position(|name| *name == installed_mod.name)
+                .map(|index| index as u32 * LOAD_PRIORITY_STEP);
+            Self::apply_load_priority(installed_mod, priority)?;
+        }
+        
+        self.save_installed_mods(&installed_mods)?;
+        Ok(installed_mods)
+    }
+
+    /// 1つのMODの読み込み順の優先度を設定（Noneで接頭辞を外す）
+    pub fn set_mod_load_priority(&self, mod_name: &str, priority: Option<u32>) -> Result<InstalledMod, ModManagerError> {
+        if priority.is_some_and(|p| p > MAX_LOAD_PRIORITY) {
+            return Err(format!("Load priority must be {} or less", MAX_LOAD_PRIORITY).into());
+        }
+        
+        let mut installed_mods = self.get_installed_mods()?;
+        let installed_mod = installed_mods.iter_mut()
+            .find(|m| m.name == mod_name)
+            .ok_or_else(|| ModManagerError::NotFound(format!("MOD '{}' not found", mod_name)))?;
+        Self::apply_load_priority(installed_mod, priority)?;
+        let updated = installed_mod.clone();
+        
+        self.save_installed_mods(&installed_mods)?;
+        Ok(updated)
+    }
+
+    /// MODファイルを優先度に応じた名前にリネームし、エントリを更新する
+    fn apply_load_priority(installed_mod: &mut InstalledMod, priority: Option<u32>) -> Result<(), ModManagerError> {
+        // 元々数字で始まるファイル名を誤って変更しないよう、未設定のMODはそのままにする
+        if priority.is_none() && installed_mod.load_priority.is_none() {
+            return Ok(());
         }
+
+        let file_name = installed_mod.dll_path.file_name()
+            .ok_or("Invalid mod file path")?
+            .to_string_lossy()
+            .to_string();
+        let new_path = installed_mod.dll_path.with_file_name(load_order_file_name(&file_name, priority));
         
+        if new_path != installed_mod.dll_path {
+            if new_path.exists() {
+                return Err(ModManagerError::FileSystemError(format!("Cannot rename {} to {}: file already exists", file_name, new_path.display())));
+            }
+            if installed_mod.dll_path.exists() {
+                fs::rename(&installed_mod.dll_path, &new_path)?;
+            }
+            installed_mod.dll_path = new_path;
+        }
+        installed_mod.load_priority = priority;
+        Ok(())
+    }
+
+    /// MODローダーが読み込む順序を予測する（Gameフォルダからの相対パス）
+    ///
+    /// RMLは `rml_mods` 直下の `.dll` のみをファイル名の大文字小文字を区別しないアルファベット順で読み込み、
+    /// サブフォルダや `.disabled` のファイルは読み込まない。MonkeyLoaderは `Mods` 配下を再帰的に探す
+    /// （無効化したMODは `Mods` の外の `DisabledMods` フォルダにあるため含まれない）。
+    pub fn get_predicted_load_order(&self) -> Vec<String> {
+        let game_dir = self.profile_dir.join("Game");
+        let mut load_order = Vec::new();
+        
+        let mut rml_files: Vec<PathBuf> = fs::read_dir(&self.mods_dir)
+            .map(|entries| entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|path| path.is_file() && path.extension().map(|ext| ext == "dll").unwrap_or(false))
+                .collect())
+            .unwrap_or_default();
+        rml_files.sort_by_key(|path| path.file_name().unwrap_or_default().to_string_lossy().to_lowercase());
+        
+        let monkey_mods_dir = game_dir.join("MonkeyLoader").join("Mods");
+        let mut monkey_files = Vec::new();
+        if monkey_mods_dir.exists() {
+            let _ = Self::collect_files_recursive(&monkey_mods_dir, &["nupkg", "dll"], &mut monkey_files);
+        }
+        monkey_files.sort_by_key(|path| path.to_string_lossy().to_lowercase());
+        
+        for path in rml_files.iter().chain(monkey_files.iter()) {
+            let relative = path.strip_prefix(&game_dir).unwrap_or(path);
+            load_order.push(relative.to_string_lossy().replace('\\', "/"));
+        }
+        
+        load_order
+    }
+
+    /// MODの設定スキーマを解釈してGUIのフォーム用に返す
+    ///
+    /// スキーマが無いMODは `has_schema: false` となり、生JSONでの編集にフォールバックする。
+    pub fn parse_mod_config_schema(&self, mod_name: &str) -> Result<ConfigSchema, ModManagerError> {
+        Ok(mod_config::load_config_schema(&self.profile_dir.join("Game"), mod_name)?)
+    }
+
+    /// インストール済みMODと設定ファイル（rml_config / MonkeyLoader/Configs）を対応付ける
+    ///
+    /// どのMODのものか判別できない設定ファイルは `unmatched` に入る。
+    pub fn map_mod_configs(&self) -> Result<ModConfigMap, ModManagerError> {
+        let mods = self.config_match_keys()?;
+        let game_dir = self.profile_dir.join("Game");
+        Ok(mod_config::match_config_files(&mods, &mod_config::list_config_files(&game_dir)))
+    }
+
+    /// 設定ファイルの対応付けに使う（MOD名, DLLのファイル名）の組
+    fn config_match_keys(&self) -> Result<Vec<(String, String)>, ModManagerError> {
+        Ok(self.get_installed_mods()?
+            .into_iter()
+            .map(|m| {
+                // 無効化されたMODは "Name.dll.disabled" になっているため拡張子を二重に除く
+                let file_name = m.dll_path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+                let file_name = file_name.strip_suffix(".disabled").unwrap_or(&file_name);
+                let file_name = strip_load_order_prefix(file_name);
+                let dll_name = std::path::Path::new(file_name)
+                    .file_stem()
+                    .map(|s| s.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                (m.name, dll_name)
+            })
+            .collect())
+    }
+
+    /// `Game/rml_config` 配下の設定ファイルを、対応するMOD名と合わせて列挙
+    pub fn list_mod_configs(&self) -> Result<Vec<ModConfig>, ModManagerError> {
+        let mods = self.config_match_keys()?;
+        Ok(mod_config::list_rml_configs(&self.profile_dir.join("Game"), &mods))
+    }
+
+    /// `rml_config` 配下の設定ファイルを読み込む（`file_name` は `rml_config` からの相対パス）
+    pub fn read_mod_config(&self, file_name: &str) -> Result<String, ModManagerError> {
+        Ok(mod_config::read_rml_config(&self.profile_dir.join("Game"), file_name)?)
+    }
+
+    /// `rml_config` 配下の設定ファイルを書き込む（JSONとして不正な内容はエラー）
+    pub fn write_mod_config(&self, file_name: &str, content: &str) -> Result<(), ModManagerError> {
+        Ok(mod_config::write_rml_config(&self.profile_dir.join("Game"), file_name, content)?)
+    }
+
+    /// ファイルをダウンロードして保存（失敗時は指数バックオフで再試行）
+    async fn download_to_file(&self, url: &str, file_path: &std::path::Path) -> Result<(), ModManagerError> {
+        Self::download_with_retry(&self.client, &self.download_limiter, self.max_retries, url, file_path).await
+    }
+
+    /// ダウンロード元の候補を順に試し、最初に取得できたものを使う
+    ///
+    /// ハッシュが指定されている場合は一致しないファイルも失敗として次の候補を試す（改ざん・破損の検出）。
+    async fn download_from_candidates(
+        &self,
+        candidates: &[String],
+        file_path: &std::path::Path,
+        expected_sha256: Option<&str>,
+    ) -> Result<(), ModManagerError> {
+        let file_name = file_path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let mut failures = Vec::new();
+
+        for url in candidates {
+            if let Err(e) = self.download_to_file(url, file_path).await {
+                log_println!("Download failed from {}: {}", url, e);
+                failures.push(format!("{}: {}", url, e));
+                continue;
+            }
+
+            if let Some(expected_sha256) = expected_sha256 {
+                let actual_sha256 = self.calculate_file_sha256(file_path)?;
+                if !actual_sha256.eq_ignore_ascii_case(expected_sha256) {
+                    let _ = fs::remove_file(file_path);
+                    failures.push(format!(
+                        "{}: SHA256 mismatch for {}: expected {}, got {}",
+                        url, file_name, expected_sha256, actual_sha256
+                    ));
+                    continue;
+                }
+                log_println!("SHA256 verified for {}", file_name);
+            }
+            return Ok(());
+        }
+
+        Err(ModManagerError::NetworkError(format!(
+            "Failed to download {} from all {} sources:\n{}",
+            file_name,
+            candidates.len(),
+            failures.join("\n")
+        )))
+    }
+
+    /// 複数のファイルを並列にダウンロード（並列度はDownloadLimiterで制限）
+    ///
+    /// 結果は入力と同じ順序で返す。
+    pub async fn download_files(&self, downloads: Vec<(String, PathBuf)>) -> Vec<Result<(), String>> {
+        let handles: Vec<_> = downloads
+            .into_iter()
+            .map(|(url, file_path)| {
+                let client = self.client.clone();
+                let limiter = self.download_limiter.clone();
+                let max_retries = self.max_retries;
+                tokio::spawn(async move {
+                    Self::download_with_retry(&client, &limiter, max_retries, &url, &file_path)
+                        .await
+                        .map_err(|e| e.to_string())
+                })
+            })
+            .collect();
+
+        let mut results = Vec::with_capacity(handles.len());
+        for handle in handles {
+            results.push(handle.await.unwrap_or_else(|e| Err(format!("Download task failed: {}", e))));
+        }
+        results
+    }
+
+    async fn download_with_retry(
+        client: &reqwest::Client,
+        limiter: &DownloadLimiter,
+        max_retries: u32,
+        url: &str,
+        file_path: &std::path::Path,
+    ) -> Result<(), ModManagerError> {
+        let mut attempt = 0;
+        
+        // 一時ファイルに保存し、成功した場合だけ置き換える（失敗しても既存のファイルを残す）
+        let mut part_file_name = file_path.file_name().unwrap_or_default().to_os_string();
+        part_file_name.push(".part");
+        let part_path = file_path.with_file_name(part_file_name);
+        
+        loop {
+            // 部分的にダウンロードされたファイルを削除してから取得
+            if part_path.exists() {
+                fs::remove_file(&part_path)?;
+            }
+            
+            // レート制限中のホストはここで解除まで待機する
+            let result = {
+                let _permit = limiter.acquire(url).await;
+                Self::try_download(client, limiter, url, &part_path).await
+            };
+            
+            match result {
+                Ok(()) => {
+                    limiter.report_success(url);
+                    if let Err(e) = fs::rename(&part_path, file_path) {
+                        let _ = fs::remove_file(&part_path);
+                        return Err(e.into());
+                    }
+                    return Ok(());
+                }
+                Err(e) => {
+                    if part_path.exists() {
+                        let _ = fs::remove_file(&part_path);
+                    }
+                    
+                    if attempt >= max_retries {
+                        return Err(ModManagerError::NetworkError(format!("Download failed after {} attempts: {}", attempt + 1, e)));
+                    }
+                    
+                    let delay = Duration::from_secs(1 << attempt);
+                    attempt += 1;
+                    eprintln!("Download failed ({}), retrying in {}s ({}/{}): {}", url, delay.as_secs(), attempt, max_retries, e);
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+    
+    /// ファイルを1回ダウンロードして保存
+    async fn try_download(client: &reqwest::Client, limiter: &DownloadLimiter, url: &str, file_path: &std::path::Path) -> Result<(), ModManagerError> {
+        let response = client.get(url).send().await?;
+        if !response.status().is_success() {
+            // レート制限ならホストの並列度を下げて待機させる
+            if let Some(wait) = rate_limit_wait(response.status().as_u16(), response.headers()) {
+                limiter.report_rate_limited(url, wait);
+                return Err(ModManagerError::NetworkError(format!("HTTP {} (rate limited)", response.status())));
+            }
+            return Err(ModManagerError::NetworkError(format!("HTTP {}", response.status())));
+        }
+        
+        let file_content = response.bytes().await?;
+        fs::write(file_path, file_content)?;
         Ok(())
     }
 
     /// 手動でGitHubリポジトリURLを解析
-    fn github_repo_to_api_url(&self, repo_url: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+    fn github_repo_to_api_url(&self, repo_url: &str) -> Result<String, ModManagerError> {
         let url = repo_url.trim_end_matches('/');
         
-        if let Some(captures) = regex::Regex::new(r"github\.com/([^/]+)/([^/]+)")?.captures(url) {
+        if let Some(captures) = regex::Regex::new(r"github\.com/([^/]+)/([^/]+)").map_err(|e| ModManagerError::Other(e.to_string()))?.captures(url) {
             let owner = captures.get(1).unwrap().as_str();
             let repo = captures.get(2).unwrap().as_str();
             Ok(format!("https://api.github.com/repos/{}/{}", owner, repo))
@@ -1278,28 +4123,30 @@ impl ModManager {
     }
 
     /// インストール済みMOD一覧に追加
-    fn add_to_installed_mods(&self, new_mod: &InstalledMod) -> Result<(), Box<dyn Error + Send + Sync>> {
+    fn add_to_installed_mods(&self, new_mod: &InstalledMod) -> Result<(), ModManagerError> {
         let mut installed_mods = self.get_installed_mods()?;
+        let mut new_mod = new_mod.clone();
+        new_mod.source_location = utils::normalize_source_location(&new_mod.source_location);
         
         // 同じ名前のMODがある場合は更新
         if let Some(pos) = installed_mods.iter().position(|m| m.name == new_mod.name) {
-            installed_mods[pos] = new_mod.clone();
+            installed_mods[pos] = new_mod;
         } else {
-            installed_mods.push(new_mod.clone());
+            installed_mods.push(new_mod);
         }
         
         self.save_installed_mods(&installed_mods)
     }
 
     /// インストール済みMOD一覧を保存
-    fn save_installed_mods(&self, mods: &[InstalledMod]) -> Result<(), Box<dyn Error + Send + Sync>> {
+    fn save_installed_mods(&self, mods: &[InstalledMod]) -> Result<(), ModManagerError> {
         let content = serde_json::to_string_pretty(mods)?;
         fs::write(&self.installed_mods_file, content)?;
         Ok(())
     }
     
     /// ファイルのSHA256ハッシュを計算
-    pub fn calculate_file_sha256(&self, file_path: &std::path::Path) -> Result<String, Box<dyn Error + Send + Sync>> {
+    pub fn calculate_file_sha256(&self, file_path: &std::path::Path) -> Result<String, ModManagerError> {
         let file_content = fs::read(file_path)?;
         let mut hasher = Sha256::new();
         hasher.update(&file_content);
@@ -1308,11 +4155,8 @@ impl ModManager {
     }
     
     /// ハッシュルックアップテーブルを取得
-    pub async fn fetch_hash_lookup_table(&self) -> Result<HashMap<String, HashLookupEntry>, Box<dyn Error + Send + Sync>> {
-        let cache_url = "https://raw.githubusercontent.com/resonite-love/resonite-mod-cache/master/cache/hash-lookup.json";
-        
-        let response = self.client.get(cache_url).send().await?;
-        let lookup_text = response.text().await?;
+    pub async fn fetch_hash_lookup_table(&self) -> Result<HashMap<String, HashLookupEntry>, ModManagerError> {
+        let lookup_text = self.fetch_cached(&self.hash_lookup_cache, false).await?;
         let lookup_table: HashMap<String, HashLookupEntry> = serde_json::from_str(&lookup_text)?;
         
         Ok(lookup_table)
@@ -1322,20 +4166,20 @@ impl ModManager {
     pub async fn find_mod_by_hash(&self, hash: &str) -> Option<HashLookupEntry> {
         match self.fetch_hash_lookup_table().await {
             Ok(lookup_table) => {
-                println!("Hash lookup table fetched successfully, {} entries", lookup_table.len());
+                log_println!("Hash lookup table fetched successfully, {} entries", lookup_table.len());
                 match lookup_table.get(hash) {
                     Some(entry) => {
-                        println!("Found hash match for {}: {}", hash, entry.mod_name);
+                        log_println!("Found hash match for {}: {}", hash, entry.mod_name);
                         Some(entry.clone())
                     }
                     None => {
-                        println!("Hash {} not found in lookup table", hash);
+                        log_println!("Hash {} not found in lookup table", hash);
                         None
                     }
                 }
             }
             Err(e) => {
-                println!("Failed to fetch hash lookup table: {}", e);
+                log_println!("Failed to fetch hash lookup table: {}", e);
                 None
             }
         }
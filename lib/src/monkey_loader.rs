@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 use anyhow::{Result, anyhow};
 use zip::ZipArchive;
 
+use crate::utils;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MonkeyLoaderInfo {
     pub installed: bool,
@@ -24,6 +26,8 @@ pub struct GitHubAsset {
 }
 
 const GITHUB_API_URL: &str = "https://api.github.com/repos/ResoniteModdingGroup/MonkeyLoader.GamePacks.Resonite/releases/latest";
+/// インストールしたバージョン（タグ）を記録するファイル名（MonkeyLoaderフォルダ内）
+const VERSION_FILE_NAME: &str = "installed_version.txt";
 
 pub struct MonkeyLoader {
     game_path: PathBuf,
@@ -74,9 +78,31 @@ impl MonkeyLoader {
         // 一時ファイルを削除
         fs::remove_file(&temp_path)?;
 
+        // 更新確認用にバージョンを記録
+        let monkeyloader_dir = self.game_path.join("MonkeyLoader");
+        fs::create_dir_all(&monkeyloader_dir)?;
+        fs::write(monkeyloader_dir.join(VERSION_FILE_NAME), &release.tag_name)?;
+
         Ok(format!("MonkeyLoader {} をインストールしました", release.tag_name))
     }
 
+    /// 最新リリースとインストール済みバージョンを比較し、更新可能なら新しいバージョンを返す
+    ///
+    /// インストール済みバージョンが不明な場合は比較できないためNoneを返す。
+    pub async fn check_update(&self) -> Result<Option<String>> {
+        let current_version = match self.get_status()?.version {
+            Some(version) if version != "Unknown" => version,
+            _ => return Ok(None),
+        };
+
+        let release = self.get_latest_release().await?;
+        if utils::compare_versions(&release.tag_name, &current_version) == std::cmp::Ordering::Greater {
+            Ok(Some(release.tag_name))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// MonkeyLoaderをアンインストール
     pub fn uninstall(&self) -> Result<String> {
         // MonkeyLoader関連ファイルを削除
@@ -120,10 +146,13 @@ impl MonkeyLoader {
         current_args.retain(|arg| !arg.starts_with("--doorstop-enabled"));
     }
 
-    /// バージョンを検出（実装は後で改善）
+    /// バージョンを検出（インストール時に記録したバージョンを読み取る）
     fn detect_version(&self) -> Option<String> {
-        // 現在は固定値を返す（後でファイルから読み取るように改善）
-        Some("Unknown".to_string())
+        let recorded = fs::read_to_string(self.game_path.join("MonkeyLoader").join(VERSION_FILE_NAME))
+            .ok()
+            .map(|content| content.trim().to_string())
+            .filter(|version| !version.is_empty());
+        Some(recorded.unwrap_or_else(|| "Unknown".to_string()))
     }
 
     /// GitHubから最新リリース情報を取得
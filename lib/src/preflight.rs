@@ -0,0 +1,293 @@
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::path::Path;
+
+use crate::bepis_loader::BepisLoader;
+use crate::launch_args::get_known_launch_args;
+use crate::launch_lock;
+use crate::mod_loader::ModLoader;
+use crate::mod_loader_type::ModLoaderType;
+use crate::mod_manager::ModManager;
+use crate::monkey_loader::MonkeyLoader;
+use crate::profile::{Profile, ProfileManager};
+use crate::utils::{self, WriteAccessFailure};
+
+/// 起動前チェックの結果（Ok < Warning < Error の順に重い）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CheckStatus {
+    Ok,
+    /// 起動はできるが確認した方がよい
+    Warning,
+    /// このままでは起動できない
+    Error,
+}
+
+/// 起動前チェックの項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PreflightCheckKind {
+    /// ゲーム本体がインストールされているか
+    Game,
+    /// 同じプロファイルが起動中でないか
+    NotRunning,
+    /// 起動引数を展開でき、値が必要な引数に値があるか
+    LaunchArgs,
+    /// MODローダーが導入済みで、MODがローダーに合っているか
+    ModLoader,
+    /// DataPathのドライブが接続されていて書き込めるか
+    DataPath,
+    /// ゲームのバージョンを取得でき、記録や固定バージョンと一致するか
+    GameVersion,
+}
+
+/// 起動前チェック1項目の結果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreflightCheck {
+    pub kind: PreflightCheckKind,
+    pub status: CheckStatus,
+    pub message: String,
+}
+
+/// 起動前チェックの結果まとめ
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreflightReport {
+    pub profile_name: String,
+    /// 全項目のうち最も重い結果
+    pub status: CheckStatus,
+    pub checks: Vec<PreflightCheck>,
+}
+
+impl PreflightReport {
+    /// エラーが無く起動してよいか（警告は確認のうえ続行できる）
+    pub fn can_launch(&self) -> bool {
+        self.status != CheckStatus::Error
+    }
+}
+
+fn check(kind: PreflightCheckKind, status: CheckStatus, message: impl Into<String>) -> PreflightCheck {
+    PreflightCheck {
+        kind,
+        status,
+        message: message.into(),
+    }
+}
+
+/// 起動前のチェックをまとめて実行する
+///
+/// ネットワークを使うチェック（アップデートの有無など）は含まない。
+pub fn preflight_check(profile_manager: &ProfileManager, profile_name: &str) -> Result<PreflightReport, Box<dyn Error>> {
+    let profile = profile_manager.get_profile(profile_name)?;
+    let profile_dir = profile_manager.get_profile_dir(profile_name);
+
+    let mut checks = vec![
+        check_game(&profile, &profile_dir),
+        check_not_running(&profile_dir),
+    ];
+
+    let args = profile.resolve_launch_args(&profile_dir);
+    checks.push(match &args {
+        Ok(args) => check_launch_args(args),
+        Err(e) => check(PreflightCheckKind::LaunchArgs, CheckStatus::Error, format!("起動引数を展開できません: {}", e)),
+    });
+    checks.push(check_mod_loader(&profile, &profile_dir));
+    if let Ok(args) = &args {
+        checks.push(check_data_path(args));
+    }
+    checks.push(check_game_version(&profile, &profile_dir));
+
+    let status = checks.iter().map(|c| c.status).max().unwrap_or(CheckStatus::Ok);
+    Ok(PreflightReport {
+        profile_name: profile_name.to_string(),
+        status,
+        checks,
+    })
+}
+
+fn check_game(profile: &Profile, profile_dir: &Path) -> PreflightCheck {
+    let exe = profile.get_resonite_exe(profile_dir);
+    if !profile.has_game_installed() {
+        check(PreflightCheckKind::Game, CheckStatus::Error, "ゲームがインストールされていません")
+    } else if !exe.exists() {
+        check(PreflightCheckKind::Game, CheckStatus::Error, format!("Resonite.exeが見つかりません: {}", exe.display()))
+    } else {
+        check(PreflightCheckKind::Game, CheckStatus::Ok, "ゲームはインストール済みです")
+    }
+}
+
+fn check_not_running(profile_dir: &Path) -> PreflightCheck {
+    match launch_lock::running_pid(profile_dir) {
+        Some(pid) => check(
+            PreflightCheckKind::NotRunning,
+            CheckStatus::Error,
+            format!("このプロファイルは既に起動中です (PID {})", pid),
+        ),
+        None => check(PreflightCheckKind::NotRunning, CheckStatus::Ok, "起動中ではありません"),
+    }
+}
+
+/// 値が必要な既知の引数に値が無いものを警告する
+fn check_launch_args(args: &[String]) -> PreflightCheck {
+    let known_args = get_known_launch_args();
+    let missing_values: Vec<&str> = args
+        .iter()
+        .enumerate()
+        .filter(|(_, arg)| known_args.iter().any(|spec| spec.takes_value && spec.name.eq_ignore_ascii_case(arg)))
+        .filter(|(i, _)| args.get(i + 1).is_none_or(|next| next.starts_with('-')))
+        .map(|(_, arg)| arg.as_str())
+        .collect();
+
+    if missing_values.is_empty() {
+        check(PreflightCheckKind::LaunchArgs, CheckStatus::Ok, "起動引数に問題はありません")
+    } else {
+        check(
+            PreflightCheckKind::LaunchArgs,
+            CheckStatus::Warning,
+            format!("値が指定されていない引数があります: {}", missing_values.join(", ")),
+        )
+    }
+}
+
+fn check_mod_loader(profile: &Profile, profile_dir: &Path) -> PreflightCheck {
+    let Some(loader) = profile.mod_loader_type else {
+        return check(PreflightCheckKind::ModLoader, CheckStatus::Ok, "MODローダーは使用していません");
+    };
+
+    let game_dir = profile.get_game_dir(profile_dir);
+    let installed = match loader {
+        ModLoaderType::ResoniteModLoader => ModLoader::new(game_dir.clone()).get_status().is_ok_and(|s| s.installed),
+        ModLoaderType::MonkeyLoader => MonkeyLoader::new(game_dir.clone()).get_status().is_ok_and(|s| s.installed),
+        ModLoaderType::BepisLoader => BepisLoader::new(profile_dir.to_path_buf()).get_status().installed,
+    };
+    if !installed {
+        return check(
+            PreflightCheckKind::ModLoader,
+            CheckStatus::Warning,
+            format!("{:?} がインストールされていません", loader),
+        );
+    }
+
+    if loader == ModLoaderType::ResoniteModLoader && ModLoader::new(game_dir).needs_launch_arg_update(&profile.args) {
+        return check(
+            PreflightCheckKind::ModLoader,
+            CheckStatus::Warning,
+            "起動引数にResoniteModLoaderの -LoadAssembly がありません",
+        );
+    }
+
+    match ModManager::new(profile_dir.to_path_buf()).find_loader_mismatches(Some(loader)) {
+        Ok(mismatches) if !mismatches.is_empty() => {
+            let names: Vec<&str> = mismatches.iter().map(|m| m.mod_name.as_str()).collect();
+            check(
+                PreflightCheckKind::ModLoader,
+                CheckStatus::Warning,
+                format!("{:?} では読み込まれないMODがあります: {}", loader, names.join(", ")),
+            )
+        }
+        Ok(_) => check(PreflightCheckKind::ModLoader, CheckStatus::Ok, format!("{:?} は導入済みです", loader)),
+        Err(e) => check(
+            PreflightCheckKind::ModLoader,
+            CheckStatus::Warning,
+            format!("MODの確認に失敗しました: {}", e),
+        ),
+    }
+}
+
+/// 実際に渡すDataPathに書き込めるか（ドライブの接続・権限を含む）
+fn check_data_path(args: &[String]) -> PreflightCheck {
+    let data_path = args
+        .iter()
+        .position(|arg| arg.eq_ignore_ascii_case("-DataPath"))
+        .and_then(|i| args.get(i + 1));
+    let Some(data_path) = data_path else {
+        return check(PreflightCheckKind::DataPath, CheckStatus::Ok, "DataPathは既定の場所を使用します");
+    };
+
+    match utils::check_directory_writable(Path::new(data_path)) {
+        Ok(()) => check(PreflightCheckKind::DataPath, CheckStatus::Ok, "DataPathに書き込めます"),
+        // 空き容量不足は起動自体はできるため警告にとどめる
+        Err(e) if e.reason == WriteAccessFailure::InsufficientSpace => {
+            check(PreflightCheckKind::DataPath, CheckStatus::Warning, e.to_string())
+        }
+        Err(e) => check(PreflightCheckKind::DataPath, CheckStatus::Error, e.to_string()),
+    }
+}
+
+fn check_game_version(profile: &Profile, profile_dir: &Path) -> PreflightCheck {
+    if !profile.has_game_installed() {
+        return check(PreflightCheckKind::GameVersion, CheckStatus::Ok, "ゲームが未インストールのため確認しません");
+    }
+
+    let Some(version) = profile.get_game_version(profile_dir) else {
+        return check(PreflightCheckKind::GameVersion, CheckStatus::Warning, "Build.versionからバージョンを取得できません");
+    };
+    if profile.is_pinned_version_mismatch() {
+        return check(
+            PreflightCheckKind::GameVersion,
+            CheckStatus::Warning,
+            format!(
+                "インストール済みのゲームが固定バージョン (マニフェスト {}) と異なります",
+                profile.pinned_manifest_id.as_deref().unwrap_or_default()
+            ),
+        );
+    }
+    match profile.game_info.as_ref().and_then(|info| info.version.as_deref()) {
+        Some(recorded) if recorded != version => check(
+            PreflightCheckKind::GameVersion,
+            CheckStatus::Warning,
+            format!("ゲームのバージョン {} が記録 ({}) と異なります", version, recorded),
+        ),
+        _ => check(PreflightCheckKind::GameVersion, CheckStatus::Ok, format!("バージョン {}", version)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::GameInfo;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_preflight_check() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = ProfileManager::new(temp_dir.path());
+        let mut profile = manager.create_profile("Preflight").unwrap();
+        let profile_dir = manager.get_profile_dir(profile.get_folder_name());
+
+        // ゲーム未インストールはエラー
+        let report = preflight_check(&manager, profile.get_folder_name()).unwrap();
+        assert_eq!(report.status, CheckStatus::Error);
+        assert!(!report.can_launch());
+
+        let game_dir = profile_dir.join("Game");
+        std::fs::create_dir_all(&game_dir).unwrap();
+        std::fs::write(game_dir.join("Resonite.exe"), b"").unwrap();
+        std::fs::write(game_dir.join("Build.version"), b"2025.1.1.1").unwrap();
+        profile.game_info = Some(GameInfo {
+            branch: "release".to_string(),
+            manifest_id: None,
+            depot_id: "2519832".to_string(),
+            installed: true,
+            last_updated: None,
+            version: Some("2025.1.1.1".to_string()),
+        });
+        profile.mod_loader_type = None;
+        profile.args.push("-BackgroundWorkers".to_string());
+        manager.update_profile(&profile).unwrap();
+
+        // 値の無い引数は警告だけで起動できる
+        let report = preflight_check(&manager, profile.get_folder_name()).unwrap();
+        assert_eq!(report.status, CheckStatus::Warning);
+        assert!(report.can_launch());
+        let args_check = report.checks.iter().find(|c| c.kind == PreflightCheckKind::LaunchArgs).unwrap();
+        assert_eq!(args_check.status, CheckStatus::Warning);
+        assert!(args_check.message.contains("-BackgroundWorkers"));
+
+        // 固定バージョンと異なるゲームが入っている場合は警告する
+        profile.args.clear();
+        profile.pinned_manifest_id = Some("222".to_string());
+        manager.update_profile(&profile).unwrap();
+        let report = preflight_check(&manager, profile.get_folder_name()).unwrap();
+        let version_check = report.checks.iter().find(|c| c.kind == PreflightCheckKind::GameVersion).unwrap();
+        assert_eq!(version_check.status, CheckStatus::Warning);
+        assert!(version_check.message.contains("222"));
+    }
+}
@@ -2,7 +2,14 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::error::Error;
+use std::collections::HashMap;
+use crate::game_dedup::{self, DedupReport};
+use crate::launch_args::{self, PlaceholderVariable, UndefinedPlaceholderPolicy};
+use crate::launch_lock;
 use crate::mod_loader_type::ModLoaderType;
+use crate::profile_template::{PendingTemplateSetup, ProfileDefaults, ProfileTemplate};
+use crate::version_history::{VersionHistoryManager, VersionRecord};
+use crate::webhook::WebhookConfig;
 
 /// Resoniteゲーム情報
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -15,9 +22,24 @@ pub struct GameInfo {
     pub version: Option<String>,
 }
 
+/// Resonite起動後に実行するコマンド
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PostLaunchCommand {
+    /// 実行するプログラム（パス変数を使用可能）
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Resonite終了時にこのプロセスも停止する
+    #[serde(default)]
+    pub stop_on_exit: bool,
+}
+
 /// プロファイル設定のバージョン
 const PROFILE_CONFIG_VERSION: u32 = 2;
 
+/// 初期状態でピン留めされているプロファイル
+const DEFAULT_PINNED_PROFILE: &str = "default";
+
 /// Resoniteの起動プロファイルを管理するための構造体
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Profile {
@@ -37,12 +59,58 @@ pub struct Profile {
     /// インストールされているMODローダーのタイプ
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mod_loader_type: Option<ModLoaderType>,
+    /// Resonite起動後に順に実行するコマンド
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_launch_commands: Vec<PostLaunchCommand>,
+    /// 一括更新・起動時チェックでゲームのアップデートを自動適用するか
+    #[serde(default = "default_auto_update_game")]
+    pub auto_update_game: bool,
+    /// 固定するゲームのマニフェストID（指定時は最新が出ても更新しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_manifest_id: Option<String>,
+    /// 起動引数に未定義の `{変数}` があった場合の扱い
+    #[serde(default)]
+    pub undefined_placeholder: UndefinedPlaceholderPolicy,
+    /// 最初にゲームをインストールするときのブランチ（新規プロファイルのデフォルト設定から引き継ぐ）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    /// テンプレートから作成し、ゲームのインストール後に適用するMODローダー・MOD
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_template_setup: Option<PendingTemplateSetup>,
+    /// 一覧の上部に固定するか（未設定の場合は `default` プロファイルのみ固定）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned: Option<bool>,
+    /// デバッグログモード（有効時は詳細ログ用の起動引数を付けて起動する）
+    #[serde(default)]
+    pub debug_logging: bool,
+    /// 起動・終了を外部に通知するWebhook（`launch_and_monitor` で使用）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<WebhookConfig>,
+    /// 起動時の解像度（幅, 高さ）。未設定の場合は引数を付けない
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<(u32, u32)>,
+    /// 起動時の画面モード（"windowed" / "fullscreen"）。未設定の場合は引数を付けない
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub screen_mode: Option<String>,
 }
 
 fn default_config_version() -> u32 {
     PROFILE_CONFIG_VERSION
 }
 
+fn default_auto_update_game() -> bool {
+    true
+}
+
+/// パスを値に持つプレースホルダ変数を作成
+fn path_variable(name: &str, description: &str, value: &Path) -> PlaceholderVariable {
+    PlaceholderVariable {
+        name: name.to_string(),
+        description: description.to_string(),
+        value: value.to_string_lossy().to_string(),
+    }
+}
+
 impl Profile {
     /// 新しいプロファイルを作成する
     pub fn new(id: &str, display_name: &str, _full_profile_path: &Path) -> Self {
@@ -58,6 +126,17 @@ impl Profile {
                 "%PROFILE_DIR%\\DataPath".to_string(), // パス変数を使用
             ],
             mod_loader_type: None,
+            post_launch_commands: Vec::new(),
+            auto_update_game: true,
+            pinned_manifest_id: None,
+            undefined_placeholder: UndefinedPlaceholderPolicy::default(),
+            default_branch: None,
+            pending_template_setup: None,
+            pinned: None,
+            debug_logging: false,
+            webhook: None,
+            resolution: None,
+            screen_mode: None,
         }
     }
     
@@ -114,6 +193,11 @@ impl Profile {
         }
     }
 
+    /// 一覧の上部に固定されているか
+    pub fn is_pinned(&self) -> bool {
+        self.pinned.unwrap_or(self.get_folder_name() == DEFAULT_PINNED_PROFILE)
+    }
+
     /// プロファイルにゲームがインストールされているかチェック
     pub fn has_game_installed(&self) -> bool {
         self.game_info.as_ref().map_or(false, |info| info.installed)
@@ -137,6 +221,28 @@ impl Profile {
             .map(|content| content.trim().to_string())
     }
 
+    /// ゲームのアップデートを自動適用してよいか（固定中は常にfalse）
+    pub fn should_auto_update_game(&self) -> bool {
+        self.auto_update_game && self.pinned_manifest_id.is_none()
+    }
+
+    /// 固定バージョンを考慮して、インストール・更新に使うマニフェストIDを決定
+    pub fn resolve_manifest_id(&self, requested: Option<String>) -> Option<String> {
+        self.pinned_manifest_id.clone().or(requested)
+    }
+
+    /// 固定バージョンが指定されているが、インストール済みのゲームがそれと異なるか
+    pub fn is_pinned_version_mismatch(&self) -> bool {
+        match &self.pinned_manifest_id {
+            Some(pinned) => self
+                .game_info
+                .as_ref()
+                .map(|info| info.manifest_id.as_deref() != Some(pinned.as_str()))
+                .unwrap_or(false),
+            None => false,
+        }
+    }
+
     /// ゲーム情報を更新
     pub fn update_game_info(&mut self, game_info: GameInfo) {
         self.game_info = Some(game_info);
@@ -144,11 +250,85 @@ impl Profile {
 
     /// 起動引数のパス変数を展開
     pub fn expand_args(&self, profile_dir: &Path) -> Vec<String> {
-        self.args.iter().map(|arg| {
-            arg.replace("%PROFILE_DIR%", &profile_dir.to_string_lossy())
-               .replace("%GAME_DIR%", &self.get_game_dir(profile_dir).to_string_lossy())
-               .replace("%DATA_DIR%", &profile_dir.join("DataPath").to_string_lossy())
-        }).collect()
+        self.args.iter().map(|arg| self.expand_path_variables(arg, profile_dir)).collect()
+    }
+
+    /// 起動時に使用する引数を取得（パス変数と `{変数}` プレースホルダを展開）
+    pub fn resolve_launch_args(&self, profile_dir: &Path) -> Result<Vec<String>, Box<dyn Error>> {
+        let variables = self.get_placeholder_variables(profile_dir);
+        let mut args = self.expand_args(profile_dir)
+            .iter()
+            .map(|arg| {
+                launch_args::expand_placeholders(arg, &variables, self.undefined_placeholder)
+                    .map_err(|e| e.into())
+            })
+            .collect::<Result<Vec<String>, Box<dyn Error>>>()?;
+
+        if self.debug_logging {
+            launch_args::apply_debug_logging(&mut args);
+        }
+        launch_args::apply_display_settings(&mut args, self.resolution, self.screen_mode.as_deref())?;
+        Ok(args)
+    }
+
+    /// 起動引数で使用できるプレースホルダ変数の一覧（展開後の値付き）
+    ///
+    /// `{data_path}` は起動引数の `-DataPath` に合わせる（指定が無ければプロファイルのDataPathフォルダ）。
+    pub fn get_placeholder_variables(&self, profile_dir: &Path) -> Vec<PlaceholderVariable> {
+        let data_path = self.get_data_path(profile_dir).unwrap_or_else(|| profile_dir.join("DataPath"));
+        let mut variables = self.get_base_placeholder_variables(profile_dir);
+        variables.insert(2, path_variable("data_path", "データ保存先（DataPath）", &data_path));
+        variables
+    }
+
+    /// `{data_path}` 以外のプレースホルダ変数（`-DataPath` の値の展開にも使う）
+    fn get_base_placeholder_variables(&self, profile_dir: &Path) -> Vec<PlaceholderVariable> {
+        let game_dir = self.get_game_dir(profile_dir);
+
+        vec![
+            path_variable("profile_dir", "プロファイルのフォルダ", profile_dir),
+            path_variable("game_dir", "ゲームのインストール先", &game_dir),
+            path_variable("cache_path", "キャッシュ保存先", &profile_dir.join("Cache")),
+            path_variable("mods_dir", "RML MODフォルダ", &game_dir.join("rml_mods")),
+            path_variable("mod_config_dir", "RML MOD設定フォルダ", &game_dir.join("rml_config")),
+            path_variable("monkey_mods_dir", "MonkeyLoader MODフォルダ", &game_dir.join("MonkeyLoader").join("Mods")),
+            PlaceholderVariable {
+                name: "profile_id".to_string(),
+                description: "プロファイルID".to_string(),
+                value: self.get_folder_name().to_string(),
+            },
+            PlaceholderVariable {
+                name: "profile_name".to_string(),
+                description: "プロファイルの表示名".to_string(),
+                value: self.get_display_name().to_string(),
+            },
+            PlaceholderVariable {
+                name: "branch".to_string(),
+                description: "ゲームのブランチ".to_string(),
+                value: self.game_info.as_ref().map(|info| info.branch.clone()).unwrap_or_default(),
+            },
+        ]
+    }
+
+    /// 起動引数の `-DataPath` で指定されたデータ保存先（展開済み）を取得
+    pub fn get_data_path(&self, profile_dir: &Path) -> Option<PathBuf> {
+        let args = self.expand_args(profile_dir);
+        let value = args.iter()
+            .position(|arg| arg.eq_ignore_ascii_case("-DataPath"))
+            .and_then(|i| args.get(i + 1))?;
+
+        // `-DataPath` の値に使われた `{変数}` も展開する（`{data_path}` 自身は展開しない）
+        let variables = self.get_base_placeholder_variables(profile_dir);
+        let value = launch_args::expand_placeholders(value, &variables, UndefinedPlaceholderPolicy::Keep)
+            .unwrap_or_else(|_| value.clone());
+        Some(PathBuf::from(value.replace('\\', std::path::MAIN_SEPARATOR_STR)))
+    }
+
+    /// 文字列中のパス変数（%PROFILE_DIR%, %GAME_DIR%, %DATA_DIR%）を展開
+    pub fn expand_path_variables(&self, value: &str, profile_dir: &Path) -> String {
+        value.replace("%PROFILE_DIR%", &profile_dir.to_string_lossy())
+             .replace("%GAME_DIR%", &self.get_game_dir(profile_dir).to_string_lossy())
+             .replace("%DATA_DIR%", &profile_dir.join("DataPath").to_string_lossy())
     }
 
     /// プロファイルをJSONファイルとして保存する
@@ -210,9 +390,9 @@ impl Profile {
         let is_version_migration = profile.config_version < PROFILE_CONFIG_VERSION;
         
         if is_version_migration {
-            println!("プロファイル設定をバージョン{}からバージョン{}にマイグレーションします", profile.config_version, PROFILE_CONFIG_VERSION);
+            log_println!("プロファイル設定をバージョン{}からバージョン{}にマイグレーションします", profile.config_version, PROFILE_CONFIG_VERSION);
         } else {
-            println!("プロファイル設定の欠けているフィールドを補完します");
+            log_println!("プロファイル設定の欠けているフィールドを補完します");
         }
         
         // バージョン1からバージョン2へのマイグレーション
@@ -220,9 +400,9 @@ impl Profile {
             // 既存のMODローダーがインストールされているかチェック
             if let Some(mod_loader_type) = Self::detect_existing_mod_loader(profile_dir) {
                 profile.mod_loader_type = Some(mod_loader_type);
-                println!("既存のMODローダーを検出しました: {:?}", mod_loader_type);
+                log_println!("既存のMODローダーを検出しました: {:?}", mod_loader_type);
             } else {
-                println!("MODローダーは検出されませんでした");
+                log_println!("MODローダーは検出されませんでした");
             }
         }
         
@@ -233,9 +413,9 @@ impl Profile {
         profile.config_version = PROFILE_CONFIG_VERSION;
         
         if is_version_migration {
-            println!("マイグレーション完了");
+            log_println!("マイグレーション完了");
         } else {
-            println!("フィールド補完完了");
+            log_println!("フィールド補完完了");
         }
         
         Ok(profile)
@@ -287,7 +467,7 @@ impl Profile {
         }
         
         if !fields_created.is_empty() {
-            println!("作成されたフィールド: {}", fields_created.join(", "));
+            log_println!("作成されたフィールド: {}", fields_created.join(", "));
         }
         
         Ok(())
@@ -377,6 +557,15 @@ impl Profile {
     }
 }
 
+/// ピン留めされたプロファイルを先頭に、それぞれ表示名順に並べる
+pub fn sort_profiles_for_display(profiles: &mut [Profile]) {
+    profiles.sort_by(|a, b| {
+        b.is_pinned()
+            .cmp(&a.is_pinned())
+            .then_with(|| a.get_display_name().to_lowercase().cmp(&b.get_display_name().to_lowercase()))
+    });
+}
+
 /// プロファイル管理に関する機能
 #[derive(Clone)]
 pub struct ProfileManager {
@@ -437,6 +626,44 @@ impl ProfileManager {
         Ok(profile)
     }
 
+    /// テンプレートを適用して新しいプロファイルを作成する
+    ///
+    /// 起動引数はすぐに反映し、MODローダーとMODのインストールは `pending_template_setup` に記録する
+    /// （ゲームのインストール後に適用する）。
+    pub fn create_profile_from_template(&self, name: &str, template: &ProfileTemplate) -> Result<Profile, Box<dyn Error>> {
+        let mut profile = self.create_profile(name)?;
+
+        if !template.description.is_empty() {
+            profile.description = template.description.clone();
+        }
+        if !template.args.is_empty() {
+            profile.args = template.args.clone();
+        }
+        profile.pending_template_setup = template.pending_setup();
+
+        self.update_profile(&profile)?;
+        Ok(profile)
+    }
+
+    /// 新規プロファイルの既定の設定を適用して新しいプロファイルを作成する
+    ///
+    /// ブランチは最初のゲームのインストールで使うよう `default_branch` に記録し、
+    /// MODローダーの自動インストールはテンプレートと同様に `pending_template_setup` に記録する。
+    pub fn create_profile_with_defaults(&self, name: &str, defaults: &ProfileDefaults) -> Result<Profile, Box<dyn Error>> {
+        let mut profile = self.create_profile(name)?;
+
+        if !defaults.args.is_empty() {
+            profile.args = defaults.args.clone();
+        }
+        profile.default_branch = Some(defaults.branch.clone());
+        profile.mod_loader_type = defaults.mod_loader_type;
+        profile.auto_update_game = defaults.auto_update_game;
+        profile.pending_template_setup = defaults.pending_setup();
+
+        self.update_profile(&profile)?;
+        Ok(profile)
+    }
+
     /// 利用可能なプロファイルの一覧を取得する
     pub fn list_profiles(&self) -> Result<Vec<Profile>, Box<dyn Error>> {
         let mut profiles = Vec::new();
@@ -469,6 +696,63 @@ impl ProfileManager {
         Ok(profiles)
     }
 
+    /// プロファイルのピン留めを切り替え、切り替え後の状態を返す
+    pub fn toggle_profile_pin(&self, profile_identifier: &str) -> Result<bool, Box<dyn Error>> {
+        let mut profile = self.get_profile(profile_identifier)?;
+        let pinned = !profile.is_pinned();
+        profile.pinned = Some(pinned);
+        self.update_profile(&profile)?;
+        Ok(pinned)
+    }
+
+    /// 同じブランチ・同じバージョンのGameフォルダの同一ファイルをハードリンクで共有し、ディスクを節約する
+    ///
+    /// 共有したファイルはゲームのインストール・更新前に個別のコピーへ戻される。
+    pub fn deduplicate_game_installs(&self) -> Result<DedupReport, Box<dyn Error>> {
+        let mut report = DedupReport::default();
+        let mut groups: HashMap<(String, Option<String>, String), Vec<PathBuf>> = HashMap::new();
+
+        for profile in self.list_profiles()? {
+            let Some(game_info) = profile.game_info.as_ref().filter(|info| info.installed) else {
+                continue;
+            };
+            let profile_dir = self.get_profile_dir(profile.get_folder_name());
+            let Some(version) = profile.get_game_version(&profile_dir) else {
+                report.skipped.push(format!("{}: game version is unknown", profile.get_display_name()));
+                continue;
+            };
+
+            groups
+                .entry((game_info.branch.clone(), game_info.manifest_id.clone(), version))
+                .or_default()
+                .push(profile_dir);
+        }
+
+        for profile_dirs in groups.values().filter(|dirs| dirs.len() > 1) {
+            let source = profile_dirs[0].join("Game");
+            for profile_dir in &profile_dirs[1..] {
+                let name = profile_dir.file_name().unwrap_or_default().to_string_lossy().to_string();
+                match game_dedup::link_identical_files(&source, &profile_dir.join("Game")) {
+                    Ok((linked_files, saved_bytes)) => {
+                        report.linked_files += linked_files;
+                        report.saved_bytes += saved_bytes;
+                        report.linked_profiles.push(name);
+                    }
+                    // 別ドライブなどハードリンクできない場合はスキップ
+                    Err(e) => report.skipped.push(format!("{}: {}", name, e)),
+                }
+            }
+        }
+
+        log_println!(
+            "Deduplicated {} files across {} profiles ({} bytes saved)",
+            report.linked_files,
+            report.linked_profiles.len(),
+            report.saved_bytes
+        );
+        Ok(report)
+    }
+
     /// 特定のプロファイルを取得する
     /// profile_identifierは表示名でもIDでも可
     pub fn get_profile(&self, profile_identifier: &str) -> Result<Profile, Box<dyn Error>> {
@@ -562,6 +846,47 @@ impl ProfileManager {
         Ok(profile.game_info)
     }
 
+    /// プロファイルにインストール・更新したResoniteのバージョン履歴を取得（新しい順）
+    ///
+    /// 記録の `manifest_id` を指定して再インストールすると、そのバージョンに戻せる。
+    pub fn get_version_history(&self, profile_name: &str) -> Vec<VersionRecord> {
+        VersionHistoryManager::new(&self.get_profile_dir(profile_name)).load_records()
+    }
+
+    /// プロファイルのゲーム本体（Gameフォルダ）だけを削除してディスクを空ける
+    ///
+    /// プロファイルの設定・MOD情報（`installed_mods.json`）・DataPathは残すため、
+    /// 再インストールすると元の設定のまま使える。起動中のプロファイルや、
+    /// DataPathがGameフォルダ内にある場合は削除しない。
+    pub fn remove_game_files(&self, name: &str) -> Result<(), Box<dyn Error>> {
+        let mut profile = self.get_profile(name)?;
+        let profile_dir = self.get_profile_dir(name);
+        let game_dir = profile.get_game_dir(&profile_dir);
+
+        launch_lock::ensure_not_running(&profile_dir, name)?;
+
+        if let Some(data_path) = profile.get_data_path(&profile_dir) {
+            if data_path.starts_with(&game_dir) {
+                return Err(format!(
+                    "DataPath {} is inside the Game folder; move it before removing the game files",
+                    data_path.display()
+                ).into());
+            }
+        }
+
+        if game_dir.exists() {
+            fs::remove_dir_all(&game_dir)?;
+        }
+
+        // ブランチ等は再インストール時に使えるよう残し、未インストール扱いにする
+        if let Some(game_info) = profile.game_info.as_mut() {
+            game_info.installed = false;
+            game_info.version = None;
+        }
+        self.update_profile(&profile)?;
+        Ok(())
+    }
+
     /// プロファイルを削除する（プロファイルリストから削除のみ、ディレクトリは呼び出し側で削除）
     pub fn delete_profile(&mut self, profile_identifier: &str) -> Result<(), Box<dyn Error>> {
         // Prevent deletion of default profile
@@ -745,6 +1070,146 @@ mod tests {
         assert_eq!(loaded.display_name, original.display_name);
     }
 
+    #[test]
+    fn test_profile_post_launch_commands_default() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile_dir = temp_dir.path().join("test_profile");
+        fs::create_dir_all(&profile_dir).unwrap();
+
+        // post_launch_commandsを含まない既存の設定ファイル
+        let json = r#"{
+            "config_version": 2,
+            "id": "test_id",
+            "display_name": "Test",
+            "description": "",
+            "game_info": null,
+            "args": ["-Screen"]
+        }"#;
+        let profile: Profile = serde_json::from_str(json).unwrap();
+        assert!(profile.post_launch_commands.is_empty());
+        assert!(profile.auto_update_game);
+        assert!(profile.pinned_manifest_id.is_none());
+
+        // 空の場合は保存されない
+        let serialized = serde_json::to_string(&profile).unwrap();
+        assert!(!serialized.contains("post_launch_commands"));
+    }
+
+    #[test]
+    fn test_profile_resolve_launch_args_placeholders() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile_dir = temp_dir.path().join("test_profile");
+        let mut profile = Profile::new("id", "name", &profile_dir);
+
+        profile.args = vec!["-CachePath".to_string(), "{cache_path}".to_string()];
+        let args = profile.resolve_launch_args(&profile_dir).unwrap();
+        assert_eq!(args[1], profile_dir.join("Cache").to_string_lossy());
+
+        // 既定では未定義の変数はそのまま渡す
+        profile.args = vec!["{undefined}".to_string()];
+        assert_eq!(profile.resolve_launch_args(&profile_dir).unwrap(), vec!["{undefined}"]);
+
+        profile.undefined_placeholder = UndefinedPlaceholderPolicy::Error;
+        assert!(profile.resolve_launch_args(&profile_dir).is_err());
+
+        profile.undefined_placeholder = UndefinedPlaceholderPolicy::Empty;
+        assert_eq!(profile.resolve_launch_args(&profile_dir).unwrap(), vec![String::new()]);
+    }
+
+    #[test]
+    fn test_profile_resolve_launch_args_debug_logging() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile_dir = temp_dir.path().join("test_profile");
+        let mut profile = Profile::new("id", "name", &profile_dir);
+        profile.args = vec!["-Screen".to_string()];
+
+        assert_eq!(profile.resolve_launch_args(&profile_dir).unwrap(), vec!["-Screen"]);
+
+        // 有効時だけ詳細ログ用の引数が付き、プロファイルの引数自体は変わらない
+        profile.debug_logging = true;
+        assert_eq!(profile.resolve_launch_args(&profile_dir).unwrap(), vec!["-Screen", "-Verbose"]);
+        assert_eq!(profile.args, vec!["-Screen"]);
+    }
+
+    #[test]
+    fn test_profile_resolve_launch_args_display_settings() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile_dir = temp_dir.path().join("test_profile");
+        let mut profile = Profile::new("id", "name", &profile_dir);
+        profile.args = vec!["-Screen".to_string()];
+
+        profile.resolution = Some((2560, 1440));
+        profile.screen_mode = Some("fullscreen".to_string());
+        assert_eq!(
+            profile.resolve_launch_args(&profile_dir).unwrap(),
+            vec!["-Screen", "-screen-width", "2560", "-screen-height", "1440", "-screen-fullscreen", "1"]
+        );
+        assert_eq!(profile.args, vec!["-Screen"]);
+    }
+
+    #[test]
+    fn test_profile_get_data_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile_dir = temp_dir.path().join("test_profile");
+        let mut profile = Profile::new("id", "name", &profile_dir);
+
+        assert_eq!(profile.get_data_path(&profile_dir), Some(profile_dir.join("DataPath")));
+
+        profile.args = vec!["-Screen".to_string()];
+        assert_eq!(profile.get_data_path(&profile_dir), None);
+
+        // {data_path} は -DataPath で指定した保存先になる
+        profile.args = vec!["-DataPath".to_string(), "{profile_dir}/Data".to_string(), "-Logs".to_string(), "{data_path}/Logs".to_string()];
+        let data_path = profile_dir.join("Data");
+        assert_eq!(profile.get_data_path(&profile_dir), Some(data_path.clone()));
+        let args = profile.resolve_launch_args(&profile_dir).unwrap();
+        assert_eq!(args[3], format!("{}/Logs", data_path.to_string_lossy()));
+    }
+
+    #[test]
+    fn test_profile_update_policy() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile_dir = temp_dir.path().join("test_profile");
+        let mut profile = Profile::new("id", "name", &profile_dir);
+
+        // 既定では自動更新し、固定はしない
+        assert!(profile.should_auto_update_game());
+        assert_eq!(profile.resolve_manifest_id(Some("111".to_string())), Some("111".to_string()));
+        let serialized = serde_json::to_string(&profile).unwrap();
+        assert!(!serialized.contains("pinned_manifest_id"));
+
+        profile.update_game_info(GameInfo {
+            branch: "release".to_string(),
+            manifest_id: Some("111".to_string()),
+            depot_id: "2519832".to_string(),
+            installed: true,
+            last_updated: None,
+            version: None,
+        });
+        profile.pinned_manifest_id = Some("222".to_string());
+
+        // 固定中は最新があっても自動更新せず、固定バージョンを使う
+        assert!(!profile.should_auto_update_game());
+        assert_eq!(profile.resolve_manifest_id(None), Some("222".to_string()));
+        assert!(profile.is_pinned_version_mismatch());
+
+        profile.pinned_manifest_id = Some("111".to_string());
+        assert!(!profile.is_pinned_version_mismatch());
+    }
+
+    #[test]
+    fn test_profile_expand_path_variables() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile_dir = temp_dir.path().join("my_profile");
+        let profile = Profile::new("id", "name", &profile_dir);
+
+        let expanded = profile.expand_path_variables("%GAME_DIR%/tools/bridge.exe", &profile_dir);
+        assert_eq!(
+            expanded,
+            format!("{}/tools/bridge.exe", profile_dir.join("Game").to_string_lossy())
+        );
+    }
+
     // === GameInfo構造体のテスト ===
 
     #[test]
@@ -795,6 +1260,75 @@ mod tests {
         assert!(profile_dir.join("Game").exists());
     }
 
+    #[test]
+    fn test_profile_manager_create_profile_from_template() {
+        let (_temp, manager) = create_test_env();
+
+        let template = ProfileTemplate {
+            name: "RML".to_string(),
+            description: "RML環境".to_string(),
+            args: vec!["-Screen".to_string()],
+            mod_loader_type: Some(ModLoaderType::ResoniteModLoader),
+            mods: vec!["https://github.com/test/ModA".to_string()],
+        };
+        let profile = manager.create_profile_from_template("テンプレート", &template).unwrap();
+
+        let loaded = manager.get_profile(&profile.id).unwrap();
+        assert_eq!(loaded.description, "RML環境");
+        assert_eq!(loaded.args, vec!["-Screen"]);
+        // MODローダーはゲームのインストール後に入れるため、まだ設定されない
+        assert!(loaded.mod_loader_type.is_none());
+        let setup = loaded.pending_template_setup.unwrap();
+        assert_eq!(setup.mod_loader_type, Some(ModLoaderType::ResoniteModLoader));
+        assert_eq!(setup.mods, template.mods);
+    }
+
+    #[test]
+    fn test_profile_manager_create_profile_with_defaults() {
+        let (_temp, manager) = create_test_env();
+
+        let defaults = ProfileDefaults {
+            branch: "prerelease".to_string(),
+            mod_loader_type: Some(ModLoaderType::MonkeyLoader),
+            args: vec!["-SkipIntroTutorial".to_string()],
+            auto_update_game: false,
+            ..ProfileDefaults::default()
+        };
+        let profile = manager.create_profile_with_defaults("既定", &defaults).unwrap();
+
+        let loaded = manager.get_profile(&profile.id).unwrap();
+        assert_eq!(loaded.default_branch.as_deref(), Some("prerelease"));
+        assert_eq!(loaded.mod_loader_type, Some(ModLoaderType::MonkeyLoader));
+        assert_eq!(loaded.args, vec!["-SkipIntroTutorial"]);
+        assert!(!loaded.auto_update_game);
+        // 自動インストールしない場合はゲームのインストール後のセットアップを記録しない
+        assert!(loaded.pending_template_setup.is_none());
+    }
+
+    #[test]
+    fn test_profile_pinning_and_display_order() {
+        let (_temp, manager) = create_test_env();
+
+        manager.create_profile("default").unwrap();
+        let beta = manager.create_profile("Beta").unwrap();
+        manager.create_profile("alpha").unwrap();
+
+        // defaultは初期状態でピン留めされている
+        assert!(manager.get_profile("default").unwrap().is_pinned());
+        assert!(!beta.is_pinned());
+
+        assert!(manager.toggle_profile_pin(&beta.id).unwrap());
+        assert!(manager.get_profile(&beta.id).unwrap().is_pinned());
+
+        let mut profiles = manager.list_profiles().unwrap();
+        sort_profiles_for_display(&mut profiles);
+        let names: Vec<&str> = profiles.iter().map(|p| p.get_display_name()).collect();
+        assert_eq!(names, vec!["Beta", "default", "alpha"]);
+
+        assert!(!manager.toggle_profile_pin("default").unwrap());
+        assert!(!manager.get_profile("default").unwrap().is_pinned());
+    }
+
     #[test]
     fn test_profile_manager_list_profiles_empty() {
         let (_temp, manager) = create_test_env();
@@ -868,4 +1402,35 @@ mod tests {
         let installed = manager.check_game_installed(&profile.id).unwrap();
         assert!(!installed);
     }
+
+    #[test]
+    fn test_remove_game_files_keeps_profile_data() {
+        let (_temp, manager) = create_test_env();
+        let mut profile = manager.create_profile("Archive").unwrap();
+        let profile_dir = manager.get_profile_dir(profile.get_folder_name());
+
+        let game_dir = profile_dir.join("Game");
+        fs::create_dir_all(game_dir.join("rml_mods")).unwrap();
+        fs::write(game_dir.join("Resonite.exe"), b"").unwrap();
+        fs::create_dir_all(profile_dir.join("DataPath")).unwrap();
+        fs::write(profile_dir.join("installed_mods.json"), b"{}").unwrap();
+        profile.game_info = Some(GameInfo {
+            branch: "release".to_string(),
+            manifest_id: None,
+            depot_id: "2519832".to_string(),
+            installed: true,
+            last_updated: None,
+            version: None,
+        });
+        manager.update_profile(&profile).unwrap();
+
+        manager.remove_game_files(profile.get_folder_name()).unwrap();
+
+        assert!(!game_dir.exists());
+        assert!(profile_dir.join("DataPath").exists());
+        assert!(profile_dir.join("installed_mods.json").exists());
+        let reloaded = manager.get_profile(profile.get_folder_name()).unwrap();
+        assert_eq!(reloaded.game_info.as_ref().unwrap().branch, "release");
+        assert!(!reloaded.has_game_installed());
+    }
 }
@@ -0,0 +1,264 @@
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::mod_loader_type::ModLoaderType;
+
+/// プロファイル作成時に適用するテンプレート
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProfileTemplate {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// 既定の起動引数（空の場合は通常のプロファイルと同じ既定値を使う）
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// インストールするMODローダー
+    #[serde(default)]
+    pub mod_loader_type: Option<ModLoaderType>,
+    /// インストールするMODのsource_location一覧
+    #[serde(default)]
+    pub mods: Vec<String>,
+}
+
+impl ProfileTemplate {
+    /// ゲームのインストール後に行うセットアップ（MODローダー・MODが無ければNone）
+    pub fn pending_setup(&self) -> Option<PendingTemplateSetup> {
+        if self.mod_loader_type.is_none() && self.mods.is_empty() {
+            return None;
+        }
+        Some(PendingTemplateSetup {
+            template_name: self.name.clone(),
+            mod_loader_type: self.mod_loader_type,
+            mods: self.mods.clone(),
+        })
+    }
+}
+
+/// テンプレートから作成したプロファイルで、まだ行っていないMODローダー・MODのインストール
+///
+/// MODローダーはゲーム本体が無いとインストールできないため、ゲームのインストール後に適用する。
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PendingTemplateSetup {
+    pub template_name: String,
+    pub mod_loader_type: Option<ModLoaderType>,
+    pub mods: Vec<String>,
+}
+
+/// 新規プロファイルに適用する既定の設定
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProfileDefaults {
+    /// 最初のゲームのインストールで使うブランチ
+    #[serde(default = "default_branch")]
+    pub branch: String,
+    /// 既定のMODローダー
+    #[serde(default)]
+    pub mod_loader_type: Option<ModLoaderType>,
+    /// ゲームのインストール後に `mod_loader_type` のMODローダーを自動でインストールするか
+    #[serde(default)]
+    pub auto_install_mod_loader: bool,
+    /// 既定の起動引数（空の場合は組み込みの既定値を使う）
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default = "default_auto_update_game")]
+    pub auto_update_game: bool,
+}
+
+fn default_branch() -> String {
+    "release".to_string()
+}
+
+fn default_auto_update_game() -> bool {
+    true
+}
+
+impl Default for ProfileDefaults {
+    fn default() -> Self {
+        ProfileDefaults {
+            branch: default_branch(),
+            mod_loader_type: None,
+            auto_install_mod_loader: false,
+            args: Vec::new(),
+            auto_update_game: default_auto_update_game(),
+        }
+    }
+}
+
+impl ProfileDefaults {
+    /// MODローダーを自動でインストールする場合、ゲームのインストール後に行うセットアップ
+    pub fn pending_setup(&self) -> Option<PendingTemplateSetup> {
+        if !self.auto_install_mod_loader {
+            return None;
+        }
+        self.mod_loader_type.map(|mod_loader_type| PendingTemplateSetup {
+            template_name: "新規プロファイルのデフォルト".to_string(),
+            mod_loader_type: Some(mod_loader_type),
+            mods: Vec::new(),
+        })
+    }
+}
+
+/// アプリデータの `templates.json` でプロファイルテンプレートを、
+/// `profile_defaults.json` で新規プロファイルの既定の設定を管理する
+pub struct ProfileTemplateManager {
+    templates_file: PathBuf,
+    defaults_file: PathBuf,
+}
+
+impl ProfileTemplateManager {
+    pub fn new(data_dir: &Path) -> Self {
+        ProfileTemplateManager {
+            templates_file: data_dir.join("templates.json"),
+            defaults_file: data_dir.join("profile_defaults.json"),
+        }
+    }
+
+    /// 新規プロファイルの既定の設定を読み込む（未設定の場合は組み込みの既定値）
+    pub fn get_defaults(&self) -> ProfileDefaults {
+        fs::read_to_string(&self.defaults_file)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// 新規プロファイルの既定の設定を保存する
+    pub fn save_defaults(&self, defaults: &ProfileDefaults) -> Result<(), Box<dyn Error>> {
+        if defaults.branch.trim().is_empty() {
+            return Err("Default branch cannot be empty".into());
+        }
+        if defaults.auto_install_mod_loader && defaults.mod_loader_type.is_none() {
+            return Err("Select a mod loader to install automatically".into());
+        }
+
+        if let Some(parent) = self.defaults_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.defaults_file, serde_json::to_string_pretty(defaults)?)?;
+        Ok(())
+    }
+
+    /// テンプレート一覧を読み込む
+    pub fn list(&self) -> Vec<ProfileTemplate> {
+        fs::read_to_string(&self.templates_file)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// 名前を指定してテンプレートを取得する
+    pub fn get(&self, name: &str) -> Option<ProfileTemplate> {
+        self.list().into_iter().find(|template| template.name == name)
+    }
+
+    /// テンプレートを保存する（同名のテンプレートは置き換える）
+    pub fn save_template(&self, template: &ProfileTemplate) -> Result<(), Box<dyn Error>> {
+        if template.name.trim().is_empty() {
+            return Err("Template name cannot be empty".into());
+        }
+
+        let mut templates = self.list();
+        match templates.iter_mut().find(|t| t.name == template.name) {
+            Some(existing) => *existing = template.clone(),
+            None => templates.push(template.clone()),
+        }
+        self.save(&templates)
+    }
+
+    /// テンプレートを削除する
+    pub fn delete_template(&self, name: &str) -> Result<(), Box<dyn Error>> {
+        let mut templates = self.list();
+        let count = templates.len();
+        templates.retain(|t| t.name != name);
+        if templates.len() == count {
+            return Err(format!("Template '{}' not found", name).into());
+        }
+        self.save(&templates)
+    }
+
+    fn save(&self, templates: &[ProfileTemplate]) -> Result<(), Box<dyn Error>> {
+        if let Some(parent) = self.templates_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.templates_file, serde_json::to_string_pretty(templates)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn test_template(name: &str) -> ProfileTemplate {
+        ProfileTemplate {
+            name: name.to_string(),
+            description: String::new(),
+            args: vec!["-Screen".to_string()],
+            mod_loader_type: Some(ModLoaderType::ResoniteModLoader),
+            mods: vec!["https://github.com/test/ModA".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_save_replace_and_delete_templates() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = ProfileTemplateManager::new(temp_dir.path());
+        assert!(manager.list().is_empty());
+
+        manager.save_template(&test_template("RML")).unwrap();
+        manager.save_template(&test_template("Screen")).unwrap();
+
+        let mut updated = test_template("RML");
+        updated.mods.clear();
+        manager.save_template(&updated).unwrap();
+
+        let templates = manager.list();
+        assert_eq!(templates.len(), 2);
+        assert!(manager.get("RML").unwrap().mods.is_empty());
+
+        assert!(manager.save_template(&test_template(" ")).is_err());
+        manager.delete_template("Screen").unwrap();
+        assert!(manager.delete_template("Screen").is_err());
+        assert_eq!(manager.list().len(), 1);
+    }
+
+    #[test]
+    fn test_pending_setup() {
+        let template = test_template("RML");
+        let setup = template.pending_setup().unwrap();
+        assert_eq!(setup.template_name, "RML");
+        assert_eq!(setup.mods.len(), 1);
+
+        let mut args_only = test_template("Args");
+        args_only.mod_loader_type = None;
+        args_only.mods.clear();
+        assert!(args_only.pending_setup().is_none());
+    }
+
+    #[test]
+    fn test_profile_defaults() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = ProfileTemplateManager::new(temp_dir.path());
+        let defaults = manager.get_defaults();
+        assert_eq!(defaults, ProfileDefaults::default());
+        assert_eq!(defaults.branch, "release");
+        assert!(defaults.pending_setup().is_none());
+
+        let mut defaults = ProfileDefaults {
+            mod_loader_type: Some(ModLoaderType::MonkeyLoader),
+            ..ProfileDefaults::default()
+        };
+        // 自動インストールしない場合はゲームのインストール後のセットアップは不要
+        assert!(defaults.pending_setup().is_none());
+
+        defaults.auto_install_mod_loader = true;
+        manager.save_defaults(&defaults).unwrap();
+        let setup = manager.get_defaults().pending_setup().unwrap();
+        assert_eq!(setup.mod_loader_type, Some(ModLoaderType::MonkeyLoader));
+        assert!(setup.mods.is_empty());
+
+        defaults.mod_loader_type = None;
+        assert!(manager.save_defaults(&defaults).is_err());
+    }
+}
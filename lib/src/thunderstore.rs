@@ -5,6 +5,8 @@ use std::fs;
 use std::path::PathBuf;
 use std::time::Duration;
 
+use crate::game_dedup;
+
 /// Thunderstoreパッケージ情報
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThunderstorePackage {
@@ -379,7 +381,7 @@ impl ThunderstoreClient {
                         }
                         let mut contents = Vec::new();
                         file.read_to_end(&mut contents)?;
-                        fs::write(&dest_path, &contents)?;
+                        game_dedup::write_unshared(&dest_path, &contents)?;
                         println!("Extracted: {} -> {}", file_name, dest_path.display());
                         extracted_files.push(dest_path);
                     }
@@ -396,7 +398,7 @@ impl ThunderstoreClient {
                         }
                         let mut contents = Vec::new();
                         file.read_to_end(&mut contents)?;
-                        fs::write(&dest_path, &contents)?;
+                        game_dedup::write_unshared(&dest_path, &contents)?;
                         println!("Extracted: {} -> {}", file_name, dest_path.display());
                         extracted_files.push(dest_path);
                     }
@@ -413,7 +415,7 @@ impl ThunderstoreClient {
                         }
                         let mut contents = Vec::new();
                         file.read_to_end(&mut contents)?;
-                        fs::write(&dest_path, &contents)?;
+                        game_dedup::write_unshared(&dest_path, &contents)?;
                         println!("Extracted: {} -> {}", file_name, dest_path.display());
                         extracted_files.push(dest_path);
                     }
@@ -430,7 +432,7 @@ impl ThunderstoreClient {
                         }
                         let mut contents = Vec::new();
                         file.read_to_end(&mut contents)?;
-                        fs::write(&dest_path, &contents)?;
+                        game_dedup::write_unshared(&dest_path, &contents)?;
                         println!("Extracted: {} -> {}", file_name, dest_path.display());
                         extracted_files.push(dest_path);
                     }
@@ -445,7 +447,7 @@ impl ThunderstoreClient {
                     }
                     let mut contents = Vec::new();
                     file.read_to_end(&mut contents)?;
-                    fs::write(&dest_path, &contents)?;
+                    game_dedup::write_unshared(&dest_path, &contents)?;
                     println!("Extracted: {} -> {}", file_name, dest_path.display());
                     extracted_files.push(dest_path);
                 }
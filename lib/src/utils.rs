@@ -1,6 +1,11 @@
 use std::path::{Path, PathBuf};
 use std::error::Error;
 use std::env;
+use std::fmt;
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
 
 /// 実行可能ファイルのディレクトリを取得する
 pub fn get_executable_directory() -> Result<PathBuf, Box<dyn Error>> {
@@ -12,11 +17,36 @@ pub fn get_executable_directory() -> Result<PathBuf, Box<dyn Error>> {
     Ok(exe_dir.to_path_buf())
 }
 
+/// 進捗ログを標準エラー出力に書き出すかどうか
+static LOG_TO_STDERR: AtomicBool = AtomicBool::new(false);
+
+/// 進捗ログの出力先を標準エラー出力に切り替える
+///
+/// CLIの `--json` のように、標準出力をJSONだけにしたい場合に使う。
+pub fn set_log_to_stderr(enabled: bool) {
+    LOG_TO_STDERR.store(enabled, Ordering::Relaxed);
+}
+
+/// 進捗ログを標準エラー出力に書き出す設定かどうか
+pub fn log_to_stderr() -> bool {
+    LOG_TO_STDERR.load(Ordering::Relaxed)
+}
+
+/// ブランチ名の最大長
+const MAX_BRANCH_NAME_LENGTH: usize = 64;
+
 /// ブランチ名が有効かチェックする
+///
+/// release/prerelease以外にも、Steamのbetaブランチ名（英数字・`-`・`_`・`.`）を受け付ける。
+/// DepotDownloaderの引数として渡すため、`-` で始まる名前は受け付けない。
 pub fn validate_branch(branch: &str) -> Result<(), Box<dyn Error>> {
-    if branch != "release" && branch != "prerelease" {
+    let valid = !branch.is_empty()
+        && branch.len() <= MAX_BRANCH_NAME_LENGTH
+        && !branch.starts_with('-')
+        && branch.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid {
         return Err(format!(
-            "Invalid branch '{}'. Must be 'release' or 'prerelease'",
+            "Invalid branch '{}'. Must be 'release', 'prerelease' or a Steam beta branch name",
             branch
         )
         .into());
@@ -32,11 +62,517 @@ pub fn ensure_directory_exists(path: &Path) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// ネットワーク接続の確認に使用するホスト（Steamのコンテンツ配信に必要なもの）
+const NETWORK_CHECK_HOSTS: &[&str] = &["api.steampowered.com:443", "steamcdn-a.akamaihd.net:443"];
+
+/// ネットワーク接続の確認結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkStatus {
+    /// いずれかのホストに接続できた
+    Online,
+    /// 名前解決できない・経路が無いなど、ネットワーク自体に繋がっていない
+    Offline,
+    /// 接続できなかったが、切断とは断定できない（ファイアウォールやプロキシでホストが遮断されている場合など）
+    Unknown,
+}
+
+/// 1つのホストへの接続確認の結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProbeOutcome {
+    Connected,
+    /// 名前解決に失敗した
+    Unresolved,
+    /// ネットワーク・ホストへの経路が無い
+    Unreachable,
+    /// タイムアウトや接続拒否など
+    Failed,
+}
+
+/// インターネットに接続できるか確認する
+///
+/// 確認用のホストだけが遮断されている環境もあるため、すべてのホストで名前解決や経路が無い場合のみ `Offline` とする。
+pub fn check_network() -> NetworkStatus {
+    let outcomes: Vec<ProbeOutcome> = NETWORK_CHECK_HOSTS.iter().map(|host| probe_host(host)).collect();
+    classify_probe_outcomes(&outcomes)
+}
+
+/// ネットワークに繋がっていないと判定できない限りtrueを返す（`Unknown` は接続中とみなす）
+pub fn is_network_available() -> bool {
+    check_network() != NetworkStatus::Offline
+}
+
+fn probe_host(host: &str) -> ProbeOutcome {
+    use std::net::{TcpStream, ToSocketAddrs};
+
+    let addrs: Vec<_> = match host.to_socket_addrs() {
+        Ok(addrs) => addrs.collect(),
+        Err(_) => return ProbeOutcome::Unresolved,
+    };
+    if addrs.is_empty() {
+        return ProbeOutcome::Unresolved;
+    }
+
+    let mut outcome = ProbeOutcome::Unreachable;
+    for addr in addrs {
+        match TcpStream::connect_timeout(&addr, std::time::Duration::from_secs(5)) {
+            Ok(_) => return ProbeOutcome::Connected,
+            Err(e) if matches!(e.kind(), io::ErrorKind::NetworkUnreachable | io::ErrorKind::HostUnreachable) => {}
+            Err(_) => outcome = ProbeOutcome::Failed,
+        }
+    }
+    outcome
+}
+
+fn classify_probe_outcomes(outcomes: &[ProbeOutcome]) -> NetworkStatus {
+    if outcomes.contains(&ProbeOutcome::Connected) {
+        NetworkStatus::Online
+    } else if !outcomes.is_empty() && outcomes.iter().all(|o| matches!(o, ProbeOutcome::Unresolved | ProbeOutcome::Unreachable)) {
+        NetworkStatus::Offline
+    } else {
+        NetworkStatus::Unknown
+    }
+}
+
+/// ネットワークが復帰するまで待機する（タイムアウトした場合はfalse）
+pub fn wait_for_network(timeout: std::time::Duration) -> bool {
+    let started = std::time::Instant::now();
+    while started.elapsed() < timeout {
+        if is_network_available() {
+            return true;
+        }
+        std::thread::sleep(std::time::Duration::from_secs(5));
+    }
+    is_network_available()
+}
+
+/// ディレクトリに書き込めない理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WriteAccessFailure {
+    /// 権限不足
+    PermissionDenied,
+    /// 読み取り専用のドライブ・ファイルシステム
+    ReadOnly,
+    /// 空き容量不足
+    InsufficientSpace,
+    /// ドライブが接続されていない・ネットワークドライブがオフライン
+    Offline,
+    Other,
+}
+
+/// 書き込みテストの失敗内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WriteAccessError {
+    pub reason: WriteAccessFailure,
+    pub path: PathBuf,
+    pub detail: String,
+}
+
+impl fmt::Display for WriteAccessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self.reason {
+            WriteAccessFailure::PermissionDenied => "書き込み権限がありません",
+            WriteAccessFailure::ReadOnly => "読み取り専用のドライブです",
+            WriteAccessFailure::InsufficientSpace => "ドライブの空き容量が不足しています",
+            WriteAccessFailure::Offline => "ドライブに接続できません（外付け・ネットワークドライブがオフラインの可能性があります）",
+            WriteAccessFailure::Other => "書き込みに失敗しました",
+        };
+        write!(f, "{}: {} ({})", reason, self.path.display(), self.detail)
+    }
+}
+
+impl Error for WriteAccessError {}
+
+/// ディレクトリにテスト書き込みを行い、書き込めるか確認する（無ければ作成する）
+pub fn check_directory_writable(dir: &Path) -> Result<(), WriteAccessError> {
+    let to_error = |e: io::Error| WriteAccessError {
+        reason: classify_write_error(&e),
+        path: dir.to_path_buf(),
+        detail: e.to_string(),
+    };
+
+    std::fs::create_dir_all(dir).map_err(to_error)?;
+
+    let test_file = dir.join(".reso_launcher_write_test");
+    let result = std::fs::File::create(&test_file).and_then(|mut file| {
+        use std::io::Write;
+        file.write_all(b"write test")?;
+        file.sync_all()
+    });
+    let _ = std::fs::remove_file(&test_file);
+
+    result.map_err(to_error)
+}
+
+/// IOエラーを書き込み不可の理由に分類する
+pub fn classify_write_error(error: &io::Error) -> WriteAccessFailure {
+    if let Some(code) = error.raw_os_error() {
+        #[cfg(target_os = "windows")]
+        match code {
+            19 => return WriteAccessFailure::ReadOnly,                                // ERROR_WRITE_PROTECT
+            39 | 112 => return WriteAccessFailure::InsufficientSpace,                 // ERROR_HANDLE_DISK_FULL / ERROR_DISK_FULL
+            21 | 53 | 64 | 67 | 1231 => return WriteAccessFailure::Offline,          // ERROR_NOT_READY / ERROR_BAD_NETPATH など
+            _ => {}
+        }
+        #[cfg(not(target_os = "windows"))]
+        match code {
+            30 => return WriteAccessFailure::ReadOnly,                                // EROFS
+            28 | 122 => return WriteAccessFailure::InsufficientSpace,                 // ENOSPC / EDQUOT
+            5 | 107 | 112 | 113 | 116 => return WriteAccessFailure::Offline,         // EIO / ENOTCONN / EHOSTDOWN / EHOSTUNREACH / ESTALE
+            _ => {}
+        }
+    }
+
+    match error.kind() {
+        io::ErrorKind::PermissionDenied => WriteAccessFailure::PermissionDenied,
+        io::ErrorKind::NotFound | io::ErrorKind::NotConnected | io::ErrorKind::TimedOut => WriteAccessFailure::Offline,
+        _ => WriteAccessFailure::Other,
+    }
+}
+
+/// パスのあるドライブの空き容量（バイト）を取得する
+///
+/// パスがまだ存在しない場合は、存在する最も近い親フォルダのドライブを調べる。
+pub fn available_disk_space(path: &Path) -> Result<u64, Box<dyn Error>> {
+    let existing = path
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .ok_or_else(|| format!("No existing directory for {}", path.display()))?;
+
+    query_available_space(existing)
+}
+
+#[cfg(target_os = "windows")]
+fn query_available_space(path: &Path) -> Result<u64, Box<dyn Error>> {
+    use std::os::windows::process::CommandExt;
+
+    // PowerShellのシングルクォート内ではシングルクォートを2つ重ねてエスケープする
+    let path = path.to_string_lossy().replace('\'', "''");
+    let output = std::process::Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            &format!("[System.IO.DriveInfo]::new((Resolve-Path -LiteralPath '{}').Path).AvailableFreeSpace", path),
+        ])
+        .creation_flags(0x08000000) // CREATE_NO_WINDOW
+        .output()?;
+
+    if !output.status.success() {
+        return Err(format!("Failed to query free space: {}", String::from_utf8_lossy(&output.stderr)).into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().parse()?)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn query_available_space(path: &Path) -> Result<u64, Box<dyn Error>> {
+    let output = std::process::Command::new("df").arg("-Pk").arg(path).output()?;
+    if !output.status.success() {
+        return Err(format!("Failed to query free space: {}", String::from_utf8_lossy(&output.stderr)).into());
+    }
+
+    parse_df_available(&String::from_utf8_lossy(&output.stdout))
+        .ok_or_else(|| "Unexpected output from df".into())
+}
+
+/// `df -Pk` の出力から空き容量（バイト）を取り出す
+///
+/// 2行目の4列目が空き容量（1024バイト単位）。
+#[cfg_attr(target_os = "windows", allow(dead_code))]
+fn parse_df_available(output: &str) -> Option<u64> {
+    let line = output.lines().nth(1)?;
+    let available_kb: u64 = line.split_whitespace().nth(3)?.parse().ok()?;
+    Some(available_kb * 1024)
+}
+
+/// Visual C++ 再頒布可能パッケージ（x64）のダウンロードURL
+pub const VC_REDIST_DOWNLOAD_URL: &str = "https://aka.ms/vs/17/release/vc_redist.x64.exe";
+
+/// Resoniteの起動に必要なVisual C++ ランタイムのDLL
+const VC_RUNTIME_FILES: &[&str] = &["vcruntime140.dll", "vcruntime140_1.dll", "msvcp140.dll"];
+
+/// 不足しているシステムの依存
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissingDependency {
+    pub name: String,
+    /// 見つからなかったファイル
+    pub missing_files: Vec<String>,
+    /// インストーラーのダウンロードURL
+    pub download_url: String,
+}
+
+/// Resoniteの起動に必要なシステムの依存（VC++ ランタイムなど）が揃っているか検査する
+///
+/// Windows以外ではProton/Wineが依存を用意するため、常に空を返す。
+pub fn check_system_dependencies() -> Result<Vec<MissingDependency>, Box<dyn Error>> {
+    if !cfg!(target_os = "windows") {
+        return Ok(Vec::new());
+    }
+
+    let system_root = env::var_os("SystemRoot").map(PathBuf::from).unwrap_or_else(|| PathBuf::from("C:\\Windows"));
+    let system_dir = system_root.join("System32");
+    if !system_dir.is_dir() {
+        return Err(format!("System directory not found: {}", system_dir.display()).into());
+    }
+
+    let mut missing = Vec::new();
+    let missing_files = find_missing_files(&system_dir, VC_RUNTIME_FILES);
+    if !missing_files.is_empty() {
+        missing.push(MissingDependency {
+            name: "Visual C++ 再頒布可能パッケージ (x64)".to_string(),
+            missing_files,
+            download_url: VC_REDIST_DOWNLOAD_URL.to_string(),
+        });
+    }
+    Ok(missing)
+}
+
+/// ディレクトリに存在しないファイルを返す
+fn find_missing_files(dir: &Path, files: &[&str]) -> Vec<String> {
+    files.iter()
+        .filter(|file| !dir.join(file).is_file())
+        .map(|file| file.to_string())
+        .collect()
+}
+
+/// MODのインストール元URLを正規化する
+///
+/// 末尾の `/` や `.git`、クエリ・フラグメント、`www.` を取り除き、`http://` やスキーム無しは `https://` に揃える。
+/// `file://` や `thunderstore:` など http(s) 以外のインストール元は前後の空白を除くだけでそのまま返す。
+pub fn normalize_source_location(url: &str) -> String {
+    let url = url.trim();
+    let lower = url.to_lowercase();
+
+    let rest = if lower.starts_with("https://") {
+        &url["https://".len()..]
+    } else if lower.starts_with("http://") {
+        &url["http://".len()..]
+    } else if lower.contains(':') || !lower.contains('.') {
+        return url.to_string();
+    } else {
+        url
+    };
+
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let host = host.to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+
+    let mut path = path.trim_end_matches('/');
+    while let Some(stripped) = path.strip_suffix(".git") {
+        path = stripped.trim_end_matches('/');
+    }
+
+    if path.is_empty() {
+        format!("https://{}", host)
+    } else {
+        format!("https://{}/{}", host, path)
+    }
+}
+
+/// 2つのインストール元URLが同じMODを指すか（正規化した上で、http(s)のURLは大文字小文字を区別しない）
+pub fn same_source_location(a: &str, b: &str) -> bool {
+    let a = normalize_source_location(a);
+    let b = normalize_source_location(b);
+    if a.starts_with("https://") && b.starts_with("https://") {
+        a.eq_ignore_ascii_case(&b)
+    } else {
+        a == b
+    }
+}
+
+/// バージョン文字列を比較する（先頭の "v" は無視し、数値部分をドット区切りで比較）
+///
+/// `2024.1.1-beta` のようなプレリリース付きは同じ数値の正式版より低いとみなす（セマンティックバージョニング準拠）。
+/// `+` 以降のビルドメタデータは無視する。
+pub fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    let parse = |core: &str| -> Vec<u64> {
+        core.split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    };
+
+    let (a_core, a_pre) = split_version(a);
+    let (b_core, b_pre) = split_version(b);
+    let (a_parts, b_parts) = (parse(a_core), parse(b_core));
+    for i in 0..a_parts.len().max(b_parts.len()) {
+        let ordering = a_parts.get(i).unwrap_or(&0).cmp(b_parts.get(i).unwrap_or(&0));
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    match (a_pre, b_pre) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a_pre), Some(b_pre)) => compare_prerelease(a_pre, b_pre),
+    }
+}
+
+/// バージョン文字列を数値部分とプレリリース部分に分ける
+fn split_version(version: &str) -> (&str, Option<&str>) {
+    let version = version.trim().trim_start_matches(['v', 'V']);
+    let version = version.split('+').next().unwrap_or_default();
+
+    match version.find(|c: char| !c.is_ascii_digit() && c != '.') {
+        Some(pos) => {
+            let prerelease = version[pos..].trim_start_matches(['-', '.']);
+            let core = version[..pos].trim_end_matches('.');
+            (core, Some(prerelease).filter(|p| !p.is_empty()))
+        }
+        None => (version, None),
+    }
+}
+
+/// プレリリース部分をドット区切りの識別子ごとに比較する（数値は数値として、数値は文字列より低い）
+fn compare_prerelease(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    let mut a_ids = a.split('.');
+    let mut b_ids = b.split('.');
+    loop {
+        let ordering = match (a_ids.next(), b_ids.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a_id), Some(b_id)) => match (a_id.parse::<u64>(), b_id.parse::<u64>()) {
+                (Ok(a_num), Ok(b_num)) => a_num.cmp(&b_num),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => a_id.cmp(b_id),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// 実行中のResoniteプロセスのコマンドラインを使い回す時間
+///
+/// Windowsでは取得のたびにPowerShellを起動するため、プロファイル一覧の更新ごとに何度も呼ばれても一度だけ取得する。
+const RUNNING_PROCESS_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// 最後に取得した実行中のResoniteプロセスのコマンドラインと取得時刻
+static RUNNING_COMMAND_LINES: Mutex<Option<(std::time::Instant, Vec<String>)>> = Mutex::new(None);
+
+/// 実行中のResoniteプロセスのコマンドライン引数から、起動中のプロファイルIDを特定する
+///
+/// 実行ファイルのパスや `-DataPath` が、実行可能ファイルと同じ場所の `profiles` 配下
+/// （`ProfileManager` と同じ場所）のプロファイルディレクトリを指しているかで判定する。
+pub fn identify_running_profiles() -> Vec<String> {
+    let Ok(exe_dir) = get_executable_directory() else {
+        return Vec::new();
+    };
+    let profiles_dir = exe_dir.join("profiles");
+
+    let profile_ids: Vec<String> = match std::fs::read_dir(&profiles_dir) {
+        Ok(entries) => entries
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir())
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .collect(),
+        Err(_) => return Vec::new(),
+    };
+
+    match_profiles_in_command_lines(&cached_resonite_command_lines(), &profiles_dir, &profile_ids)
+}
+
+/// 実行中のResoniteプロセスのコマンドラインを、一定時間内なら前回の結果から返す
+fn cached_resonite_command_lines() -> Vec<String> {
+    // 同時に呼ばれた場合も取得は一度だけにするため、取得中もロックを保持する
+    let mut cache = RUNNING_COMMAND_LINES.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((fetched_at, command_lines)) = cache.as_ref() {
+        if fetched_at.elapsed() < RUNNING_PROCESS_CACHE_TTL {
+            return command_lines.clone();
+        }
+    }
+
+    let command_lines = get_resonite_command_lines();
+    *cache = Some((std::time::Instant::now(), command_lines.clone()));
+    command_lines
+}
+
+/// コマンドラインにプロファイルディレクトリが含まれるプロファイルIDを返す
+pub fn match_profiles_in_command_lines(command_lines: &[String], profiles_dir: &Path, profile_ids: &[String]) -> Vec<String> {
+    let normalize = |value: &str| value.replace('\\', "/").to_lowercase();
+    let command_lines: Vec<String> = command_lines.iter().map(|line| normalize(line)).collect();
+
+    profile_ids
+        .iter()
+        .filter(|id| {
+            let profile_dir = format!("{}/", normalize(&profiles_dir.join(id.as_str()).to_string_lossy()));
+            command_lines.iter().any(|line| line.contains(&profile_dir))
+        })
+        .cloned()
+        .collect()
+}
+
+/// 実行中のResonite.exeプロセスのコマンドラインを取得する
+#[cfg(target_os = "windows")]
+fn get_resonite_command_lines() -> Vec<String> {
+    use std::os::windows::process::CommandExt;
+
+    let output = std::process::Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            "Get-CimInstance Win32_Process -Filter \"Name='Resonite.exe'\" | Select-Object -ExpandProperty CommandLine",
+        ])
+        .creation_flags(0x08000000) // CREATE_NO_WINDOW
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// 実行中のResonite.exeプロセス（Proton/Wine経由）のコマンドラインを取得する
+#[cfg(not(target_os = "windows"))]
+fn get_resonite_command_lines() -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name().to_string_lossy().chars().all(|c| c.is_ascii_digit()))
+        .filter_map(|e| std::fs::read(e.path().join("cmdline")).ok())
+        .map(|bytes| {
+            // 引数はNUL区切り
+            String::from_utf8_lossy(&bytes)
+                .split('\0')
+                .filter(|arg| !arg.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|line| line.contains("Resonite.exe"))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_classify_probe_outcomes() {
+        use ProbeOutcome::*;
+
+        assert_eq!(classify_probe_outcomes(&[Failed, Connected]), NetworkStatus::Online);
+        assert_eq!(classify_probe_outcomes(&[Unresolved, Unreachable]), NetworkStatus::Offline);
+        // 確認用のホストだけが遮断されている場合は切断と断定しない
+        assert_eq!(classify_probe_outcomes(&[Failed, Failed]), NetworkStatus::Unknown);
+        assert_eq!(classify_probe_outcomes(&[Unresolved, Failed]), NetworkStatus::Unknown);
+        assert_eq!(classify_probe_outcomes(&[]), NetworkStatus::Unknown);
+    }
+
     #[test]
     fn test_validate_branch_release() {
         assert!(validate_branch("release").is_ok());
@@ -47,11 +583,103 @@ mod tests {
         assert!(validate_branch("prerelease").is_ok());
     }
 
+    #[test]
+    fn test_validate_branch_beta() {
+        assert!(validate_branch("beta").is_ok());
+        assert!(validate_branch("headless-test_1.0").is_ok());
+    }
+
     #[test]
     fn test_validate_branch_invalid() {
-        assert!(validate_branch("invalid").is_err());
         assert!(validate_branch("").is_err());
-        assert!(validate_branch("beta").is_err());
+        assert!(validate_branch("-dir").is_err());
+        assert!(validate_branch("my branch").is_err());
+        assert!(validate_branch(&"a".repeat(65)).is_err());
+    }
+
+    #[test]
+    fn test_normalize_source_location() {
+        let expected = "https://github.com/owner/repo";
+        for url in [
+            "https://github.com/owner/repo",
+            "https://github.com/owner/repo/",
+            "https://github.com/owner/repo.git",
+            "https://github.com/owner/repo.git/",
+            "http://www.github.com/owner/repo",
+            "github.com/owner/repo",
+            " https://GitHub.com/owner/repo#readme ",
+            "https://github.com/owner/repo?tab=readme",
+        ] {
+            assert_eq!(normalize_source_location(url), expected, "{}", url);
+        }
+
+        assert_eq!(normalize_source_location("file:///C:/Mods/MyMod.dll"), "file:///C:/Mods/MyMod.dll");
+        assert_eq!(normalize_source_location("thunderstore:Author-Mod.Name"), "thunderstore:Author-Mod.Name");
+        assert_eq!(normalize_source_location(""), "");
+    }
+
+    #[test]
+    fn test_same_source_location() {
+        assert!(same_source_location("https://github.com/Owner/Repo", "github.com/owner/repo.git"));
+        assert!(!same_source_location("https://github.com/owner/repo", "https://github.com/owner/repo2"));
+        assert!(!same_source_location("file:///mods/A.dll", "file:///mods/a.dll"));
+    }
+
+    #[test]
+    fn test_check_directory_writable_creates_and_cleans_up() {
+        let temp_dir = TempDir::new().unwrap();
+        let data_path = temp_dir.path().join("DataPath");
+
+        check_directory_writable(&data_path).unwrap();
+        assert!(data_path.exists());
+        assert_eq!(std::fs::read_dir(&data_path).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_classify_write_error() {
+        let denied = io::Error::new(io::ErrorKind::PermissionDenied, "denied");
+        assert_eq!(classify_write_error(&denied), WriteAccessFailure::PermissionDenied);
+
+        let missing = io::Error::new(io::ErrorKind::NotFound, "missing drive");
+        assert_eq!(classify_write_error(&missing), WriteAccessFailure::Offline);
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        use std::cmp::Ordering;
+
+        assert_eq!(compare_versions("v3.0.1", "3.0.0"), Ordering::Greater);
+        assert_eq!(compare_versions("2.6.0", "v3.0.0"), Ordering::Less);
+        assert_eq!(compare_versions("3.0", "3.0.0"), Ordering::Equal);
+        assert_eq!(compare_versions("1.10.0", "1.9.0"), Ordering::Greater);
+
+        // プレリリースは同じ数値の正式版より低い
+        assert_eq!(compare_versions("2024.1.1-beta", "2024.1.1"), Ordering::Less);
+        assert_eq!(compare_versions("v2024.1.1", "2024.1.1-beta"), Ordering::Greater);
+        assert_eq!(compare_versions("2024.1.2-beta", "2024.1.1"), Ordering::Greater);
+        assert_eq!(compare_versions("1.0.0-alpha", "1.0.0-beta"), Ordering::Less);
+        assert_eq!(compare_versions("1.0.0-beta.2", "1.0.0-beta.11"), Ordering::Less);
+        assert_eq!(compare_versions("1.0.0-beta", "1.0.0-beta.1"), Ordering::Less);
+        assert_eq!(compare_versions("1.0.0-1", "1.0.0-alpha"), Ordering::Less);
+        assert_eq!(compare_versions("3.0.0rc1", "3.0.0"), Ordering::Less);
+        assert_eq!(compare_versions("1.0.0+build.5", "1.0.0"), Ordering::Equal);
+
+        // 数値でないタグでもパニックしない
+        assert_eq!(compare_versions("latest", "nightly"), Ordering::Less);
+        assert_eq!(compare_versions("", "1.0.0"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_match_profiles_in_command_lines() {
+        let profiles_dir = Path::new("C:\\Launcher\\profiles");
+        let profile_ids = vec!["main".to_string(), "main_test".to_string(), "other".to_string()];
+        let command_lines = vec![
+            "\"C:\\Launcher\\profiles\\main\\Game\\Resonite.exe\" -DataPath C:\\Launcher\\profiles\\main\\DataPath".to_string(),
+            "C:/launcher/profiles/other/Game/Resonite.exe -Screen".to_string(),
+        ];
+
+        let running = match_profiles_in_command_lines(&command_lines, profiles_dir, &profile_ids);
+        assert_eq!(running, vec!["main".to_string(), "other".to_string()]);
     }
 
     #[test]
@@ -82,4 +710,28 @@ mod tests {
         ensure_directory_exists(&nested_dir).unwrap();
         assert!(nested_dir.exists());
     }
+
+    #[test]
+    fn test_find_missing_files() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("vcruntime140.dll"), b"").unwrap();
+
+        let missing = find_missing_files(temp_dir.path(), VC_RUNTIME_FILES);
+        assert_eq!(missing, vec!["vcruntime140_1.dll".to_string(), "msvcp140.dll".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_df_available() {
+        let output = "Filesystem     1024-blocks      Used Available Capacity Mounted on\n\
+                      /dev/sda1        102400000  51200000  51200000      50% /\n";
+        assert_eq!(parse_df_available(output), Some(51200000 * 1024));
+        assert_eq!(parse_df_available("Filesystem\n"), None);
+    }
+
+    #[test]
+    fn test_available_disk_space_for_missing_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let missing = temp_dir.path().join("profiles").join("new").join("Game");
+        assert!(available_disk_space(&missing).unwrap() > 0);
+    }
 }
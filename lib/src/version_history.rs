@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 保持するバージョン履歴の最大件数
+const MAX_VERSION_RECORDS: usize = 50;
+
+/// インストール・更新したResoniteのバージョンの記録
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VersionRecord {
+    /// 記録日時（RFC3339）
+    pub installed_at: String,
+    pub branch: String,
+    /// インストールしたマニフェストID（指定が無い場合はDepotDownloaderの記録から検出、不明ならNone）
+    pub manifest_id: Option<String>,
+    /// Build.versionのバージョン
+    pub version: Option<String>,
+}
+
+/// プロファイルごとのResoniteのバージョン履歴を管理する
+pub struct VersionHistoryManager {
+    history_file: PathBuf,
+}
+
+impl VersionHistoryManager {
+    pub fn new(profile_dir: &Path) -> Self {
+        VersionHistoryManager {
+            history_file: profile_dir.join("version_history.json"),
+        }
+    }
+
+    /// 履歴を読み込む（新しいものが先頭）
+    pub fn load_records(&self) -> Vec<VersionRecord> {
+        fs::read_to_string(&self.history_file)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// 記録を履歴の先頭に追加して保存する
+    pub fn add_record(&self, record: VersionRecord) -> Result<(), Box<dyn Error>> {
+        let mut records = self.load_records();
+        records.insert(0, record);
+        records.truncate(MAX_VERSION_RECORDS);
+
+        let content = serde_json::to_string_pretty(&records)?;
+        fs::write(&self.history_file, content)?;
+        Ok(())
+    }
+}
+
+/// DepotDownloaderが `.DepotDownloader` に残したマニフェストから、インストール済みのマニフェストIDを検出する
+///
+/// `<depot_id>_<manifest_id>.manifest` のうち最後に更新されたものを使う。
+pub fn detect_installed_manifest_id(game_dir: &Path, depot_id: &str) -> Option<String> {
+    let prefix = format!("{}_", depot_id);
+    fs::read_dir(game_dir.join(".DepotDownloader"))
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let manifest_id = file_name.strip_prefix(&prefix)?.strip_suffix(".manifest")?.to_string();
+            if manifest_id.is_empty() || !manifest_id.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            let modified = entry.metadata().and_then(|m| m.modified()).ok()?;
+            Some((manifest_id, modified))
+        })
+        .max_by_key(|(_, modified)| *modified)
+        .map(|(manifest_id, _)| manifest_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn record(manifest_id: &str) -> VersionRecord {
+        VersionRecord {
+            installed_at: "2025-01-01T00:00:00Z".to_string(),
+            branch: "release".to_string(),
+            manifest_id: Some(manifest_id.to_string()),
+            version: None,
+        }
+    }
+
+    #[test]
+    fn test_version_history_newest_first() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = VersionHistoryManager::new(temp_dir.path());
+        assert!(manager.load_records().is_empty());
+
+        manager.add_record(record("100")).unwrap();
+        manager.add_record(record("200")).unwrap();
+
+        let records = manager.load_records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].manifest_id.as_deref(), Some("200"));
+        assert_eq!(records[1].manifest_id.as_deref(), Some("100"));
+    }
+
+    #[test]
+    fn test_detect_installed_manifest_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let game_dir = temp_dir.path();
+        assert_eq!(detect_installed_manifest_id(game_dir, "2519832"), None);
+
+        let depot_dir = game_dir.join(".DepotDownloader");
+        fs::create_dir_all(&depot_dir).unwrap();
+        fs::write(depot_dir.join("depot.config"), b"").unwrap();
+        fs::write(depot_dir.join("2519832_1234567890.manifest"), b"").unwrap();
+        fs::write(depot_dir.join("999_42.manifest"), b"").unwrap();
+
+        assert_eq!(detect_installed_manifest_id(game_dir, "2519832").as_deref(), Some("1234567890"));
+    }
+}
@@ -0,0 +1,194 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::time::Duration;
+
+/// Webhookの送信タイムアウト
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Webhookで通知する起動イベント
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchWebhookEvent {
+    /// 起動した（起動完了を検知、または検知できないまま監視時間が過ぎた）
+    Launched,
+    /// 正常終了した
+    Exited,
+    /// 起動完了前の終了、または終了コードが0以外
+    Crashed,
+}
+
+fn all_events() -> Vec<LaunchWebhookEvent> {
+    vec![
+        LaunchWebhookEvent::Launched,
+        LaunchWebhookEvent::Exited,
+        LaunchWebhookEvent::Crashed,
+    ]
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// プロファイルごとのWebhook設定
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebhookConfig {
+    pub url: String,
+    /// 通知を有効にするか（未指定は有効）
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// 通知するイベント（未指定はすべて）
+    #[serde(default = "all_events")]
+    pub events: Vec<LaunchWebhookEvent>,
+}
+
+impl WebhookConfig {
+    /// イベントを通知するか
+    pub fn should_notify(&self, event: LaunchWebhookEvent) -> bool {
+        self.enabled && !self.url.trim().is_empty() && self.events.contains(&event)
+    }
+}
+
+/// Webhookで送信する内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookPayload {
+    pub event: LaunchWebhookEvent,
+    pub profile: String,
+    pub timestamp: String,
+    /// 人が読むためのメッセージ
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub startup_seconds: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+}
+
+impl WebhookPayload {
+    pub fn new(event: LaunchWebhookEvent, profile: &str) -> Self {
+        let message = match event {
+            LaunchWebhookEvent::Launched => format!("プロファイル '{}' のResoniteを起動しました", profile),
+            LaunchWebhookEvent::Exited => format!("プロファイル '{}' のResoniteが終了しました", profile),
+            LaunchWebhookEvent::Crashed => format!("プロファイル '{}' のResoniteが異常終了しました", profile),
+        };
+        WebhookPayload {
+            event,
+            profile: profile.to_string(),
+            timestamp: Utc::now().to_rfc3339(),
+            message,
+            startup_seconds: None,
+            exit_code: None,
+        }
+    }
+
+    pub fn with_startup_seconds(mut self, startup_seconds: Option<f64>) -> Self {
+        if let Some(seconds) = startup_seconds {
+            self.message = format!("{}（起動時間 {:.1}秒）", self.message, seconds);
+        }
+        self.startup_seconds = startup_seconds;
+        self
+    }
+
+    pub fn with_exit_code(mut self, exit_code: Option<i32>) -> Self {
+        if let Some(code) = exit_code {
+            self.message = format!("{}（終了コード {}）", self.message, code);
+        }
+        self.exit_code = exit_code;
+        self
+    }
+}
+
+/// Discord WebhookのURLか
+fn is_discord_url(url: &str) -> bool {
+    let url = url.trim().to_lowercase();
+    ["discord.com", "discordapp.com", "ptb.discord.com", "canary.discord.com"]
+        .iter()
+        .any(|host| url.starts_with(&format!("https://{}/api/webhooks/", host)))
+}
+
+/// 送信するJSON（Discordは `content` にメッセージを入れる形式に変換する）
+pub fn request_body(url: &str, payload: &WebhookPayload) -> serde_json::Value {
+    if is_discord_url(url) {
+        serde_json::json!({
+            "username": "RESO Launcher",
+            "content": payload.message,
+        })
+    } else {
+        serde_json::to_value(payload).unwrap_or_default()
+    }
+}
+
+async fn post(url: &str, body: &serde_json::Value) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let response = reqwest::Client::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()?
+        .post(url)
+        .header("User-Agent", "resonite-tools")
+        .json(body)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(format!("Webhook returned {}", response.status()).into());
+    }
+    Ok(())
+}
+
+/// 設定で有効なイベントならWebhookに送信する
+///
+/// 起動・監視を止めないよう別スレッドで送信し、失敗してもリトライせずログに残すだけにする。
+pub fn notify_in_background(config: &WebhookConfig, payload: WebhookPayload) {
+    if !config.should_notify(payload.event) {
+        return;
+    }
+
+    let url = config.url.trim().to_string();
+    let body = request_body(&url, &payload);
+    std::thread::spawn(move || {
+        let result = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+            Ok(runtime) => runtime.block_on(post(&url, &body)),
+            Err(e) => Err(e.into()),
+        };
+
+        match result {
+            Ok(()) => println!("Webhook notified: {:?}", payload.event),
+            Err(e) => eprintln!("Failed to send webhook ({:?}): {}", payload.event, e),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_notify() {
+        let mut config: WebhookConfig = serde_json::from_str(r#"{"url":"https://example.com/hook","enabled":true}"#).unwrap();
+        assert!(config.should_notify(LaunchWebhookEvent::Crashed));
+
+        config.events = vec![LaunchWebhookEvent::Crashed];
+        assert!(!config.should_notify(LaunchWebhookEvent::Launched));
+
+        config.enabled = false;
+        assert!(!config.should_notify(LaunchWebhookEvent::Crashed));
+
+        // enabledを省略した場合はURLだけで通知する
+        let config: WebhookConfig = serde_json::from_str(r#"{"url":"https://example.com/hook"}"#).unwrap();
+        assert!(config.should_notify(LaunchWebhookEvent::Launched));
+    }
+
+    #[test]
+    fn test_request_body_for_discord_and_generic() {
+        let payload = WebhookPayload::new(LaunchWebhookEvent::Exited, "Headless").with_exit_code(Some(1));
+        assert!(payload.message.contains("終了コード 1"));
+
+        let discord = request_body("https://discord.com/api/webhooks/1/abc", &payload);
+        assert_eq!(discord["content"], payload.message.as_str());
+        assert!(discord.get("event").is_none());
+
+        let generic = request_body("https://example.com/hook", &payload);
+        assert_eq!(generic["event"], "exited");
+        assert_eq!(generic["profile"], "Headless");
+        assert_eq!(generic["exit_code"], 1);
+        assert!(generic.get("startup_seconds").is_none());
+    }
+}
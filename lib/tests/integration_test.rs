@@ -174,3 +174,210 @@ fn test_mod_loader_type_persistence() {
     let loaded = manager.get_profile(&profile.id).unwrap();
     assert_eq!(loaded.mod_loader_type, Some(ModLoaderType::ResoniteModLoader));
 }
+
+#[test]
+fn test_mod_manager_error_kind() {
+    use reso_launcher_lib::mod_manager::{ModManager, ModManagerError};
+
+    let temp_dir = TempDir::new().unwrap();
+    let mod_manager = ModManager::new(temp_dir.path().to_path_buf());
+
+    // インストールされていないMODの操作はNotFoundになる
+    match mod_manager.disable_mod("MissingMod") {
+        Err(ModManagerError::NotFound(message)) => assert!(message.contains("MissingMod")),
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_disable_monkey_loader_mod_moves_out_of_mods_folder() {
+    use reso_launcher_lib::mod_manager::ModManager;
+
+    let temp_dir = TempDir::new().unwrap();
+    let mods_dir = temp_dir.path().join("Game").join("MonkeyLoader").join("Mods");
+    std::fs::create_dir_all(&mods_dir).unwrap();
+    let nupkg_path = mods_dir.join("TestMod.nupkg");
+    std::fs::write(&nupkg_path, b"nupkg").unwrap();
+    let installed = serde_json::json!([{
+        "name": "TestMod",
+        "description": "",
+        "source_location": "https://github.com/test/TestMod",
+        "installed_version": "1.0.0",
+        "installed_date": "2024-01-01 00:00:00",
+        "dll_path": nupkg_path,
+        "mod_loader_type": "MonkeyLoader",
+        "file_format": "nupkg",
+        "enabled": true
+    }]);
+    std::fs::write(temp_dir.path().join("installed_mods.json"), installed.to_string()).unwrap();
+
+    let mod_manager = ModManager::new(temp_dir.path().to_path_buf());
+    mod_manager.disable_mod("TestMod").unwrap();
+
+    // MonkeyLoaderはMods配下を再帰的に読み込むため、Modsの外に移動する
+    let disabled_path = temp_dir.path().join("Game").join("MonkeyLoader").join("DisabledMods").join("TestMod.nupkg");
+    assert!(disabled_path.exists());
+    assert!(!nupkg_path.exists());
+    assert_eq!(mod_manager.get_installed_mods().unwrap()[0].dll_path, disabled_path);
+
+    mod_manager.enable_mod("TestMod").unwrap();
+    assert!(nupkg_path.exists());
+    assert_eq!(mod_manager.get_installed_mods().unwrap()[0].dll_path, nupkg_path);
+}
+
+#[tokio::test]
+async fn test_install_local_file_rejects_loader_mismatch() {
+    use reso_launcher_lib::mod_manager::{ModManager, ModManagerError};
+
+    let temp_dir = TempDir::new().unwrap();
+    let nupkg_path = temp_dir.path().join("TestMod.nupkg");
+    std::fs::write(&nupkg_path, b"nupkg").unwrap();
+
+    // .nupkgはMonkeyLoader用なので、ResoniteModLoaderとしてはインストールできない
+    let mod_manager = ModManager::new(temp_dir.path().join("profile"));
+    match mod_manager.install_mod_from_local_file(&nupkg_path, Some("ResoniteModLoader")).await {
+        Err(ModManagerError::LoaderMismatch(message)) => assert!(message.contains("nupkg")),
+        other => panic!("expected LoaderMismatch, got {:?}", other),
+    }
+    assert!(mod_manager.get_installed_mods().unwrap().is_empty());
+}
+
+#[test]
+fn test_find_loader_mismatches_uses_recorded_loader() {
+    use reso_launcher_lib::mod_loader_type::ModLoaderType;
+    use reso_launcher_lib::mod_manager::ModManager;
+
+    let temp_dir = TempDir::new().unwrap();
+    let mods_dir = temp_dir.path().join("Game").join("MonkeyLoader").join("Mods");
+    std::fs::create_dir_all(&mods_dir).unwrap();
+    let dll_path = mods_dir.join("MonkeyMod.dll");
+    std::fs::write(&dll_path, b"dll").unwrap();
+    let installed = serde_json::json!([{
+        "name": "MonkeyMod",
+        "description": "",
+        "source_location": "https://github.com/test/MonkeyMod",
+        "installed_version": "1.0.0",
+        "installed_date": "2024-01-01 00:00:00",
+        "dll_path": dll_path,
+        "mod_loader_type": "MonkeyLoader",
+        "file_format": "dll",
+        "enabled": true
+    }]);
+    std::fs::write(temp_dir.path().join("installed_mods.json"), installed.to_string()).unwrap();
+
+    let mod_manager = ModManager::new(temp_dir.path().to_path_buf());
+
+    // DLLでもMonkeyLoader用として記録されたMODはRMLでは読み込まれない
+    let mismatches = mod_manager.find_loader_mismatches(Some(ModLoaderType::ResoniteModLoader)).unwrap();
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].supported_loaders, vec![ModLoaderType::MonkeyLoader]);
+    assert!(mod_manager.find_loader_mismatches(Some(ModLoaderType::MonkeyLoader)).unwrap().is_empty());
+
+    // MODローダーが無い場合は判定しない
+    assert!(mod_manager.find_loader_mismatches(None).unwrap().is_empty());
+}
+
+#[test]
+fn test_restore_safe_mode_mods_drops_uninstalled_mods() {
+    use reso_launcher_lib::mod_manager::ModManager;
+
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("safe_mode_disabled_mods.json"), r#"["RemovedMod"]"#).unwrap();
+
+    let mod_manager = ModManager::new(temp_dir.path().to_path_buf());
+
+    // アンインストール済みのMODは有効化できないが、記録からは外れる
+    assert!(mod_manager.restore_safe_mode_mods().unwrap().is_empty());
+    assert!(mod_manager.get_safe_mode_pending_mods().is_empty());
+}
+
+/// テスト用のインストール済みMODエントリ
+fn installed_mod_entry(name: &str, source: &str, version: &str, dll_path: &std::path::Path) -> serde_json::Value {
+    serde_json::json!({
+        "name": name,
+        "description": "",
+        "source_location": source,
+        "installed_version": version,
+        "installed_date": "2024-01-01 00:00:00",
+        "dll_path": dll_path,
+        "file_format": "dll",
+        "enabled": true
+    })
+}
+
+#[test]
+fn test_merge_duplicate_mods_keeps_existing_copies() {
+    use reso_launcher_lib::mod_manager::ModManager;
+
+    let temp_dir = TempDir::new().unwrap();
+    let mods_dir = temp_dir.path().join("Game").join("rml_mods");
+    std::fs::create_dir_all(&mods_dir).unwrap();
+    let first = mods_dir.join("Foo.dll");
+    let second = mods_dir.join("Foo-copy.dll");
+    std::fs::write(&first, b"dll").unwrap();
+    std::fs::write(&second, b"dll").unwrap();
+    let installed = serde_json::json!([
+        installed_mod_entry("Foo", "https://github.com/test/Foo", "1.0.0", &first),
+        installed_mod_entry("Foo", "https://github.com/test/Foo", "1.1.0", &second),
+    ]);
+    std::fs::write(temp_dir.path().join("installed_mods.json"), installed.to_string()).unwrap();
+
+    // 名前が同じでも両方のファイルが存在する場合はマージしない
+    let mod_manager = ModManager::new(temp_dir.path().to_path_buf());
+    assert_eq!(mod_manager.get_installed_mods().unwrap().len(), 2);
+}
+
+#[test]
+fn test_merge_duplicate_mods_drops_missing_file_entry() {
+    use reso_launcher_lib::mod_manager::ModManager;
+
+    let temp_dir = TempDir::new().unwrap();
+    let mods_dir = temp_dir.path().join("Game").join("rml_mods");
+    std::fs::create_dir_all(&mods_dir).unwrap();
+    let existing = mods_dir.join("Foo.dll");
+    std::fs::write(&existing, b"dll").unwrap();
+    let installed = serde_json::json!([
+        installed_mod_entry("Foo", "https://github.com/test/Foo", "1.1.0", &mods_dir.join("Foo-old.dll")),
+        installed_mod_entry("Foo", "https://github.com/test/Foo", "1.0.0", &existing),
+    ]);
+    std::fs::write(temp_dir.path().join("installed_mods.json"), installed.to_string()).unwrap();
+
+    // ファイルが無くなったエントリは、存在する方にマージされる
+    let mod_manager = ModManager::new(temp_dir.path().to_path_buf());
+    let mods = mod_manager.get_installed_mods().unwrap();
+    assert_eq!(mods.len(), 1);
+    assert_eq!(mods[0].installed_version, "1.0.0");
+    let saved: Vec<serde_json::Value> =
+        serde_json::from_str(&std::fs::read_to_string(temp_dir.path().join("installed_mods.json")).unwrap()).unwrap();
+    assert_eq!(saved.len(), 1);
+}
+
+#[test]
+fn test_merge_duplicate_mods_same_source_and_file_name() {
+    use reso_launcher_lib::mod_manager::ModManager;
+
+    let temp_dir = TempDir::new().unwrap();
+    let mods_dir = temp_dir.path().join("Game").join("rml_mods");
+    let nested_dir = mods_dir.join("Foo");
+    std::fs::create_dir_all(&nested_dir).unwrap();
+    let other_dir = mods_dir.join("Other");
+    std::fs::create_dir_all(&other_dir).unwrap();
+    let first = mods_dir.join("Foo.dll");
+    let second = nested_dir.join("Foo.dll");
+    let other = other_dir.join("Foo.dll");
+    for path in [&first, &second, &other] {
+        std::fs::write(path, b"dll").unwrap();
+    }
+    let installed = serde_json::json!([
+        installed_mod_entry("Foo", "https://github.com/test/Foo", "1.0.0", &first),
+        installed_mod_entry("Foo", "https://github.com/test/Foo.git", "1.0.0", &second),
+        installed_mod_entry("Foo", "https://github.com/other/Foo", "1.0.0", &other),
+    ]);
+    std::fs::write(temp_dir.path().join("installed_mods.json"), installed.to_string()).unwrap();
+
+    // インストール元とファイル名が同じものはマージし、別リポジトリのものは残す
+    let mod_manager = ModManager::new(temp_dir.path().to_path_buf());
+    let mods = mod_manager.get_installed_mods().unwrap();
+    assert_eq!(mods.len(), 2);
+    assert!(mods.iter().any(|m| m.source_location == "https://github.com/other/Foo"));
+}